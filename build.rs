@@ -2,15 +2,24 @@ use std::env;
 use std::fs;
 use std::path::PathBuf;
 
+#[path = "src/spec_overlay.rs"]
+mod spec_overlay;
+
 fn main() {
-    println!("cargo::rerun-if-changed=openapi.yaml");
+    println!("cargo::rerun-if-changed={}", spec_overlay::SPEC_PATH);
+    println!("cargo::rerun-if-changed={}", spec_overlay::OVERLAY_PATH);
+    println!("cargo::rerun-if-changed=src/spec_overlay.rs");
+
+    let spec_yaml = spec_overlay::load_spec();
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
 
-    let spec_path = PathBuf::from("openapi.yaml");
-    let spec_content = fs::read_to_string(&spec_path).expect("Failed to read openapi.yaml");
+    // The patched spec is embedded for doctor and map --heat
+    let patched = serde_yaml::to_string(&spec_yaml).expect("Failed to serialize spec");
+    fs::write(out_dir.join("openapi.yaml"), patched).expect("Failed to write patched spec");
 
-    // Parse YAML to JSON Value first, then convert to OpenAPI
+    // Convert YAML to JSON Value first, then to OpenAPI
     let spec_json: serde_json::Value =
-        serde_yaml::from_str(&spec_content).expect("Failed to parse openapi.yaml");
+        serde_json::to_value(&spec_yaml).expect("Failed to convert openapi.yaml");
     let spec_string = serde_json::to_string(&spec_json).expect("Failed to serialize to JSON");
     let spec: openapiv3::OpenAPI =
         serde_json::from_str(&spec_string).expect("Failed to parse as OpenAPI");
@@ -23,7 +32,6 @@ fn main() {
     let ast = syn::parse2(tokens).expect("Failed to parse generated tokens");
     let content = prettyplease::unparse(&ast);
 
-    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
     let out_path = out_dir.join("codegen.rs");
     fs::write(&out_path, content).expect("Failed to write generated code");
 }
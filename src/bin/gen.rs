@@ -3,18 +3,18 @@
 //! Usage:
 //!   cargo run --bin gen > src/cli_generated.rs
 //!
-//! This parses openapi.yaml and generates:
+//! This parses openapi.yaml (with openapi.overlay.yaml applied) and generates:
 //! - Action enum with Clap derive attributes
 //! - impl Action with to_game_command() method
 
 use serde_yaml::Value;
 use std::collections::HashSet;
-use std::fs;
+
+#[path = "../spec_overlay.rs"]
+mod spec_overlay;
 
 fn main() {
-    let spec_path = "openapi.yaml";
-    let content = fs::read_to_string(spec_path).expect("Failed to read openapi.yaml");
-    let spec: Value = serde_yaml::from_str(&content).expect("Failed to parse YAML");
+    let spec: Value = spec_overlay::load_spec();
 
     let schemas = &spec["components"]["schemas"];
 
@@ -92,7 +92,6 @@ struct ParamDef {
     param_type: ParamType,
     description: String,
     required: bool,
-    has_default: bool, // Boolean fields with default are treated as required bool
 }
 
 #[derive(Debug, Clone)]
@@ -121,15 +120,12 @@ fn parse_params(schema: &Value) -> Vec<ParamDef> {
                 let param_type = parse_type(value);
                 let description = value["description"].as_str().unwrap_or("").to_string();
                 let is_required = required.contains(name);
-                // Boolean fields with a default value should be treated as required bool
-                let has_default = value.get("default").is_some();
 
                 params.push(ParamDef {
                     name: name.clone(),
                     param_type,
                     description,
                     required: is_required,
-                    has_default,
                 });
             }
         }
@@ -3,17 +3,19 @@
 //! Usage:
 //!   cargo run --bin gen_formatters > src/output/table_generated.rs
 //!
-//! This parses openapi.yaml and generates format_*() functions for all entity types,
-//! displaying ALL fields.
+//! This parses openapi.yaml (with openapi.overlay.yaml applied) and generates
+//! format_*() functions for all entity types, displaying ALL fields, plus
+//! *_cell() renderers used to project selected fields (--fields) into columns
+//! and *_id_kinds() listing the kinds of IDs those show with names.
 
 use serde_yaml::Value;
 use std::collections::HashSet;
-use std::fs;
+
+#[path = "../spec_overlay.rs"]
+mod spec_overlay;
 
 fn main() {
-    let spec_path = "openapi.yaml";
-    let content = fs::read_to_string(spec_path).expect("Failed to read openapi.yaml");
-    let spec: Value = serde_yaml::from_str(&content).expect("Failed to parse YAML");
+    let spec: Value = spec_overlay::load_spec();
 
     let schemas = &spec["components"]["schemas"];
 
@@ -37,6 +39,7 @@ fn main() {
         "CityEvent",
         "UnitEvent",
         "WonderEvent",
+        "TurnSummary",
         "PlayerTechs",
         "PlayerFamilies",
         "PlayerReligion",
@@ -77,6 +80,9 @@ fn main() {
     // Sort for consistent output
     schema_defs.sort_by(|a, b| a.name.cmp(&b.name));
 
+    // Schemas returned directly by an endpoint (others are only shown inside lists)
+    let single_responses = collect_single_responses(&spec);
+
     // Generate output
     generate_preamble();
     for schema in &schema_defs {
@@ -89,6 +95,19 @@ fn main() {
             generate_list_formatter(schema);
             println!();
         }
+        // Generate field projection (--fields) formatters
+        generate_cell_function(schema);
+        println!();
+        if !skip_single_formatter(&schema.name) && single_responses.contains(&schema.name) {
+            generate_single_fields_formatter(schema);
+            println!();
+        }
+        if should_have_list_formatter(&schema.name) {
+            generate_list_columns_formatter(schema);
+            println!();
+        }
+        generate_id_kinds(schema);
+        println!();
     }
 }
 
@@ -144,7 +163,7 @@ fn parse_schema_fields(schema: &Value, enum_fields: &HashSet<&str>) -> Vec<Field
 
 fn parse_field_type(schema: &Value) -> FieldType {
     if let Some(ref_path) = schema["$ref"].as_str() {
-        let type_name = ref_path.split('/').last().unwrap_or("Unknown");
+        let type_name = ref_path.split('/').next_back().unwrap_or("Unknown");
         return FieldType::Object(type_name.to_string());
     }
 
@@ -214,6 +233,11 @@ fn skip_single_formatter(name: &str) -> bool {
     )
 }
 
+/// Digest types whose entity arrays are expanded inline instead of shown as "N items"
+fn expand_nested_lists(name: &str) -> bool {
+    matches!(name, "TurnSummary")
+}
+
 fn generate_preamble() {
     println!("// This file is auto-generated by `cargo run --bin gen_formatters`");
     println!("// Do not edit manually. Regenerate with:");
@@ -229,8 +253,10 @@ fn generate_single_formatter(schema: &SchemaDef) {
 
     println!("/// Format a single {} for display (all fields)", type_name);
     println!(
-        "pub fn {}(item: &types::{}) -> String {{",
-        fn_name, type_name
+        "pub fn {}(item: &types::{}, {}: &Names) -> String {{",
+        fn_name,
+        type_name,
+        names_param(single_uses_names(schema))
     );
     println!("    let mut lines = Vec::new();");
     println!();
@@ -238,7 +264,15 @@ fn generate_single_formatter(schema: &SchemaDef) {
     println!();
 
     for field in &schema.fields {
-        generate_field_display(field);
+        match &field.field_type {
+            FieldType::Array(inner) if expand_nested_lists(&schema.name) => match inner.as_ref() {
+                FieldType::Object(item_type) if should_have_list_formatter(item_type) => {
+                    generate_nested_list_display(field, item_type);
+                }
+                _ => generate_field_display(field),
+            },
+            _ => generate_field_display(field),
+        }
     }
 
     println!();
@@ -246,6 +280,112 @@ fn generate_single_formatter(schema: &SchemaDef) {
     println!("}}");
 }
 
+/// Render an array of entities through their list formatter, indented under the field name
+fn generate_nested_list_display(field: &FieldDef, item_type: &str) {
+    let rust_name = &field.rust_name;
+    let display_name = to_display_name(&field.name);
+    let list_fn = list_formatter_name(item_type);
+
+    println!("    if !item.{}.is_empty() {{", rust_name);
+    println!("        lines.push(\"  {}:\".to_string());", display_name);
+    println!(
+        "        for line in {}(&item.{}, names).lines() {{",
+        list_fn, rust_name
+    );
+    println!("            lines.push(format!(\"    {{}}\", line));");
+    println!("        }}");
+    println!("    }}");
+}
+
+/// Whether a field is rendered with the name of the entity its ID refers to
+fn resolves_id(field: &FieldDef) -> bool {
+    !field.is_enum
+        && id_resolver(&field.name).is_some()
+        && match &field.field_type {
+            FieldType::Integer | FieldType::String => true,
+            FieldType::Array(inner) => matches!(inner.as_ref(), FieldType::Integer),
+            _ => false,
+        }
+}
+
+/// Entity types of the arrays a single formatter expands through their list formatter
+fn nested_list_types(schema: &SchemaDef) -> Vec<&str> {
+    if !expand_nested_lists(&schema.name) {
+        return Vec::new();
+    }
+    schema
+        .fields
+        .iter()
+        .filter_map(|field| match &field.field_type {
+            FieldType::Array(inner) => match inner.as_ref() {
+                FieldType::Object(item_type) if should_have_list_formatter(item_type) => {
+                    Some(item_type.as_str())
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn single_uses_names(schema: &SchemaDef) -> bool {
+    schema.fields.iter().any(resolves_id) || !nested_list_types(schema).is_empty()
+}
+
+/// Name of the `&Names` parameter, underscored where the formatter shows no IDs
+fn names_param(used: bool) -> &'static str {
+    if used {
+        "names"
+    } else {
+        "_names"
+    }
+}
+
+/// `*_id_kinds()`: the kinds of IDs the formatters show, so only those names are fetched
+fn generate_id_kinds(schema: &SchemaDef) {
+    let id_fields: Vec<String> = schema
+        .fields
+        .iter()
+        .filter(|field| resolves_id(field))
+        .map(|field| {
+            let kind = match id_resolver(&field.name) {
+                Some("resolve_player") => "Player",
+                Some("resolve_city") => "City",
+                _ => "Character",
+            };
+            format!("(\"{}\", Kind::{})", field.name, kind)
+        })
+        .collect();
+    let nested = nested_list_types(schema);
+
+    println!(
+        "/// Kinds of IDs a {} table shows, of all fields or only the selected ones",
+        schema.name
+    );
+    println!(
+        "pub fn {}(fields: Option<&[&str]>) -> Needed {{",
+        id_kinds_function_name(&schema.name)
+    );
+    if nested.is_empty() {
+        println!("    Needed::of_fields(&[{}], fields)", id_fields.join(", "));
+    } else {
+        println!(
+            "    let needed = Needed::of_fields(&[{}], fields);",
+            id_fields.join(", ")
+        );
+        println!("    // Nested lists are only expanded when all fields are shown");
+        println!("    match fields {{");
+        let unions: Vec<String> = nested
+            .iter()
+            .map(|item_type| format!(".union({}(None))", id_kinds_function_name(item_type)))
+            .collect();
+        println!("        None => needed{},", unions.join(""));
+        println!("        Some(_) => needed,");
+        println!("    }}");
+    }
+    println!("}}");
+}
+
 fn generate_field_display(field: &FieldDef) {
     let rust_name = &field.rust_name;
     let display_name = to_display_name(&field.name);
@@ -261,6 +401,44 @@ fn generate_field_display(field: &FieldDef) {
         return;
     }
 
+    // ID references render with the referenced entity's name
+    if let Some(resolver) = id_resolver(&field.name) {
+        match &field.field_type {
+            FieldType::Integer => {
+                println!("    if let Some(v) = item.{} {{", rust_name);
+                println!(
+                    "        lines.push(format!(\"  {}: {{}}\", {}(names, v)));",
+                    display_name, resolver
+                );
+                println!("    }}");
+                return;
+            }
+            FieldType::String => {
+                println!("    if let Some(v) = &item.{} {{", rust_name);
+                println!(
+                    "        lines.push(format!(\"  {}: {{}}\", {}(names, v)));",
+                    display_name, resolver
+                );
+                println!("    }}");
+                return;
+            }
+            FieldType::Array(inner) if matches!(inner.as_ref(), FieldType::Integer) => {
+                println!("    if !item.{}.is_empty() {{", rust_name);
+                println!(
+                    "        let items: Vec<String> = item.{}.iter().map(|v| {}(names, v)).collect();",
+                    rust_name, resolver
+                );
+                println!(
+                    "        lines.push(format!(\"  {}: {{}}\", items.join(\", \")));",
+                    display_name
+                );
+                println!("    }}");
+                return;
+            }
+            _ => {}
+        }
+    }
+
     match &field.field_type {
         FieldType::Integer | FieldType::Float => {
             println!("    if let Some(v) = item.{} {{", rust_name);
@@ -394,9 +572,16 @@ fn generate_list_formatter(schema: &SchemaDef) {
     let plural = pluralize_name(&schema.name);
 
     println!("/// Format a list of {} (all fields for each)", plural);
+    let uses_names = if skip_single_formatter(&schema.name) {
+        schema.fields.iter().any(resolves_id)
+    } else {
+        single_uses_names(schema)
+    };
     println!(
-        "pub fn {}(items: &[types::{}]) -> String {{",
-        fn_name, type_name
+        "pub fn {}(items: &[types::{}], {}: &Names) -> String {{",
+        fn_name,
+        type_name,
+        names_param(uses_names)
     );
     println!("    if items.is_empty() {{");
     println!("        return \"No {} found\".to_string();", plural);
@@ -423,13 +608,179 @@ fn generate_list_formatter(schema: &SchemaDef) {
         let single_fn = single_formatter_name(&schema.name);
         println!("    items");
         println!("        .iter()");
-        println!("        .map(|item| {}(item))", single_fn);
+        if uses_names {
+            println!("        .map(|item| {}(item, names))", single_fn);
+        } else {
+            println!("        .map(|item| {}(item, _names))", single_fn);
+        }
         println!("        .collect::<Vec<_>>()");
         println!("        .join(\"\\n\\n\")");
     }
     println!("}}");
 }
 
+fn generate_cell_function(schema: &SchemaDef) {
+    let fn_name = cell_function_name(&schema.name);
+    let type_name = &schema.name;
+
+    println!(
+        "/// Render one {} field as a table cell (None for fields not in the spec)",
+        type_name
+    );
+    println!(
+        "pub fn {}(item: &types::{}, field: &str, {}: &Names) -> Option<String> {{",
+        fn_name,
+        type_name,
+        names_param(schema.fields.iter().any(resolves_id))
+    );
+    println!("    let cell = match field {{");
+    for field in &schema.fields {
+        println!("        \"{}\" => {},", field.name, cell_expression(field));
+    }
+    println!("        _ => return None,");
+    println!("    }};");
+    println!("    Some(cell.unwrap_or_default())");
+    println!("}}");
+}
+
+/// Expression producing Option<String> for a field, following the display rules above
+fn cell_expression(field: &FieldDef) -> String {
+    let rust_name = &field.rust_name;
+    let shorten = is_type_constant_field(&field.name);
+
+    if field.is_enum {
+        return format!(
+            "item.{}.as_ref().map(|v| format!(\"{{:?}}\", v))",
+            rust_name
+        );
+    }
+
+    if let Some(resolver) = id_resolver(&field.name) {
+        match &field.field_type {
+            FieldType::Integer => {
+                return format!("item.{}.map(|v| {}(names, v))", rust_name, resolver)
+            }
+            FieldType::String => {
+                return format!(
+                    "item.{}.as_deref().map(|v| {}(names, v))",
+                    rust_name, resolver
+                )
+            }
+            FieldType::Array(inner) if matches!(inner.as_ref(), FieldType::Integer) => {
+                return format!(
+                    "Some(item.{}.iter().map(|v| {}(names, v)).collect::<Vec<_>>().join(\", \"))",
+                    rust_name, resolver
+                )
+            }
+            _ => {}
+        }
+    }
+
+    match &field.field_type {
+        FieldType::Integer | FieldType::Float => {
+            format!("item.{}.map(|v| v.to_string())", rust_name)
+        }
+        FieldType::String if shorten => format!("item.{}.as_deref().map(shorten_type)", rust_name),
+        FieldType::String => format!("item.{}.clone()", rust_name),
+        FieldType::Boolean => format!("item.{}.map(yes_no)", rust_name),
+        FieldType::Array(inner) => match inner.as_ref() {
+            FieldType::String if shorten => format!(
+                "Some(item.{}.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(\", \"))",
+                rust_name
+            ),
+            FieldType::String => format!("Some(item.{}.join(\", \"))", rust_name),
+            FieldType::Integer | FieldType::Float => format!(
+                "Some(item.{}.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(\", \"))",
+                rust_name
+            ),
+            _ => format!("Some(format!(\"{{}} items\", item.{}.len()))", rust_name),
+        },
+        FieldType::HashMap(inner) => match inner.as_ref() {
+            FieldType::Boolean => format!(
+                "Some(item.{}.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(\", \"))",
+                rust_name
+            ),
+            FieldType::Integer | FieldType::Float | FieldType::String => format!(
+                "Some(item.{}.iter().map(|(k, v)| format!(\"{{}}: {{}}\", shorten_type(k), v)).collect::<Vec<_>>().join(\", \"))",
+                rust_name
+            ),
+            _ => format!(
+                "Some(item.{}.iter().map(|(k, v)| format!(\"{{}}: {{:?}}\", shorten_type(k), v)).collect::<Vec<_>>().join(\", \"))",
+                rust_name
+            ),
+        },
+        FieldType::JsonMap => format!(
+            "Some(item.{}.iter().map(|(k, v)| format!(\"{{}}: {{}}\", shorten_type(k), v)).collect::<Vec<_>>().join(\", \"))",
+            rust_name
+        ),
+        FieldType::Object(type_name) if type_name == "Location" => {
+            format!("item.{}.as_ref().map(location_cell)", rust_name)
+        }
+        FieldType::Object(type_name) => format!(
+            "item.{}.as_ref().map(|_| \"(nested {})\".to_string())",
+            rust_name, type_name
+        ),
+    }
+}
+
+/// Collect schema names referenced directly (not as array items) by GET responses
+fn collect_single_responses(spec: &Value) -> HashSet<String> {
+    let mut names = HashSet::new();
+    if let Value::Mapping(paths) = &spec["paths"] {
+        for (_, item) in paths {
+            let schema = &item["get"]["responses"]["200"]["content"]["application/json"]["schema"];
+            if let Some(name) = schema["$ref"].as_str().and_then(|r| r.rsplit('/').next()) {
+                names.insert(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+fn generate_single_fields_formatter(schema: &SchemaDef) {
+    let fn_name = format!("{}_fields", single_formatter_name(&schema.name));
+    let type_name = &schema.name;
+
+    println!(
+        "/// Format a single {} showing only the selected fields",
+        type_name
+    );
+    println!(
+        "pub fn {}(item: &types::{}, fields: &[&str], names: &Names) -> String {{",
+        fn_name, type_name
+    );
+    println!(
+        "    format_fields(\"{}\", item, fields, names, {})",
+        type_name,
+        cell_function_name(&schema.name)
+    );
+    println!("}}");
+}
+
+fn generate_list_columns_formatter(schema: &SchemaDef) {
+    let fn_name = format!("{}_columns", list_formatter_name(&schema.name));
+    let type_name = &schema.name;
+    let plural = pluralize_name(&schema.name);
+
+    println!(
+        "/// Format a list of {} as a table of the selected fields",
+        plural
+    );
+    println!(
+        "pub fn {}(items: &[types::{}], fields: &[&str], names: &Names) -> String {{",
+        fn_name, type_name
+    );
+    println!("    if items.is_empty() {{");
+    println!("        return \"No {} found\".to_string();", plural);
+    println!("    }}");
+    println!();
+    println!(
+        "    format_columns(items, fields, names, {})",
+        cell_function_name(&schema.name)
+    );
+    println!("}}");
+}
+
 fn generate_field_display_inline(field: &FieldDef) {
     let rust_name = &field.rust_name;
     let display_name = to_display_name(&field.name);
@@ -444,6 +795,43 @@ fn generate_field_display_inline(field: &FieldDef) {
         return;
     }
 
+    if let Some(resolver) = id_resolver(&field.name) {
+        match &field.field_type {
+            FieldType::Integer => {
+                println!("if let Some(v) = item.{} {{", rust_name);
+                println!(
+                    "                lines.push(format!(\"  {}: {{}}\", {}(names, v)));",
+                    display_name, resolver
+                );
+                println!("            }}");
+                return;
+            }
+            FieldType::String => {
+                println!("if let Some(v) = &item.{} {{", rust_name);
+                println!(
+                    "                lines.push(format!(\"  {}: {{}}\", {}(names, v)));",
+                    display_name, resolver
+                );
+                println!("            }}");
+                return;
+            }
+            FieldType::Array(inner) if matches!(inner.as_ref(), FieldType::Integer) => {
+                println!("if !item.{}.is_empty() {{", rust_name);
+                println!(
+                    "                let vals: Vec<String> = item.{}.iter().map(|v| {}(names, v)).collect();",
+                    rust_name, resolver
+                );
+                println!(
+                    "                lines.push(format!(\"  {}: {{}}\", vals.join(\", \")));",
+                    display_name
+                );
+                println!("            }}");
+                return;
+            }
+            _ => {}
+        }
+    }
+
     match &field.field_type {
         FieldType::Integer | FieldType::Float => {
             println!("if let Some(v) = item.{} {{", rust_name);
@@ -516,6 +904,14 @@ fn single_formatter_name(name: &str) -> String {
     }
 }
 
+fn cell_function_name(name: &str) -> String {
+    format!("{}_cell", to_snake_case(name))
+}
+
+fn id_kinds_function_name(name: &str) -> String {
+    format!("{}_id_kinds", to_snake_case(name))
+}
+
 fn list_formatter_name(name: &str) -> String {
     match name {
         "City" => "format_cities".to_string(),
@@ -567,6 +963,68 @@ fn is_type_constant_field(name: &str) -> bool {
         || name_lower.contains("difficulty")
 }
 
+/// Fields that hold a player index, city ID or character ID, mapped to the
+/// table.rs helper that appends the referenced entity's name
+fn id_resolver(name: &str) -> Option<&'static str> {
+    const PLAYER_FIELDS: &[&str] = &[
+        "allyPlayerId",
+        "capturePlayer",
+        "currentPlayer",
+        "firstPlayer",
+        "lastOwnerId",
+        "lastPlayer",
+        "newOwnerId",
+        "oldOwnerId",
+        "originalPlayer",
+        "owner",
+        "player",
+        "playerId",
+        "playerInt",
+        "rebelPlayer",
+    ];
+    const CITY_FIELDS: &[&str] = &[
+        "birthCityID",
+        "capitalCityID",
+        "cityID",
+        "cityId",
+        "cityTerritory",
+        "holyCityId",
+        "originalCapitalCityID",
+    ];
+    const CHARACTER_FIELDS: &[&str] = &[
+        "birthFatherID",
+        "birthMotherID",
+        "characterId",
+        "childrenIds",
+        "chosenHeirID",
+        "cityAgentID",
+        "cityGovernorID",
+        "defaultGovernorID",
+        "explorerID",
+        "fatherID",
+        "founderID",
+        "generalID",
+        "governorID",
+        "headCharacterId",
+        "leaderId",
+        "motherID",
+        "newHeirId",
+        "newLeaderId",
+        "parentIds",
+        "spouseIds",
+    ];
+
+    if PLAYER_FIELDS.contains(&name) {
+        Some("resolve_player")
+    } else if CITY_FIELDS.contains(&name) {
+        Some("resolve_city")
+    } else if CHARACTER_FIELDS.contains(&name) {
+        Some("resolve_character")
+    } else {
+        None
+    }
+}
+
 /// Convert camelCase to snake_case, handling acronyms like ID correctly
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -580,7 +1038,7 @@ fn to_snake_case(s: &str) -> String {
             // Add underscore before this char if:
             // - Not first char AND
             // - Either previous was lowercase OR (previous was upper AND next is lower)
-            if i > 0 && (!prev_upper || (prev_upper && next_lower)) {
+            if i > 0 && (!prev_upper || next_lower) {
                 result.push('_');
             }
             result.push(c.to_lowercase().next().unwrap());
@@ -605,7 +1063,7 @@ fn to_display_name(s: &str) -> String {
             // Add space before this char if:
             // - Previous was lowercase (start of new word), OR
             // - Previous was upper AND next is lower (end of acronym like "ID" before "Name")
-            if !prev_upper || (prev_upper && next_lower) {
+            if !prev_upper || next_lower {
                 result.push(' ');
             }
         }
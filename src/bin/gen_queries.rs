@@ -0,0 +1,1104 @@
+//! Code generator for the query path registry from OpenAPI spec.
+//!
+//! Usage:
+//!   cargo run --bin gen_queries -- paths > src/path_parser_generated.rs
+//!   cargo run --bin gen_queries -- dispatch > src/commands/query_generated.rs
+//!   cargo run --bin gen_queries -- responses > src/output/response_generated.rs
+//!   cargo run --bin gen_queries -- help > src/help_generated.rs
+//!
+//! This parses the GET operations in openapi.yaml (with openapi.overlay.yaml
+//! applied) and generates:
+//! - paths: EndpointType enum and the path grammar used by parse_path()
+//! - dispatch: execute_typed_query() mapping each endpoint to its client call,
+//!   api_request_path() mapping it to its API route (for --raw), and the
+//!   SNAPSHOT_ENDPOINTS table used to capture and answer from snapshots
+//! - responses: TypedResponse enum with to_json()/to_table()/write_ndjson(), ID kinds, columns and list helpers
+//! - help: QUERIES registry and PLAYER_RESOURCES completions
+
+use serde_yaml::Value;
+use std::collections::HashSet;
+
+/// API path prefixes that are exposed under a different name in the CLI
+const CLI_PREFIX_ALIASES: &[(&str, &str)] = &[("turn-summary", "events")];
+
+/// Path parameters that also accept a name, keyed by the segment before them,
+/// with the commands::lookup function that resolves the name
+const NAME_LOOKUPS: &[(&str, &str)] = &[
+    ("player", "player_index"),
+    ("city", "city_id"),
+    ("character", "character_id"),
+    ("tribe", "tribe_type"),
+];
+
+#[path = "../spec_overlay.rs"]
+mod spec_overlay;
+
+fn main() {
+    let target = std::env::args().nth(1).unwrap_or_default();
+
+    let spec: Value = spec_overlay::load_spec();
+
+    let endpoints = parse_endpoints(&spec);
+
+    match target.as_str() {
+        "paths" => generate_paths(&endpoints),
+        "dispatch" => generate_dispatch(&endpoints),
+        "responses" => generate_responses(&endpoints),
+        "help" => generate_help(&endpoints),
+        _ => {
+            eprintln!("Usage: gen_queries <paths|dispatch|responses|help>");
+            std::process::exit(2);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct EndpointDef {
+    variant: String,        // e.g., "PlayerUnits"
+    method: String,         // Client method, e.g., "get_player_units"
+    api_path: String,       // API route, e.g., "/player/{index}/units"
+    segments: Vec<Segment>, // CLI path segments, e.g., player/<index>/units
+    description: String,
+    query_params: Vec<QueryParam>,
+    response: ResponseType,
+    columns: Vec<String>, // JSON property names of the (item) schema, in spec order
+}
+
+#[derive(Debug)]
+enum Segment {
+    Literal(String),
+    Param {
+        name: String,
+        is_integer: bool,
+        lookup: Option<&'static str>,
+    },
+}
+
+#[derive(Debug)]
+struct QueryParam {
+    name: String,
+    default: Option<i64>,
+}
+
+#[derive(Debug)]
+enum ResponseType {
+    /// A single schema object
+    Single(String),
+    /// An array of schema objects
+    List(String),
+    /// A paginated wrapper - the named array property is unwrapped into a list
+    Paginated { item: String, property: String },
+}
+
+fn parse_endpoints(spec: &Value) -> Vec<EndpointDef> {
+    let schemas = &spec["components"]["schemas"];
+    let mut endpoints = Vec::new();
+
+    if let Value::Mapping(paths) = &spec["paths"] {
+        for (key, value) in paths {
+            let (Value::String(api_path), Some(op)) = (key, value.get("get")) else {
+                continue;
+            };
+
+            let Some(operation_id) = op["operationId"].as_str() else {
+                eprintln!("Warning: Skipping {} - no operationId", api_path);
+                continue;
+            };
+
+            let Some(response) = parse_response_type(
+                &op["responses"]["200"]["content"]["application/json"]["schema"],
+                schemas,
+            ) else {
+                eprintln!(
+                    "Warning: Skipping {} - unsupported response schema",
+                    api_path
+                );
+                continue;
+            };
+
+            let parameters: Vec<&Value> = op["parameters"]
+                .as_sequence()
+                .map(|seq| seq.iter().collect())
+                .unwrap_or_default();
+
+            let path_param_is_integer = |name: &str| {
+                parameters.iter().any(|p| {
+                    p["in"].as_str() == Some("path")
+                        && p["name"].as_str() == Some(name)
+                        && p["schema"]["type"].as_str() == Some("integer")
+                })
+            };
+
+            let raw_segments: Vec<&str> = api_path.trim_start_matches('/').split('/').collect();
+            let segments: Vec<Segment> = raw_segments
+                .iter()
+                .enumerate()
+                .map(
+                    |(i, seg)| match seg.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+                        Some(name) => Segment::Param {
+                            name: name.to_string(),
+                            is_integer: path_param_is_integer(name),
+                            lookup: i
+                                .checked_sub(1)
+                                .and_then(|prev| name_lookup(raw_segments[prev])),
+                        },
+                        None if i == 0 => Segment::Literal(cli_alias(seg).to_string()),
+                        None => Segment::Literal(seg.to_string()),
+                    },
+                )
+                .collect();
+
+            let mut query_params: Vec<QueryParam> = parameters
+                .iter()
+                .filter(|p| p["in"].as_str() == Some("query"))
+                .filter_map(|p| {
+                    Some(QueryParam {
+                        name: p["name"].as_str()?.to_string(),
+                        default: p["schema"]["default"].as_i64(),
+                    })
+                })
+                .collect();
+            // Progenitor orders query parameters alphabetically
+            query_params.sort_by(|a, b| a.name.cmp(&b.name));
+
+            let operation = operation_id.strip_prefix("get").unwrap_or(operation_id);
+
+            let item_schema = match &response {
+                ResponseType::Single(name)
+                | ResponseType::List(name)
+                | ResponseType::Paginated { item: name, .. } => &schemas[name.as_str()],
+            };
+            let columns = item_schema["properties"]
+                .as_mapping()
+                .map(|props| {
+                    props
+                        .keys()
+                        .filter_map(|k| k.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            endpoints.push(EndpointDef {
+                variant: to_pascal_case(operation),
+                method: to_snake_case(operation_id),
+                api_path: api_path.clone(),
+                segments,
+                description: describe(op["summary"].as_str().unwrap_or("")),
+                query_params,
+                response,
+                columns,
+            });
+        }
+    }
+
+    endpoints
+}
+
+fn parse_response_type(schema: &Value, schemas: &Value) -> Option<ResponseType> {
+    if let Some(name) = schema_ref_name(schema) {
+        // Paginated wrappers carry a `pagination` object next to a single array of items
+        let properties = schemas[name.as_str()]["properties"].as_mapping();
+        if let Some(props) = properties.filter(|p| p.contains_key("pagination")) {
+            for (prop_name, prop) in props {
+                if let (Value::String(prop_name), Some(item)) =
+                    (prop_name, schema_ref_name(&prop["items"]))
+                {
+                    return Some(ResponseType::Paginated {
+                        item,
+                        property: prop_name.clone(),
+                    });
+                }
+            }
+        }
+        return Some(ResponseType::Single(name));
+    }
+
+    if schema["type"].as_str() == Some("array") {
+        return schema_ref_name(&schema["items"]).map(ResponseType::List);
+    }
+
+    None
+}
+
+fn schema_ref_name(schema: &Value) -> Option<String> {
+    schema["$ref"]
+        .as_str()
+        .and_then(|r| r.split('/').next_back())
+        .map(String::from)
+}
+
+fn name_lookup(segment: &str) -> Option<&'static str> {
+    NAME_LOOKUPS
+        .iter()
+        .find(|(literal, _)| *literal == segment)
+        .map(|(_, lookup)| *lookup)
+}
+
+fn cli_alias(segment: &str) -> &str {
+    CLI_PREFIX_ALIASES
+        .iter()
+        .find(|(api, _)| *api == segment)
+        .map(|(_, cli)| *cli)
+        .unwrap_or(segment)
+}
+
+/// Turn an operation summary into a help description ("Get all players" -> "All players")
+fn describe(summary: &str) -> String {
+    let trimmed = summary.strip_prefix("Get ").unwrap_or(summary);
+    let mut chars = trimmed.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+impl EndpointDef {
+    /// Help pattern, e.g. "player/<index>/units"
+    fn pattern(&self) -> String {
+        self.segments
+            .iter()
+            .map(|s| match s {
+                Segment::Literal(lit) => lit.clone(),
+                Segment::Param { name, .. } => format!("<{}>", to_snake_case(name)),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Path parameters as (Rust name, is_integer, name lookup)
+    fn params(&self) -> Vec<(String, bool, Option<&'static str>)> {
+        self.segments
+            .iter()
+            .filter_map(|s| match s {
+                Segment::Param {
+                    name,
+                    is_integer,
+                    lookup,
+                } => Some((escape_keyword(&to_snake_case(name)), *is_integer, *lookup)),
+                Segment::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    fn is_list(&self) -> bool {
+        !matches!(self.response, ResponseType::Single(_))
+    }
+
+    /// Path parameter names as in the spec, e.g. ["tribeType"]
+    fn api_params(&self) -> Vec<&str> {
+        self.api_path
+            .split('/')
+            .filter_map(|seg| seg.strip_prefix('{')?.strip_suffix('}'))
+            .collect()
+    }
+
+    fn rust_type(&self) -> String {
+        match &self.response {
+            ResponseType::Single(name) => format!("types::{}", name),
+            ResponseType::List(name) | ResponseType::Paginated { item: name, .. } => {
+                format!("Vec<types::{}>", name)
+            }
+        }
+    }
+}
+
+fn generate_preamble(target: &str, out_path: &str, included_from: &str) {
+    println!(
+        "// This file is auto-generated by `cargo run --bin gen_queries -- {}`",
+        target
+    );
+    println!("// Do not edit manually. Regenerate with:");
+    println!(
+        "//   cargo run --bin gen_queries -- {} > {}",
+        target, out_path
+    );
+    println!("//");
+    println!("// Included via include!() in {}", included_from);
+    println!();
+}
+
+// === paths ===
+
+fn generate_paths(endpoints: &[EndpointDef]) {
+    generate_preamble("paths", "src/path_parser_generated.rs", "path_parser.rs");
+
+    println!(
+        "/// Query endpoints generated from OpenAPI spec ({} endpoints)",
+        endpoints.len()
+    );
+    println!("#[derive(Debug, Clone, PartialEq)]");
+    println!("pub enum EndpointType {{");
+    for ep in endpoints {
+        println!("    {},", ep.variant);
+    }
+    println!("}}");
+    println!();
+
+    println!("/// Match path segments against the query grammar, returning the endpoint and its parameters");
+    println!("fn match_endpoint(parts: &[&str]) -> Result<Option<(EndpointType, Vec<String>)>> {{");
+    println!("    let matched = match parts {{");
+    for ep in endpoints {
+        let pattern: Vec<String> = ep
+            .segments
+            .iter()
+            .map(|s| match s {
+                Segment::Literal(lit) => format!("\"{}\"", lit),
+                Segment::Param { name, .. } => escape_keyword(&to_snake_case(name)),
+            })
+            .collect();
+        let params = ep.params();
+
+        let values: Vec<String> = params
+            .iter()
+            .map(|(name, _, _)| format!("{}.to_string()", name))
+            .collect();
+        // Parameters that accept a name are resolved at dispatch time instead
+        let integer_params: Vec<&String> = params
+            .iter()
+            .filter(|(_, is_integer, lookup)| *is_integer && lookup.is_none())
+            .map(|(name, _, _)| name)
+            .collect();
+
+        if integer_params.is_empty() {
+            println!(
+                "        [{}] => (EndpointType::{}, vec![{}]),",
+                pattern.join(", "),
+                ep.variant,
+                values.join(", ")
+            );
+        } else {
+            println!("        [{}] => {{", pattern.join(", "));
+            for name in integer_params {
+                println!("            validate_integer({})?;", name);
+            }
+            println!(
+                "            (EndpointType::{}, vec![{}])",
+                ep.variant,
+                values.join(", ")
+            );
+            println!("        }}");
+        }
+    }
+    println!("        _ => return Ok(None),");
+    println!("    }};");
+    println!();
+    println!("    Ok(Some(matched))");
+    println!("}}");
+}
+
+// === dispatch ===
+
+fn generate_dispatch(endpoints: &[EndpointDef]) {
+    generate_preamble(
+        "dispatch",
+        "src/commands/query_generated.rs",
+        "commands/query.rs",
+    );
+
+    let uses_fields = endpoints
+        .iter()
+        .any(|ep| ep.query_params.iter().any(|q| q.name == "fields"));
+    let fields_arg = if uses_fields { "fields" } else { "_fields" };
+
+    println!("/// Execute a query for the given path, decoding the response into its schema type");
+    println!("pub async fn execute_typed_query(");
+    println!("    client: &ApiClient,");
+    println!("    path_str: &str,");
+    println!("    {}: Option<&str>,", fields_arg);
+    println!(") -> Result<TypedResponse> {{");
+    println!("    // --from-snapshot answers from the file instead of the API");
+    println!("    if let Some(snapshot) = &client.snapshot {{");
+    println!("        return snapshot.query(path_str);");
+    println!("    }}");
+    println!();
+    println!("    let api_path = parse_path(path_str)?;");
+    println!();
+    println!("    match api_path.endpoint_type {{");
+
+    for ep in endpoints {
+        let params = ep.params();
+
+        let mut args: Vec<String> = params
+            .iter()
+            .map(|(name, is_integer, lookup)| match lookup {
+                // Resolved string parameters are owned
+                Some(_) if !is_integer => format!("&{}", name),
+                _ => name.clone(),
+            })
+            .collect();
+        for q in &ep.query_params {
+            args.push(match (q.name.as_str(), q.default) {
+                ("fields", _) => "fields".to_string(),
+                (_, Some(default)) => format!("Some({})", default),
+                (_, None) => "None".to_string(),
+            });
+        }
+        let call = format!(
+            "fetch(client, || client.inner.{}({})).await?",
+            ep.method,
+            args.join(", ")
+        );
+
+        match &ep.response {
+            ResponseType::Paginated { property, .. } => {
+                println!("        EndpointType::{} => {{", ep.variant);
+                println!("            let response = {};", call);
+                println!(
+                    "            Ok(TypedResponse::{}(response.{}))",
+                    ep.variant, property
+                );
+                println!("        }}");
+            }
+            _ if params.is_empty() => {
+                println!(
+                    "        EndpointType::{} => Ok(TypedResponse::{}({})),",
+                    ep.variant, ep.variant, call
+                );
+            }
+            _ => {
+                println!("        EndpointType::{} => {{", ep.variant);
+                print_param_bindings(&params);
+                println!("            Ok(TypedResponse::{}({}))", ep.variant, call);
+                println!("        }}");
+            }
+        }
+    }
+
+    println!("    }}");
+    println!("}}");
+    println!();
+
+    println!("/// API route (with query string) for the given path, with names resolved to IDs");
+    println!("pub async fn api_request_path(");
+    println!("    client: &ApiClient,");
+    println!("    path_str: &str,");
+    println!("    {}: Option<&str>,", fields_arg);
+    println!(") -> Result<String> {{");
+    println!("    let api_path = parse_path(path_str)?;");
+    println!();
+    println!("    let route = match api_path.endpoint_type {{");
+
+    for ep in endpoints {
+        let params = ep.params();
+
+        // Path parameters become format arguments: "/player/{index}/units" -> "/player/{}/units"
+        let template: Vec<&str> = ep
+            .api_path
+            .split('/')
+            .map(|seg| if seg.starts_with('{') { "{}" } else { seg })
+            .collect();
+        let template = template.join("/");
+        let args: String = params
+            .iter()
+            .map(|(name, _, _)| format!(", {}", name))
+            .collect();
+        // Query parameters with a default are sent as the typed call sends them,
+        // so `--raw` gets the same page
+        let query = |with_fields: bool| {
+            let pairs: Vec<String> = ep
+                .query_params
+                .iter()
+                .filter_map(|q| match (q.name.as_str(), q.default) {
+                    ("fields", _) => with_fields.then(|| "fields={}".to_string()),
+                    (name, Some(default)) => Some(format!("{}={}", name, default)),
+                    (_, None) => None,
+                })
+                .collect();
+            match pairs.is_empty() {
+                true => String::new(),
+                false => format!("?{}", pairs.join("&")),
+            }
+        };
+        let route = if params.is_empty() {
+            format!("\"{}{}\".to_string()", template, query(false))
+        } else {
+            format!("format!(\"{}{}\"{})", template, query(false), args)
+        };
+        let route = if ep.query_params.iter().any(|q| q.name == "fields") {
+            format!(
+                "match fields {{ Some(fields) => format!(\"{}{}\"{}, encode_query_value(fields)), None => {} }}",
+                template,
+                query(true),
+                args,
+                route
+            )
+        } else {
+            route
+        };
+
+        if params.is_empty() {
+            println!("        EndpointType::{} => {},", ep.variant, route);
+        } else {
+            println!("        EndpointType::{} => {{", ep.variant);
+            print_param_bindings(&params);
+            println!("            {}", route);
+            println!("        }}");
+        }
+    }
+
+    println!("    }};");
+    println!("    Ok(route)");
+    println!("}}");
+    println!();
+
+    generate_snapshot_dispatch(endpoints);
+}
+
+/// SNAPSHOT_ENDPOINTS, snapshot_params() and decode_response() for `--from-snapshot`
+fn generate_snapshot_dispatch(endpoints: &[EndpointDef]) {
+    println!(
+        "/// How `owcli snapshot save` captures each endpoint and `--from-snapshot` answers it"
+    );
+    println!("pub const SNAPSHOT_ENDPOINTS: &[SnapshotEndpoint] = &[");
+    for ep in endpoints {
+        println!("    SnapshotEndpoint {{");
+        println!("        endpoint_type: EndpointType::{},", ep.variant);
+        println!("        route: \"{}\",", ep.api_path);
+        println!(
+            "        accepts_fields: {},",
+            ep.query_params.iter().any(|q| q.name == "fields")
+        );
+        println!("        source: {},", snapshot_source(ep, endpoints));
+        println!("    }},");
+    }
+    println!("];");
+    println!();
+
+    println!("/// Path parameters of a query, with names resolved from the snapshot");
+    println!(
+        "pub fn snapshot_params(snapshot: &Snapshot, api_path: &ApiPath) -> Result<Vec<String>> {{"
+    );
+    println!("    let params = match api_path.endpoint_type {{");
+    for ep in endpoints {
+        let values: Vec<String> = ep
+            .params()
+            .iter()
+            .enumerate()
+            .map(|(i, (_, is_integer, lookup))| match lookup {
+                Some(lookup) if *is_integer => format!(
+                    "snapshot.{}(api_path.str_param({})?)?.to_string()",
+                    lookup, i
+                ),
+                Some(lookup) => format!("snapshot.{}(api_path.str_param({})?)?", lookup, i),
+                None if *is_integer => format!("api_path.int_param({})?.to_string()", i),
+                None => format!("api_path.str_param({})?.to_string()", i),
+            })
+            .collect();
+        if values.is_empty() {
+            println!("        EndpointType::{} => Vec::new(),", ep.variant);
+        } else {
+            println!(
+                "        EndpointType::{} => vec![{}],",
+                ep.variant,
+                values.join(", ")
+            );
+        }
+    }
+    println!("    }};");
+    println!("    Ok(params)");
+    println!("}}");
+    println!();
+
+    println!("/// Decode an API response body into the schema type of its endpoint");
+    println!("pub fn decode_response(endpoint_type: &EndpointType, mut body: serde_json::Value) -> Result<TypedResponse> {{");
+    println!("    let response = match endpoint_type {{");
+    for ep in endpoints {
+        let body = match &ep.response {
+            ResponseType::Paginated { property, .. } => format!("body[\"{}\"].take()", property),
+            _ => "body".to_string(),
+        };
+        println!(
+            "        EndpointType::{} => TypedResponse::{}(decode_value({})?),",
+            ep.variant, ep.variant, body
+        );
+    }
+    println!("    }};");
+    println!("    Ok(response)");
+    println!("}}");
+}
+
+/// How a snapshot stores the response of `ep`: as captured, once per player,
+/// with its pages merged, as an item of a list endpoint with the same schema,
+/// as the items of such a list that refer to the entity (`/turn-summary/city/{id}`
+/// keeps the `/turn-summary/cities` events whose `cityId` is the ID). Snapshots
+/// answer every GET route, so an endpoint that fits none of these is an error.
+fn snapshot_source(ep: &EndpointDef, endpoints: &[EndpointDef]) -> String {
+    let keys = ep.api_params();
+
+    if let ResponseType::Paginated { property, .. } = &ep.response {
+        return format!("SnapshotSource::Paginated {{ items: \"{}\" }}", property);
+    }
+    if keys.is_empty() {
+        return "SnapshotSource::Route".to_string();
+    }
+    if keys.len() == 1 && ep.api_path.starts_with("/player/{") {
+        return "SnapshotSource::PerPlayer".to_string();
+    }
+
+    if let ResponseType::Single(item) = &ep.response {
+        let list = endpoints.iter().find(|other| {
+            other.api_params().is_empty()
+                && match &other.response {
+                    ResponseType::List(name) | ResponseType::Paginated { item: name, .. } => {
+                        name == item
+                    }
+                    ResponseType::Single(_) => false,
+                }
+        });
+        if let Some(list) =
+            list.filter(|list| keys.iter().all(|k| list.columns.iter().any(|c| c == k)))
+        {
+            let items = match &list.response {
+                ResponseType::Paginated { property, .. } => format!("Some(\"{}\")", property),
+                _ => "None".to_string(),
+            };
+            let keys: Vec<String> = keys.iter().map(|k| format!("\"{}\"", k)).collect();
+            return format!(
+                "SnapshotSource::Item {{ list: \"{}\", items: {}, keys: &[{}] }}",
+                list.api_path,
+                items,
+                keys.join(", ")
+            );
+        }
+    }
+
+    if let (ResponseType::List(item), [key]) = (&ep.response, keys.as_slice()) {
+        // The entity named by the segment before the parameter, e.g. `city` + `id`
+        let entity = ep.api_path.split('/').rev().nth(1).unwrap_or_default();
+        let column = format!("{}{}", entity, to_pascal_case(key));
+        let list = endpoints.iter().find(|other| {
+            other.api_params().is_empty()
+                && matches!(&other.response, ResponseType::List(name) if name == item)
+                && other.columns.contains(&column)
+        });
+        if let Some(list) = list {
+            return format!(
+                "SnapshotSource::Filter {{ list: \"{}\", key: \"{}\" }}",
+                list.api_path, column
+            );
+        }
+    }
+
+    panic!("No way to answer {} from a snapshot", ep.api_path)
+}
+
+/// Bind each path parameter of a dispatch arm, resolving names through commands::lookup
+fn print_param_bindings(params: &[(String, bool, Option<&str>)]) {
+    for (i, (name, is_integer, lookup)) in params.iter().enumerate() {
+        match lookup {
+            Some(lookup) => println!(
+                "            let {} = lookup::{}(client, api_path.str_param({})?).await?;",
+                name, lookup, i
+            ),
+            None => {
+                let getter = if *is_integer {
+                    "int_param"
+                } else {
+                    "str_param"
+                };
+                println!("            let {} = api_path.{}({})?;", name, getter, i);
+            }
+        }
+    }
+}
+
+// === responses ===
+
+fn generate_responses(endpoints: &[EndpointDef]) {
+    generate_preamble(
+        "responses",
+        "src/output/response_generated.rs",
+        "output/mod.rs",
+    );
+
+    println!("/// Typed response enum for all API endpoints");
+    println!("#[derive(Debug)]");
+    println!("#[allow(clippy::large_enum_variant)]");
+    println!("pub enum TypedResponse {{");
+    for ep in endpoints {
+        println!("    {}({}),", ep.variant, ep.rust_type());
+    }
+    println!("    /// Undecoded JSON, from --raw or a response that doesn't match the spec");
+    println!("    Raw(serde_json::Value),");
+    println!("}}");
+    println!();
+
+    println!("impl TypedResponse {{");
+    println!("    /// Convert to JSON string");
+    println!("    pub fn to_json(&self) -> Result<String> {{");
+    println!("        let json = match self {{");
+    for ep in endpoints {
+        println!(
+            "            Self::{}(v) => serde_json::to_string_pretty(v)?,",
+            ep.variant
+        );
+    }
+    println!("            Self::Raw(v) => serde_json::to_string_pretty(v)?,");
+    println!("        }};");
+    println!("        Ok(json)");
+    println!("    }}");
+    println!();
+
+    println!("    /// Format as table, rendering IDs with `names`");
+    println!("    pub fn to_table(&self, names: &Names) -> String {{");
+    println!("        match self {{");
+    for ep in endpoints {
+        let formatter = match &ep.response {
+            ResponseType::Single(name) => single_formatter_name(name),
+            ResponseType::List(name) | ResponseType::Paginated { item: name, .. } => {
+                list_formatter_name(name)
+            }
+        };
+        match formatter {
+            Some(f) => println!(
+                "            Self::{}(v) => table::{}(v, names),",
+                ep.variant, f
+            ),
+            // No generated formatter for this schema - fall back to JSON
+            None => println!(
+                "            Self::{}(v) => serde_json::to_string_pretty(v).unwrap_or_default(),",
+                ep.variant
+            ),
+        }
+    }
+    println!("            Self::Raw(v) => serde_json::to_string_pretty(v).unwrap_or_default(),");
+    println!("        }}");
+    println!("    }}");
+    println!();
+
+    println!("    /// Format as table showing only the selected fields");
+    println!("    pub fn to_table_fields(&self, fields: &[&str], names: &Names) -> String {{");
+    println!("        match self {{");
+    for ep in endpoints {
+        let formatter = match &ep.response {
+            ResponseType::Single(name) => {
+                single_formatter_name(name).map(|f| format!("{}_fields", f))
+            }
+            ResponseType::List(name) | ResponseType::Paginated { item: name, .. } => {
+                list_formatter_name(name).map(|f| format!("{}_columns", f))
+            }
+        };
+        match formatter {
+            Some(f) => println!(
+                "            Self::{}(v) => table::{}(v, fields, names),",
+                ep.variant, f
+            ),
+            // No generated formatter for this schema - fall back to projected JSON
+            None => println!(
+                "            Self::{}(v) => serde_json::to_value(v).map(|v| serde_json::to_string_pretty(&project(&v, fields)).unwrap_or_default()).unwrap_or_default(),",
+                ep.variant
+            ),
+        }
+    }
+    println!(
+        "            Self::Raw(v) => serde_json::to_string_pretty(&project(v, fields)).unwrap_or_default(),"
+    );
+    println!("        }}");
+    println!("    }}");
+    println!();
+
+    println!("    /// Kinds of IDs the table shows, of all fields or only the selected ones");
+    println!("    pub fn id_kinds(&self, fields: Option<&[&str]>) -> Needed {{");
+    println!("        match self {{");
+    for ep in endpoints {
+        let (ResponseType::Single(name)
+        | ResponseType::List(name)
+        | ResponseType::Paginated { item: name, .. }) = &ep.response;
+        let formatter = match &ep.response {
+            ResponseType::Single(name) => single_formatter_name(name),
+            _ => list_formatter_name(name),
+        };
+        match formatter {
+            Some(_) => println!(
+                "            Self::{}(_) => table::{}_id_kinds(fields),",
+                ep.variant,
+                to_snake_case(name)
+            ),
+            None => println!("            Self::{}(_) => Needed::NONE,", ep.variant),
+        }
+    }
+    println!("            Self::Raw(_) => Needed::NONE,");
+    println!("        }}");
+    println!("    }}");
+    println!();
+
+    println!("    /// Field names of the entity schema, used as columns for delimited output");
+    println!("    pub fn columns(&self) -> &'static [&'static str] {{");
+    println!("        match self {{");
+    for ep in endpoints {
+        let quoted: Vec<String> = ep.columns.iter().map(|c| format!("\"{}\"", c)).collect();
+        println!(
+            "            Self::{}(_) => &[{}],",
+            ep.variant,
+            quoted.join(", ")
+        );
+    }
+    println!("            Self::Raw(_) => &[],");
+    println!("        }}");
+    println!("    }}");
+    println!();
+
+    println!("    /// Convert to a JSON value");
+    println!("    pub fn to_value(&self) -> Result<serde_json::Value> {{");
+    println!("        let value = match self {{");
+    for ep in endpoints {
+        println!(
+            "            Self::{}(v) => serde_json::to_value(v)?,",
+            ep.variant
+        );
+    }
+    println!("            Self::Raw(v) => v.clone(),");
+    println!("        }};");
+    println!("        Ok(value)");
+    println!("    }}");
+    println!();
+
+    let lists: Vec<&EndpointDef> = endpoints.iter().filter(|ep| ep.is_list()).collect();
+
+    println!("    /// Whether this response is a list of entities");
+    println!("    pub fn is_list(&self) -> bool {{");
+    println!("        match self {{");
+    println!("            Self::Raw(v) => v.is_array(),");
+    println!("            _ => matches!(");
+    println!("                self,");
+    for (i, ep) in lists.iter().enumerate() {
+        let sep = if i == 0 { " " } else { "|" };
+        println!("                {} Self::{}(_)", sep, ep.variant);
+    }
+    println!("            ),");
+    println!("        }}");
+    println!("    }}");
+    println!();
+
+    println!(
+        "    /// Run an operation over the items of a list response (no-op for single entities)"
+    );
+    println!("    pub fn apply_list_op(&mut self, op: &impl ListOp) {{");
+    println!("        match self {{");
+    for ep in &lists {
+        println!("            Self::{}(v) => op.apply(v),", ep.variant);
+    }
+    println!("            Self::Raw(serde_json::Value::Array(v)) => op.apply(v),");
+    println!("            _ => {{}}");
+    println!("        }}");
+    println!("    }}");
+    println!();
+
+    println!("    /// Write one compact JSON line per entity (a single entity is one line)");
+    println!(
+        "    pub fn write_ndjson(&self, out: &mut impl std::io::Write, fields: Option<&[&str]>) -> Result<()> {{"
+    );
+    println!("        match self {{");
+    for ep in endpoints {
+        if ep.is_list() {
+            println!(
+                "            Self::{}(v) => write_lines(out, v, fields),",
+                ep.variant
+            );
+        } else {
+            println!(
+                "            Self::{}(v) => write_lines(out, std::slice::from_ref(v), fields),",
+                ep.variant
+            );
+        }
+    }
+    println!("            Self::Raw(serde_json::Value::Array(v)) => write_lines(out, v, fields),");
+    println!("            Self::Raw(v) => write_lines(out, std::slice::from_ref(v), fields),");
+    println!("        }}");
+    println!("    }}");
+    println!("}}");
+}
+
+// === help ===
+
+fn generate_help(endpoints: &[EndpointDef]) {
+    generate_preamble("help", "src/help_generated.rs", "help.rs");
+
+    println!("/// Central registry of all query paths - single source of truth");
+    println!("pub const QUERIES: &[QueryInfo] = &[");
+    for ep in endpoints {
+        println!("    QueryInfo {{");
+        println!("        path: \"{}\",", ep.pattern());
+        println!(
+            "        description: \"{}\",",
+            ep.description.replace('"', "\\\"")
+        );
+        println!("    }},");
+    }
+    println!("];");
+    println!();
+
+    // Player resource sub-paths are the literal tails of player/<index>/<resource>
+    let mut seen = HashSet::new();
+    println!("/// Player resource sub-paths for completion");
+    println!("pub const PLAYER_RESOURCES: &[&str] = &[");
+    for ep in endpoints {
+        if let [Segment::Literal(first), Segment::Param { .. }, Segment::Literal(resource)] =
+            ep.segments.as_slice()
+        {
+            if first == "player" && seen.insert(resource.clone()) {
+                println!("    \"{}\",", resource);
+            }
+        }
+    }
+    println!("];");
+}
+
+// === Formatter names (mirrors gen_formatters) ===
+
+fn single_formatter_name(name: &str) -> Option<String> {
+    if !has_formatter(name) || skip_single_formatter(name) {
+        return None;
+    }
+    Some(match name {
+        "GameState" => "format_state".to_string(),
+        "GameConfig" => "format_config".to_string(),
+        "MapMetadata" => "format_map".to_string(),
+        _ => format!("format_{}", to_snake_case(name)),
+    })
+}
+
+fn list_formatter_name(name: &str) -> Option<String> {
+    if !has_formatter(name) || !should_have_list_formatter(name) {
+        return None;
+    }
+    Some(match name {
+        "City" => "format_cities".to_string(),
+        "TeamDiplomacy" => "format_team_diplomacy".to_string(),
+        "TribeDiplomacy" => "format_tribe_diplomacy".to_string(),
+        "TeamAlliance" => "format_team_alliances".to_string(),
+        "TribeAlliance" => "format_tribe_alliances".to_string(),
+        "CharacterEvent" => "format_character_events".to_string(),
+        "CityEvent" => "format_city_events".to_string(),
+        "UnitEvent" => "format_unit_events".to_string(),
+        "WonderEvent" => "format_wonder_events".to_string(),
+        _ => format!("format_{}s", to_snake_case(name)),
+    })
+}
+
+/// Schemas that gen_formatters produces formatters for
+fn has_formatter(name: &str) -> bool {
+    matches!(
+        name,
+        "Player"
+            | "Unit"
+            | "City"
+            | "Character"
+            | "Tile"
+            | "Tribe"
+            | "Religion"
+            | "GameConfig"
+            | "GameState"
+            | "MapMetadata"
+            | "TeamDiplomacy"
+            | "TeamAlliance"
+            | "TribeDiplomacy"
+            | "TribeAlliance"
+            | "CharacterEvent"
+            | "CityEvent"
+            | "UnitEvent"
+            | "WonderEvent"
+            | "TurnSummary"
+            | "PlayerTechs"
+            | "PlayerFamilies"
+            | "PlayerReligion"
+            | "PlayerGoals"
+            | "PlayerDecisions"
+            | "PlayerLaws"
+            | "PlayerMissions"
+            | "PlayerResources"
+    )
+}
+
+fn should_have_list_formatter(name: &str) -> bool {
+    matches!(
+        name,
+        "Player"
+            | "Unit"
+            | "City"
+            | "Character"
+            | "Tile"
+            | "Tribe"
+            | "Religion"
+            | "TeamDiplomacy"
+            | "TeamAlliance"
+            | "TribeDiplomacy"
+            | "TribeAlliance"
+            | "CharacterEvent"
+            | "CityEvent"
+            | "UnitEvent"
+            | "WonderEvent"
+    )
+}
+
+fn skip_single_formatter(name: &str) -> bool {
+    matches!(
+        name,
+        "TeamDiplomacy"
+            | "TribeDiplomacy"
+            | "TeamAlliance"
+            | "TribeAlliance"
+            | "CharacterEvent"
+            | "CityEvent"
+            | "UnitEvent"
+            | "WonderEvent"
+    )
+}
+
+// === Naming helpers ===
+
+/// Convert camelCase (with optional dashes) to snake_case, matching progenitor's method names
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    let chars: Vec<char> = s.chars().collect();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '-' {
+            result.push('_');
+        } else if c.is_uppercase() {
+            let prev_upper = i > 0 && chars[i - 1].is_uppercase();
+            let next_lower = i + 1 < chars.len() && chars[i + 1].is_lowercase();
+            let after_separator = i > 0 && chars[i - 1] == '-';
+
+            if i > 0 && !after_separator && (!prev_upper || next_lower) {
+                result.push('_');
+            }
+            result.push(c.to_lowercase().next().unwrap());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn to_pascal_case(s: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = true;
+    for c in s.chars() {
+        if c == '_' || c == '-' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.push(c.to_uppercase().next().unwrap());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Escape Rust reserved keywords by appending an underscore
+fn escape_keyword(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn",
+        "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+        "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+        "use", "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do",
+        "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+    ];
+    if KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
@@ -1,33 +1,98 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
 use clap::{Parser, Subcommand};
 
+use crate::config::{ColorMode, OutputFormat};
+use crate::map::Layer;
+
 #[derive(Parser)]
 #[command(name = "owcli")]
 #[command(about = "CLI for the Old World game API")]
 #[command(version)]
 #[command(disable_help_subcommand = true)]
 pub struct Cli {
-    /// API host address
-    #[arg(long, global = true, env = "OWCLI_HOST", default_value = "localhost")]
-    pub host: String,
+    /// Profile from ~/.config/owcli/config.toml (default: the "default" profile, if any)
+    #[arg(long, global = true, env = "OWCLI_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Config file with profiles [default: ~/.config/owcli/config.toml]
+    #[arg(long, global = true, env = "OWCLI_CONFIG", value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// API host address [default: localhost]
+    #[arg(long, global = true, env = "OWCLI_HOST")]
+    pub host: Option<String>,
 
-    /// API port
-    #[arg(long, global = true, env = "OWCLI_PORT", default_value = "9877")]
-    pub port: u16,
+    /// API port [default: 9877]
+    #[arg(long, global = true, env = "OWCLI_PORT")]
+    pub port: Option<u16>,
 
-    /// Output raw JSON instead of tables
+    /// Output raw JSON instead of tables (same as --format json)
     #[arg(long, global = true, env = "OWCLI_JSON")]
     pub json: bool,
 
+    /// Output format for query results [default: table]
+    #[arg(long, global = true, env = "OWCLI_FORMAT", value_enum)]
+    pub format: Option<OutputFormat>,
+
+    /// Player (index or nation) that `me` stands for in query paths, e.g., player/me/units
+    #[arg(long, global = true, env = "OWCLI_PLAYER", value_name = "PLAYER")]
+    pub default_player: Option<String>,
+
+    /// Color map and watch output [default: auto]
+    #[arg(long, global = true, env = "OWCLI_COLOR", value_enum)]
+    pub color: Option<ColorMode>,
+
+    /// Show raw IDs in tables instead of resolving them to names (e.g., "17 (Roma)")
+    #[arg(long, global = true)]
+    pub no_resolve: bool,
+
+    /// Answer queries, map and tiles from a snapshot file instead of the live API
+    #[arg(long, global = true, env = "OWCLI_SNAPSHOT", value_name = "FILE")]
+    pub from_snapshot: Option<PathBuf>,
+
+    /// Show query responses as returned by the API, skipping typed decoding
+    #[arg(long, global = true)]
+    pub raw: bool,
+
+    /// Block until the game answers instead of failing, for at most TIMEOUT if given (e.g., --wait=2m)
+    #[arg(long, global = true, value_name = "TIMEOUT", num_args = 0..=1, require_equals = true, value_parser = parse_duration)]
+    pub wait: Option<Option<Duration>>,
+
+    /// Retry requests with backoff while the game is loading (503) or refusing connections [default: 0]
+    #[arg(long, global = true, env = "OWCLI_RETRIES")]
+    pub retries: Option<u32>,
+
+    /// HTTP request timeout (e.g., 30s, 2m) [default: 30s]
+    #[arg(long, global = true, env = "OWCLI_TIMEOUT", value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 
     /// API path to query (e.g., player/0/units, cities, tile/5/12)
-    #[arg(trailing_var_arg = true)]
     pub path: Vec<String>,
 
-    /// Comma-separated list of fields to include for tile queries (e.g., "x,y,terrain,height")
+    /// Comma-separated list of fields to show (e.g., "id,name,population"); tiles are also trimmed server-side
     #[arg(long, global = true)]
     pub fields: Option<String>,
+
+    /// Filter list results client-side (e.g., 'player==0 && isSleep && hp<hpMax')
+    #[arg(long = "where", global = true, value_name = "EXPR")]
+    pub filter: Option<String>,
+
+    /// Sort list results by a field, descending with ":desc" (e.g., strengthRating:desc)
+    #[arg(long, global = true, value_name = "FIELD[:desc]")]
+    pub sort_by: Option<String>,
+
+    /// Number of list items to skip
+    #[arg(long, global = true)]
+    pub offset: Option<u32>,
+
+    /// Maximum number of list items to show
+    #[arg(long, global = true)]
+    pub limit: Option<u32>,
 }
 
 #[derive(Subcommand)]
@@ -40,14 +105,6 @@ pub enum Commands {
 
     /// Fetch all tiles (or use --offset/--limit for manual pagination)
     Tiles {
-        /// Offset for pagination (enables manual pagination mode)
-        #[arg(long)]
-        offset: Option<u32>,
-
-        /// Limit for pagination (enables manual pagination mode)
-        #[arg(long)]
-        limit: Option<u32>,
-
         /// Comma-separated list of fields to include (e.g., "x,y,terrain,height")
         #[arg(long)]
         fields: Option<String>,
@@ -72,9 +129,128 @@ pub enum Commands {
     },
 
     /// Render a hex map of the game world
-    Map,
+    Map {
+        /// Browse the map full-screen: pan with the arrow keys and inspect tiles
+        #[arg(long)]
+        interactive: bool,
+
+        /// Color tiles by a layer instead of nation and terrain, with a legend
+        #[arg(long, value_enum, conflicts_with = "interactive")]
+        layer: Option<Layer>,
+
+        /// Color tiles on a gradient of a numeric tile field (e.g., tradeOutpostIncome, latitude)
+        #[arg(long, value_name = "FIELD", conflicts_with_all = ["interactive", "layer"])]
+        heat: Option<String>,
+
+        /// Write the map to a file instead: a hex map with cities and rivers (.svg), or a page showing tile details on hover (.html)
+        #[arg(long, short, value_name = "FILE", conflicts_with_all = ["interactive", "layer", "heat"])]
+        output: Option<PathBuf>,
+
+        /// Draw unit icons in the --output file
+        #[arg(long, requires = "output")]
+        units: bool,
+    },
+
+    /// Re-run a query on a timer and highlight what changed
+    Watch {
+        /// API path to query (e.g., player/0/resources)
+        #[arg(required = true)]
+        path: Vec<String>,
+
+        /// Time between polls (e.g., 2s, 500ms, 1m)
+        #[arg(long, default_value = "2s", value_parser = parse_duration)]
+        interval: Duration,
+    },
+
+    /// Compare two snapshots (or a snapshot and the running game) entity by entity
+    Diff {
+        /// Earlier snapshot or `owcli --json state` dump
+        before: PathBuf,
+
+        /// Later snapshot or state dump
+        #[arg(required_unless_present = "live")]
+        after: Option<PathBuf>,
+
+        /// Compare BEFORE against the running game instead of a second file
+        #[arg(long, conflicts_with = "after")]
+        live: bool,
+    },
+
+    /// Serve a snapshot as a mock game API on --host/--port
+    MockServer {
+        /// Snapshot file saved with `owcli snapshot save`
+        #[arg(long, value_name = "FILE")]
+        snapshot: PathBuf,
+    },
+
+    /// Check live API responses against the OpenAPI spec (Markdown, or JSON with --json)
+    ValidateApi {
+        /// Write the report to a file instead of stdout
+        #[arg(long, short, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Diagnose the connection: reachability, loaded game, latency and API version
+    Doctor,
+
+    /// Save the game to a file for offline queries (--from-snapshot)
+    Snapshot {
+        #[command(subcommand)]
+        action: SnapshotAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SnapshotAction {
+    /// Capture state, tiles, player details and config into a JSON file
+    Save {
+        /// File to write (e.g., game.json)
+        file: PathBuf,
+    },
+}
+
+/// Parse a positive duration like "2s", "500ms" or "1m"; a bare number is seconds
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}' (e.g., 2s, 500ms, 1m)", s))?;
+
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("unknown duration unit '{}' (use ms, s or m)", unit)),
+    };
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(duration) if duration.is_zero() => Err("duration must be greater than zero".to_string()),
+        Ok(duration) => Ok(duration),
+        Err(_) => Err(format!("duration '{}' is too long", s)),
+    }
 }
 
 // Action enum and to_game_command() impl are generated from openapi.yaml
 // Regenerate with: cargo run --bin gen > src/cli_generated.rs
 include!("cli_generated.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_duration("2h").is_err());
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("0s").is_err());
+        assert!(parse_duration("0").is_err());
+        assert!(parse_duration("99999999999999999999m").is_err());
+    }
+}
@@ -1,6 +1,9 @@
-use crate::config::Config;
+use crate::config::{Config, Retry};
 use crate::error::{OwcliError, Result};
+use crate::snapshot::Snapshot;
+use serde::de::DeserializeOwned;
 use std::future::Future;
+use std::time::{Duration, Instant};
 
 #[allow(dead_code)]
 mod generated {
@@ -11,29 +14,172 @@ pub use generated::types;
 
 pub struct ApiClient {
     pub inner: generated::Client,
+    /// Offline game data loaded from `--from-snapshot`
+    pub snapshot: Option<Snapshot>,
+    /// Return queries as raw JSON instead of decoding them (`--raw`)
+    pub raw: bool,
+    /// Player that `me` stands for in query paths
+    pub default_player: Option<String>,
+    retry: Retry,
 }
 
+/// First delay between attempts; doubles up to MAX_BACKOFF
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(2);
+
 impl ApiClient {
     pub fn new(config: &Config) -> Result<Self> {
-        let http_client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .build()?;
+        let http_client = reqwest::Client::builder().timeout(config.timeout).build()?;
 
         let base_url = config.base_url();
         let inner = generated::Client::new_with_client(&base_url, http_client);
+        let snapshot = config.snapshot.as_deref().map(Snapshot::load).transpose()?;
+
+        Ok(Self {
+            inner,
+            snapshot,
+            raw: config.raw,
+            default_player: config.default_player.clone(),
+            retry: config.retry,
+        })
+    }
+
+    /// Run a request, retrying with backoff while the game is loading (503)
+    /// or not accepting connections, as allowed by `--retries` and `--wait`
+    pub async fn with_retry<T, F, Fut>(&self, mut request: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        // checked_add: a bare --wait waits indefinitely
+        let deadline = self.retry.wait.map(|wait| Instant::now().checked_add(wait));
+        let mut backoff = INITIAL_BACKOFF;
+        let mut attempt = 0;
+
+        loop {
+            let err = match request().await {
+                Err(err) if is_retryable(&err) => err,
+                result => return result,
+            };
+            attempt += 1;
+
+            let may_retry = match deadline {
+                Some(None) => true,
+                Some(Some(deadline)) => Instant::now() + backoff < deadline,
+                None => attempt <= self.retry.retries,
+            };
+            if !may_retry {
+                return Err(err);
+            }
+            if attempt == 1 {
+                eprintln!("{}; retrying...", err);
+            }
+
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
 
-        Ok(Self { inner })
+    /// GET an API route as undecoded JSON
+    pub async fn get_json(&self, route: &str) -> Result<serde_json::Value> {
+        let url = format!("{}{}", self.inner.baseurl(), route);
+        self.with_retry(|| async {
+            let response = self.inner.client().get(&url).send().await?;
+            match response.status().as_u16() {
+                503 => Err(OwcliError::GameUnavailable),
+                404 => Err(OwcliError::NotFound(response.text().await?)),
+                code if code >= 400 => Err(OwcliError::Api {
+                    message: response.text().await?,
+                    code: Some(code),
+                }),
+                _ => Ok(response.json().await?),
+            }
+        })
+        .await
     }
 }
 
-/// Fetch helper - awaits a progenitor call, extracts response and maps errors
-pub async fn fetch<T, E: std::fmt::Debug>(
-    future: impl Future<Output = std::result::Result<progenitor_client::ResponseValue<T>, progenitor_client::Error<E>>>,
-) -> Result<T> {
-    future
+/// Percent-encode a query parameter value, e.g. `fields`
+pub fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Errors worth retrying: nothing reached the game, so nothing was executed
+fn is_retryable(err: &OwcliError) -> bool {
+    match err {
+        OwcliError::GameUnavailable | OwcliError::Unreachable(_) => true,
+        OwcliError::Http(e) => e.is_connect(),
+        _ => false,
+    }
+}
+
+/// Fetch helper - runs a progenitor call (retrying as configured), extracts
+/// the response and maps errors
+pub async fn fetch<T, E, F, Fut>(client: &ApiClient, request: F) -> Result<T>
+where
+    T: DeserializeOwned,
+    E: std::fmt::Debug,
+    F: Fn() -> Fut,
+    Fut: Future<
+        Output = std::result::Result<
+            progenitor_client::ResponseValue<T>,
+            progenitor_client::Error<E>,
+        >,
+    >,
+{
+    client
+        .with_retry(|| async {
+            request()
+                .await
+                .map(|r| r.into_inner())
+                .map_err(|err| match err {
+                    progenitor_client::Error::InvalidResponsePayload(body, e) => {
+                        decode_error::<T>(&body, e)
+                    }
+                    err => map_progenitor_error(err),
+                })
+        })
         .await
-        .map(|r| r.into_inner())
-        .map_err(map_progenitor_error)
+}
+
+/// Describe a response that doesn't match the spec, naming the failing field
+/// and keeping the raw JSON so it can still be shown
+fn decode_error<T: DeserializeOwned>(body: &[u8], error: serde_json::Error) -> OwcliError {
+    let Ok(raw) = serde_json::from_slice(body) else {
+        // Not JSON at all
+        return OwcliError::Json(error);
+    };
+    let deserializer = &mut serde_json::Deserializer::from_slice(body);
+    let field = match serde_path_to_error::deserialize::<_, T>(deserializer) {
+        Err(e) => e.path().to_string(),
+        Ok(_) => ".".to_string(),
+    };
+    OwcliError::Decode {
+        field,
+        message: error.to_string(),
+        raw,
+    }
+}
+
+/// Decode JSON fetched or stored without decoding, reporting a mismatch with
+/// the spec the same way as `fetch`
+pub fn decode_value<T: DeserializeOwned>(raw: serde_json::Value) -> Result<T> {
+    match serde_path_to_error::deserialize(&raw) {
+        Ok(value) => Ok(value),
+        Err(e) => Err(OwcliError::Decode {
+            field: e.path().to_string(),
+            message: e.inner().to_string(),
+            raw,
+        }),
+    }
 }
 
 fn map_progenitor_error<T: std::fmt::Debug>(err: progenitor_client::Error<T>) -> OwcliError {
@@ -49,6 +195,9 @@ fn map_progenitor_error<T: std::fmt::Debug>(err: progenitor_client::Error<T>) ->
                 },
             }
         }
+        progenitor_client::Error::CommunicationError(e) if e.is_connect() => {
+            OwcliError::Unreachable(e.to_string())
+        }
         progenitor_client::Error::CommunicationError(_) => {
             OwcliError::Other(format!("Communication error: {:?}", err))
         }
@@ -56,7 +205,72 @@ fn map_progenitor_error<T: std::fmt::Debug>(err: progenitor_client::Error<T>) ->
     }
 }
 
+/// Refuse commands that would change the game while reading a snapshot
+pub fn ensure_live(client: &ApiClient) -> Result<()> {
+    match client.snapshot {
+        Some(_) => Err(OwcliError::Other(
+            "Commands cannot run against a snapshot; drop --from-snapshot".into(),
+        )),
+        None => Ok(()),
+    }
+}
+
 /// Helper to check if a command succeeded
 pub fn command_succeeded(result: &types::CommandResult) -> bool {
     result.success.unwrap_or(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_error() {
+        let body = br#"[{"id": 1}, {"id": 2, "promotions": "PROMOTION_FIERCE"}]"#;
+        let error = serde_json::from_slice::<Vec<types::Unit>>(body).unwrap_err();
+
+        match decode_error::<Vec<types::Unit>>(body, error) {
+            OwcliError::Decode { field, raw, .. } => {
+                assert_eq!(field, "[1].promotions");
+                assert_eq!(raw[1]["promotions"], "PROMOTION_FIERCE");
+            }
+            other => panic!("expected Decode, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_encode_query_value() {
+        assert_eq!(encode_query_value("x,y,terrain"), "x%2Cy%2Cterrain");
+        assert_eq!(encode_query_value("a&b=c #d"), "a%26b%3Dc%20%23d");
+    }
+
+    #[tokio::test]
+    async fn test_with_retry() {
+        let mut config = Config::new("localhost".into(), 9877, Default::default(), false);
+        config.retry.retries = 2;
+        let client = ApiClient::new(&config).unwrap();
+
+        let attempts = std::cell::Cell::new(0);
+        let result = client
+            .with_retry(|| async {
+                attempts.set(attempts.get() + 1);
+                match attempts.get() {
+                    1 | 2 => Err(OwcliError::GameUnavailable),
+                    n => Ok(n),
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 3);
+
+        // Other errors are returned at once
+        attempts.set(0);
+        let result: Result<()> = client
+            .with_retry(|| async {
+                attempts.set(attempts.get() + 1);
+                Err(OwcliError::NotFound("city".into()))
+            })
+            .await;
+        assert!(matches!(result, Err(OwcliError::NotFound(_))));
+        assert_eq!(attempts.get(), 1);
+    }
+}
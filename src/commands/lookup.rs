@@ -0,0 +1,211 @@
+//! Name-based lookup for query path parameters
+//!
+//! `city/Roma`, `player/rome`, `character/"Hannibal"` and `tribe/gauls` resolve
+//! to IDs by fetching the matching list endpoint. Names match case-insensitively;
+//! numeric parameters are used as-is without a request.
+
+use std::fmt::Display;
+
+use crate::client::{fetch, types, ApiClient};
+use crate::error::{OwcliError, Result};
+use crate::output::names::{self, character_name, player_name};
+
+/// Resolve a player index or nation (e.g., "rome", "NATION_ROME")
+pub async fn player_index(client: &ApiClient, param: &str) -> Result<i64> {
+    if let Ok(index) = unquote(param).parse() {
+        return Ok(index);
+    }
+    find_player(
+        &fetch(client, || client.inner.get_players())
+            .await
+            .map_err(lookup_error)?,
+        param,
+    )
+}
+
+/// Resolve a city ID or name
+pub async fn city_id(client: &ApiClient, param: &str) -> Result<i64> {
+    if let Ok(id) = unquote(param).parse() {
+        return Ok(id);
+    }
+    find_city(
+        &fetch(client, || client.inner.get_cities())
+            .await
+            .map_err(lookup_error)?,
+        param,
+    )
+}
+
+/// Resolve a character ID or name
+pub async fn character_id(client: &ApiClient, param: &str) -> Result<i64> {
+    if let Ok(id) = unquote(param).parse() {
+        return Ok(id);
+    }
+    find_character(
+        &fetch(client, || client.inner.get_characters())
+            .await
+            .map_err(lookup_error)?,
+        param,
+    )
+}
+
+/// Resolve a tribe type (e.g., "TRIBE_GAULS") or its short name ("gauls")
+pub async fn tribe_type(client: &ApiClient, param: &str) -> Result<String> {
+    let name = unquote(param);
+    if name.to_uppercase().starts_with("TRIBE_") {
+        return Ok(name.to_uppercase());
+    }
+    find_tribe(
+        &fetch(client, || client.inner.get_tribes())
+            .await
+            .map_err(lookup_error)?,
+        param,
+    )
+}
+
+/// A list that fails to decode can't be searched; report it as an error so
+/// queries don't fall back to showing the raw list instead of the entity asked for
+fn lookup_error(err: OwcliError) -> OwcliError {
+    match err {
+        OwcliError::Decode { field, message, .. } => OwcliError::Other(format!(
+            "Cannot look up names: response doesn't match the API spec at '{}': {}",
+            field, message
+        )),
+        err => err,
+    }
+}
+
+/// Find a player by index or nation in an already fetched list
+pub fn find_player(players: &[types::Player], param: &str) -> Result<i64> {
+    let name = unquote(param);
+    if let Ok(index) = name.parse() {
+        return Ok(index);
+    }
+    let candidates = players.iter().enumerate().map(|(i, p)| {
+        let aliases = player_name(p).into_iter().chain(p.nation.clone()).collect();
+        (names::player_index(p, i), aliases)
+    });
+    find_unique("player", name, candidates)
+}
+
+/// Find a city by ID or name in an already fetched list
+pub fn find_city(cities: &[types::City], param: &str) -> Result<i64> {
+    let name = unquote(param);
+    if let Ok(id) = name.parse() {
+        return Ok(id);
+    }
+    let candidates = cities
+        .iter()
+        .filter_map(|c| Some((c.id? as i64, vec![c.name.clone()?])));
+    find_unique("city", name, candidates)
+}
+
+/// Find a character by ID or name in an already fetched list
+pub fn find_character(characters: &[types::Character], param: &str) -> Result<i64> {
+    let name = unquote(param);
+    if let Ok(id) = name.parse() {
+        return Ok(id);
+    }
+    let candidates = characters
+        .iter()
+        .filter_map(|c| Some((c.id? as i64, vec![character_name(c)?])));
+    find_unique("character", name, candidates)
+}
+
+/// Find a tribe by type or short name in an already fetched list
+pub fn find_tribe(tribes: &[types::Tribe], param: &str) -> Result<String> {
+    let name = unquote(param);
+    let candidates = tribes.iter().filter_map(|t| {
+        let tribe_type = t.tribe_type.clone()?;
+        let short = tribe_type.trim_start_matches("TRIBE_").replace('_', " ");
+        Some((tribe_type.clone(), vec![tribe_type, short]))
+    });
+    find_unique("tribe", name, candidates)
+}
+
+/// Strip shell-style quotes kept around names (e.g., `"Hannibal"`)
+fn unquote(param: &str) -> &str {
+    let param = param.trim();
+    ['"', '\'']
+        .iter()
+        .find_map(|q| param.strip_prefix(*q).and_then(|p| p.strip_suffix(*q)))
+        .unwrap_or(param)
+}
+
+/// Find the one candidate with a name matching `name` (already unquoted)
+/// case-insensitively
+fn find_unique<K: Display>(
+    kind: &str,
+    name: &str,
+    candidates: impl Iterator<Item = (K, Vec<String>)>,
+) -> Result<K> {
+    let mut matches: Vec<(K, String)> = candidates
+        .filter_map(|(key, names)| {
+            names
+                .into_iter()
+                .find(|n| n.eq_ignore_ascii_case(name))
+                .map(|n| (key, n))
+        })
+        .collect();
+
+    match matches.len() {
+        0 => Err(OwcliError::NotFound(format!(
+            "No {} named '{}'",
+            kind, name
+        ))),
+        1 => Ok(matches.remove(0).0),
+        _ => {
+            let listed: Vec<String> = matches
+                .iter()
+                .map(|(key, n)| format!("{} ({})", key, n))
+                .collect();
+            Err(OwcliError::InvalidPath(format!(
+                "'{}' matches {} {}s: {}. Use an ID instead.",
+                name,
+                matches.len(),
+                kind,
+                listed.join(", ")
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates() -> impl Iterator<Item = (i64, Vec<String>)> {
+        vec![
+            (12, vec!["Hannibal".to_string()]),
+            (40, vec!["Hannibal".to_string()]),
+            (7, vec!["Dido".to_string()]),
+        ]
+        .into_iter()
+    }
+
+    #[test]
+    fn test_find_unique() {
+        assert_eq!(find_unique("character", "dido", candidates()).unwrap(), 7);
+
+        let err = find_unique("character", "Hannibal", candidates()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid path: 'Hannibal' matches 2 characters: 12 (Hannibal), 40 (Hannibal). Use an ID instead."
+        );
+
+        assert!(matches!(
+            find_unique("character", "Pyrrhus", candidates()),
+            Err(OwcliError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_quoted_params() {
+        let cities: Vec<types::City> =
+            serde_json::from_value(serde_json::json!([{"id": 17, "name": "Roma"}])).unwrap();
+        // A quoted number is still an ID, not a name
+        assert_eq!(find_city(&cities, "\"17\"").unwrap(), 17);
+        assert_eq!(find_city(&[], "'17'").unwrap(), 17);
+        assert_eq!(find_city(&cities, "\"ROMA\"").unwrap(), 17);
+    }
+}
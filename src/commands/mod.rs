@@ -1,4 +1,5 @@
 pub mod actions;
+pub mod lookup;
 pub mod query;
 
 pub use actions::execute_action;
@@ -1,169 +1,95 @@
-use crate::client::{fetch, ApiClient};
+use super::lookup;
+use crate::client::{decode_value, encode_query_value, fetch, ApiClient};
 use crate::error::{OwcliError, Result};
 use crate::output::TypedResponse;
-use crate::path_parser::{parse_path, EndpointType};
+use crate::path_parser::{parse_path, substitute_player, ApiPath, EndpointType};
+use crate::snapshot::{Snapshot, SnapshotEndpoint, SnapshotSource};
+
+// execute_typed_query(), api_request_path() and the snapshot tables are generated from openapi.yaml
+// Regenerate with: cargo run --bin gen_queries -- dispatch > src/commands/query_generated.rs
+include!("query_generated.rs");
 
 /// Execute a query for the given path
+///
+/// A response that doesn't match the spec is returned as raw JSON with a
+/// warning naming the failing field. With `--raw`, decoding is skipped.
 pub async fn execute_query(
     client: &ApiClient,
     path_str: &str,
     fields: Option<&str>,
 ) -> Result<TypedResponse> {
-    let api_path = parse_path(path_str)?;
-
-    match api_path.endpoint_type {
-        EndpointType::State => Ok(TypedResponse::State(fetch(client.inner.get_state()).await?)),
-        EndpointType::Config => Ok(TypedResponse::Config(fetch(client.inner.get_config()).await?)),
-        EndpointType::Players => Ok(TypedResponse::Players(fetch(client.inner.get_players()).await?)),
-        EndpointType::Player => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::Player(fetch(client.inner.get_player(index as i64)).await?))
-        }
-        EndpointType::PlayerUnits => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerUnits(
-                fetch(client.inner.get_player_units(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerTechs => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerTechs(
-                fetch(client.inner.get_player_techs(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerFamilies => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerFamilies(
-                fetch(client.inner.get_player_families(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerReligion => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerReligion(
-                fetch(client.inner.get_player_religion(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerGoals => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerGoals(
-                fetch(client.inner.get_player_goals(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerDecisions => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerDecisions(
-                fetch(client.inner.get_player_decisions(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerLaws => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerLaws(
-                fetch(client.inner.get_player_laws(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerMissions => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerMissions(
-                fetch(client.inner.get_player_missions(index as i64)).await?,
-            ))
-        }
-        EndpointType::PlayerResources => {
-            let index = extract_index(&api_path.path, "player")?;
-            Ok(TypedResponse::PlayerResources(
-                fetch(client.inner.get_player_resources(index as i64)).await?,
-            ))
-        }
-        EndpointType::Cities => Ok(TypedResponse::Cities(fetch(client.inner.get_cities()).await?)),
-        EndpointType::City => {
-            let id = extract_index(&api_path.path, "city")?;
-            Ok(TypedResponse::City(fetch(client.inner.get_city(id as i64)).await?))
-        }
-        EndpointType::Characters => Ok(TypedResponse::Characters(fetch(client.inner.get_characters()).await?)),
-        EndpointType::Character => {
-            let id = extract_index(&api_path.path, "character")?;
-            Ok(TypedResponse::Character(fetch(client.inner.get_character(id as i64)).await?))
-        }
-        EndpointType::Units => Ok(TypedResponse::Units(fetch(client.inner.get_units()).await?)),
-        EndpointType::Unit => {
-            let id = extract_index(&api_path.path, "unit")?;
-            Ok(TypedResponse::Unit(fetch(client.inner.get_unit(id as i64)).await?))
-        }
-        EndpointType::Map => Ok(TypedResponse::Map(fetch(client.inner.get_map()).await?)),
-        EndpointType::Tiles => {
-            // Default pagination
-            let response = fetch(client.inner.get_tiles(fields, Some(100), Some(0))).await?;
-            Ok(TypedResponse::Tiles(response.tiles))
-        }
-        EndpointType::Tile => {
-            // Parse tile path - could be tile/<id> or tile/<x>/<y>
-            let parts: Vec<&str> = api_path.path.split('/').collect();
-            match parts.as_slice() {
-                ["tile", id] => {
-                    let tile_id = id
-                        .parse::<i32>()
-                        .map_err(|_| OwcliError::InvalidPath(format!("Invalid tile ID: {}", id)))?;
-                    Ok(TypedResponse::Tile(
-                        fetch(client.inner.get_tile(tile_id as i64, fields)).await?,
-                    ))
-                }
-                ["tile", x, y] => {
-                    let x_coord = x.parse::<i32>().map_err(|_| {
-                        OwcliError::InvalidPath(format!("Invalid x coordinate: {}", x))
-                    })?;
-                    let y_coord = y.parse::<i32>().map_err(|_| {
-                        OwcliError::InvalidPath(format!("Invalid y coordinate: {}", y))
-                    })?;
-                    Ok(TypedResponse::Tile(
-                        fetch(client.inner.get_tile_by_coords(x_coord as i64, y_coord as i64, fields))
-                            .await?,
-                    ))
-                }
-                _ => Err(OwcliError::InvalidPath(format!(
-                    "Invalid tile path: {}",
-                    api_path.path
-                ))),
-            }
-        }
-        EndpointType::Tribes => Ok(TypedResponse::Tribes(fetch(client.inner.get_tribes()).await?)),
-        EndpointType::Tribe => {
-            let tribe_type = extract_string_param(&api_path.path, "tribe")?;
-            Ok(TypedResponse::Tribe(fetch(client.inner.get_tribe(&tribe_type)).await?))
-        }
-        EndpointType::Religions => Ok(TypedResponse::Religions(fetch(client.inner.get_religions()).await?)),
-        EndpointType::TeamDiplomacy => Ok(TypedResponse::TeamDiplomacy(
-            fetch(client.inner.get_diplomacy_teams()).await?,
-        )),
-        EndpointType::TeamAlliances => Ok(TypedResponse::TeamAlliances(
-            fetch(client.inner.get_alliances_teams()).await?,
-        )),
-        EndpointType::TribeDiplomacy => Ok(TypedResponse::TribeDiplomacy(
-            fetch(client.inner.get_diplomacy_tribes()).await?,
-        )),
-        EndpointType::TribeAlliances => Ok(TypedResponse::TribeAlliances(
-            fetch(client.inner.get_alliances_tribes()).await?,
-        )),
-        EndpointType::CharacterEvents => Ok(TypedResponse::CharacterEvents(
-            fetch(client.inner.get_turn_summary_characters()).await?,
-        )),
-        EndpointType::UnitEvents => Ok(TypedResponse::UnitEvents(
-            fetch(client.inner.get_turn_summary_units()).await?,
-        )),
-        EndpointType::CityEvents => Ok(TypedResponse::CityEvents(
-            fetch(client.inner.get_turn_summary_cities()).await?,
-        )),
+    let path = substitute_player(path_str, client.default_player.as_deref())?;
+    let path_str = path.as_str();
+    if client.raw {
+        return execute_raw_query(client, path_str, fields).await;
+    }
+
+    match execute_typed_query(client, path_str, fields).await {
+        Err(OwcliError::Decode {
+            field,
+            message,
+            raw,
+        }) => {
+            eprintln!(
+                "Warning: {} doesn't match the API spec at '{}' ({}); showing raw JSON",
+                path_str, field, message
+            );
+            Ok(TypedResponse::Raw(raw))
+        }
+        result => result,
     }
 }
 
+/// Fetch the JSON for a query path without decoding it
+async fn execute_raw_query(
+    client: &ApiClient,
+    path_str: &str,
+    fields: Option<&str>,
+) -> Result<TypedResponse> {
+    if let Some(snapshot) = &client.snapshot {
+        return Ok(TypedResponse::Raw(snapshot.query_json(path_str)?));
+    }
+
+    let route = api_request_path(client, path_str, fields).await?;
+    Ok(TypedResponse::Raw(client.get_json(&route).await?))
+}
+
 /// Execute a query for tiles with pagination
+///
+/// Like `execute_query`, `--raw` and responses that don't match the spec give
+/// the tiles as raw JSON.
 pub async fn execute_tiles_query(
     client: &ApiClient,
     offset: u32,
     limit: u32,
     fields: Option<&str>,
 ) -> Result<TypedResponse> {
-    let response =
-        fetch(client.inner.get_tiles(fields, Some(limit as i64), Some(offset as i64))).await?;
-    Ok(TypedResponse::Tiles(response.tiles))
+    if let Some(snapshot) = &client.snapshot {
+        let tiles = snapshot
+            .tiles()?
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize);
+        return Ok(TypedResponse::Tiles(tiles.collect()));
+    }
+    if client.raw {
+        return get_raw_tiles(client, offset as i64, limit as i64, fields).await;
+    }
+
+    let response = fetch(client, || {
+        client
+            .inner
+            .get_tiles(fields, Some(limit as i64), Some(offset as i64))
+    })
+    .await;
+    match response {
+        Ok(response) => Ok(TypedResponse::Tiles(response.tiles)),
+        Err(OwcliError::Decode { field, message, .. }) => {
+            warn_raw_tiles(&field, &message);
+            get_raw_tiles(client, offset as i64, limit as i64, fields).await
+        }
+        Err(e) => Err(e),
+    }
 }
 
 /// Execute a query for all tiles (parallel batched fetching)
@@ -173,62 +99,138 @@ pub async fn execute_all_tiles_query(
 ) -> Result<TypedResponse> {
     use futures::stream::{self, StreamExt};
 
-    const BATCH_SIZE: i64 = 1000;
-    const MAX_CONCURRENT: usize = 4;
+    if let Some(snapshot) = &client.snapshot {
+        return Ok(TypedResponse::Tiles(snapshot.tiles()?));
+    }
+    if client.raw {
+        return get_all_raw_tiles(client, fields)
+            .await
+            .map(TypedResponse::Raw);
+    }
 
     // Get total tile count from map metadata
-    let map = fetch(client.inner.get_map()).await?;
+    let map = fetch(client, || client.inner.get_map()).await?;
     let total = map.num_tiles.unwrap_or(0) as i64;
 
     if total == 0 {
         return Ok(TypedResponse::Tiles(vec![]));
     }
 
-    // Calculate offsets for all batches
-    let offsets: Vec<i64> = (0..total).step_by(BATCH_SIZE as usize).collect();
-
     // Fetch batches in parallel with concurrency limit (buffered preserves order)
-    let results: Vec<_> = stream::iter(offsets)
+    let results: Vec<_> = stream::iter(tile_batches(total))
         .map(|offset| async move {
-            fetch(client.inner.get_tiles(fields, Some(BATCH_SIZE), Some(offset))).await
+            fetch(client, || {
+                client
+                    .inner
+                    .get_tiles(fields, Some(TILE_BATCH_SIZE), Some(offset))
+            })
+            .await
         })
-        .buffered(MAX_CONCURRENT)
+        .buffered(MAX_CONCURRENT_BATCHES)
         .collect()
         .await;
 
     // Combine results, preserving order by offset
     let mut all_tiles = Vec::with_capacity(total as usize);
     for result in results {
-        all_tiles.extend(result?.tiles);
+        match result {
+            Ok(response) => all_tiles.extend(response.tiles),
+            Err(OwcliError::Decode { field, message, .. }) => {
+                warn_raw_tiles(&field, &message);
+                return get_all_raw_tiles(client, fields)
+                    .await
+                    .map(TypedResponse::Raw);
+            }
+            Err(e) => return Err(e),
+        }
     }
 
     Ok(TypedResponse::Tiles(all_tiles))
 }
 
-/// Extract an integer index from a path like "player/0" or "city/123"
-fn extract_index(path: &str, prefix: &str) -> Result<i32> {
-    let parts: Vec<&str> = path.split('/').collect();
-    if parts.len() >= 2 && parts[0] == prefix {
-        parts[1]
-            .parse::<i32>()
-            .map_err(|_| OwcliError::InvalidPath(format!("Invalid {} index: {}", prefix, parts[1])))
-    } else {
-        Err(OwcliError::InvalidPath(format!(
-            "Could not extract index from path: {}",
-            path
-        )))
+const TILE_BATCH_SIZE: i64 = 1000;
+pub(crate) const MAX_CONCURRENT_BATCHES: usize = 4;
+
+/// Offsets of the batches covering `total` tiles
+fn tile_batches(total: i64) -> Vec<i64> {
+    (0..total).step_by(TILE_BATCH_SIZE as usize).collect()
+}
+
+fn warn_raw_tiles(field: &str, message: &str) {
+    eprintln!(
+        "Warning: tiles don't match the API spec at '{}' ({}); showing raw JSON",
+        field, message
+    );
+}
+
+/// One page of tiles as raw JSON, without the pagination wrapper
+async fn get_raw_tiles(
+    client: &ApiClient,
+    offset: i64,
+    limit: i64,
+    fields: Option<&str>,
+) -> Result<TypedResponse> {
+    let mut page = client.get_json(&tiles_route(offset, limit, fields)).await?;
+    Ok(TypedResponse::Raw(page["tiles"].take()))
+}
+
+/// All tiles as one raw JSON array
+pub(crate) async fn get_all_raw_tiles(
+    client: &ApiClient,
+    fields: Option<&str>,
+) -> Result<serde_json::Value> {
+    use futures::stream::{self, StreamExt};
+
+    let map = client.get_json("/map").await?;
+    let total = map["numTiles"].as_i64().unwrap_or(0);
+
+    let pages: Vec<_> = stream::iter(tile_batches(total))
+        .map(|offset| {
+            let route = tiles_route(offset, TILE_BATCH_SIZE, fields);
+            async move { client.get_json(&route).await }
+        })
+        .buffered(MAX_CONCURRENT_BATCHES)
+        .collect()
+        .await;
+
+    let mut all_tiles = Vec::with_capacity(total.max(0) as usize);
+    for page in pages {
+        if let serde_json::Value::Array(tiles) = page?["tiles"].take() {
+            all_tiles.extend(tiles);
+        }
     }
+    Ok(serde_json::Value::Array(all_tiles))
 }
 
-/// Extract a string parameter from a path like "tribe/TRIBE_GAUL"
-fn extract_string_param(path: &str, prefix: &str) -> Result<String> {
-    let parts: Vec<&str> = path.split('/').collect();
-    if parts.len() >= 2 && parts[0] == prefix {
-        Ok(parts[1].to_string())
-    } else {
-        Err(OwcliError::InvalidPath(format!(
-            "Could not extract parameter from path: {}",
-            path
-        )))
+/// `GET /tiles` route for one page
+fn tiles_route(offset: i64, limit: i64, fields: Option<&str>) -> String {
+    let mut route = format!("/tiles?offset={}&limit={}", offset, limit);
+    if let Some(fields) = fields {
+        route.push_str(&format!("&fields={}", encode_query_value(fields)));
+    }
+    route
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[tokio::test]
+    async fn test_api_request_path() {
+        let config = Config::new("localhost".into(), 9877, Default::default(), false);
+        let client = ApiClient::new(&config).unwrap();
+        let route = |path, fields| api_request_path(&client, path, fields);
+
+        // --raw asks for the same page the typed query does
+        assert_eq!(
+            route("tiles", None).await.unwrap(),
+            "/tiles?limit=100&offset=0"
+        );
+        assert_eq!(
+            route("tiles", Some("x,y")).await.unwrap(),
+            "/tiles?fields=x%2Cy&limit=100&offset=0"
+        );
+        assert_eq!(route("city/17", None).await.unwrap(), "/city/17");
     }
 }
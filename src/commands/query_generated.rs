@@ -0,0 +1,552 @@
+// This file is auto-generated by `cargo run --bin gen_queries -- dispatch`
+// Do not edit manually. Regenerate with:
+//   cargo run --bin gen_queries -- dispatch > src/commands/query_generated.rs
+//
+// Included via include!() in commands/query.rs
+
+/// Execute a query for the given path, decoding the response into its schema type
+pub async fn execute_typed_query(
+    client: &ApiClient,
+    path_str: &str,
+    fields: Option<&str>,
+) -> Result<TypedResponse> {
+    // --from-snapshot answers from the file instead of the API
+    if let Some(snapshot) = &client.snapshot {
+        return snapshot.query(path_str);
+    }
+
+    let api_path = parse_path(path_str)?;
+
+    match api_path.endpoint_type {
+        EndpointType::State => Ok(TypedResponse::State(fetch(client, || client.inner.get_state()).await?)),
+        EndpointType::Players => Ok(TypedResponse::Players(fetch(client, || client.inner.get_players()).await?)),
+        EndpointType::Player => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::Player(fetch(client, || client.inner.get_player(index)).await?))
+        }
+        EndpointType::PlayerUnits => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerUnits(fetch(client, || client.inner.get_player_units(index)).await?))
+        }
+        EndpointType::PlayerTechs => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerTechs(fetch(client, || client.inner.get_player_techs(index)).await?))
+        }
+        EndpointType::PlayerFamilies => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerFamilies(fetch(client, || client.inner.get_player_families(index)).await?))
+        }
+        EndpointType::PlayerReligion => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerReligion(fetch(client, || client.inner.get_player_religion(index)).await?))
+        }
+        EndpointType::PlayerGoals => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerGoals(fetch(client, || client.inner.get_player_goals(index)).await?))
+        }
+        EndpointType::PlayerDecisions => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerDecisions(fetch(client, || client.inner.get_player_decisions(index)).await?))
+        }
+        EndpointType::PlayerLaws => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerLaws(fetch(client, || client.inner.get_player_laws(index)).await?))
+        }
+        EndpointType::PlayerMissions => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerMissions(fetch(client, || client.inner.get_player_missions(index)).await?))
+        }
+        EndpointType::PlayerResources => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::PlayerResources(fetch(client, || client.inner.get_player_resources(index)).await?))
+        }
+        EndpointType::Cities => Ok(TypedResponse::Cities(fetch(client, || client.inner.get_cities()).await?)),
+        EndpointType::City => {
+            let id = lookup::city_id(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::City(fetch(client, || client.inner.get_city(id)).await?))
+        }
+        EndpointType::Characters => Ok(TypedResponse::Characters(fetch(client, || client.inner.get_characters()).await?)),
+        EndpointType::Character => {
+            let id = lookup::character_id(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::Character(fetch(client, || client.inner.get_character(id)).await?))
+        }
+        EndpointType::Units => Ok(TypedResponse::Units(fetch(client, || client.inner.get_units()).await?)),
+        EndpointType::Unit => {
+            let id = api_path.int_param(0)?;
+            Ok(TypedResponse::Unit(fetch(client, || client.inner.get_unit(id)).await?))
+        }
+        EndpointType::Tiles => {
+            let response = fetch(client, || client.inner.get_tiles(fields, Some(100), Some(0))).await?;
+            Ok(TypedResponse::Tiles(response.tiles))
+        }
+        EndpointType::Tile => {
+            let id = api_path.int_param(0)?;
+            Ok(TypedResponse::Tile(fetch(client, || client.inner.get_tile(id, fields)).await?))
+        }
+        EndpointType::TileByCoords => {
+            let x = api_path.int_param(0)?;
+            let y = api_path.int_param(1)?;
+            Ok(TypedResponse::TileByCoords(fetch(client, || client.inner.get_tile_by_coords(x, y, fields)).await?))
+        }
+        EndpointType::TurnSummary => Ok(TypedResponse::TurnSummary(fetch(client, || client.inner.get_turn_summary()).await?)),
+        EndpointType::TurnSummaryCharacters => Ok(TypedResponse::TurnSummaryCharacters(fetch(client, || client.inner.get_turn_summary_characters()).await?)),
+        EndpointType::TurnSummaryCharacter => {
+            let id = lookup::character_id(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::TurnSummaryCharacter(fetch(client, || client.inner.get_turn_summary_character(id)).await?))
+        }
+        EndpointType::TurnSummaryUnits => Ok(TypedResponse::TurnSummaryUnits(fetch(client, || client.inner.get_turn_summary_units()).await?)),
+        EndpointType::TurnSummaryUnit => {
+            let id = api_path.int_param(0)?;
+            Ok(TypedResponse::TurnSummaryUnit(fetch(client, || client.inner.get_turn_summary_unit(id)).await?))
+        }
+        EndpointType::TurnSummaryCities => Ok(TypedResponse::TurnSummaryCities(fetch(client, || client.inner.get_turn_summary_cities()).await?)),
+        EndpointType::TurnSummaryCity => {
+            let id = lookup::city_id(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::TurnSummaryCity(fetch(client, || client.inner.get_turn_summary_city(id)).await?))
+        }
+        EndpointType::TurnSummaryWonders => Ok(TypedResponse::TurnSummaryWonders(fetch(client, || client.inner.get_turn_summary_wonders()).await?)),
+        EndpointType::Tribes => Ok(TypedResponse::Tribes(fetch(client, || client.inner.get_tribes()).await?)),
+        EndpointType::Tribe => {
+            let tribe_type = lookup::tribe_type(client, api_path.str_param(0)?).await?;
+            Ok(TypedResponse::Tribe(fetch(client, || client.inner.get_tribe(&tribe_type)).await?))
+        }
+        EndpointType::DiplomacyTeams => Ok(TypedResponse::DiplomacyTeams(fetch(client, || client.inner.get_diplomacy_teams()).await?)),
+        EndpointType::AlliancesTeams => Ok(TypedResponse::AlliancesTeams(fetch(client, || client.inner.get_alliances_teams()).await?)),
+        EndpointType::DiplomacyTribes => Ok(TypedResponse::DiplomacyTribes(fetch(client, || client.inner.get_diplomacy_tribes()).await?)),
+        EndpointType::AlliancesTribes => Ok(TypedResponse::AlliancesTribes(fetch(client, || client.inner.get_alliances_tribes()).await?)),
+        EndpointType::Religions => Ok(TypedResponse::Religions(fetch(client, || client.inner.get_religions()).await?)),
+        EndpointType::Config => Ok(TypedResponse::Config(fetch(client, || client.inner.get_config()).await?)),
+        EndpointType::Map => Ok(TypedResponse::Map(fetch(client, || client.inner.get_map()).await?)),
+    }
+}
+
+/// API route (with query string) for the given path, with names resolved to IDs
+pub async fn api_request_path(
+    client: &ApiClient,
+    path_str: &str,
+    fields: Option<&str>,
+) -> Result<String> {
+    let api_path = parse_path(path_str)?;
+
+    let route = match api_path.endpoint_type {
+        EndpointType::State => "/state".to_string(),
+        EndpointType::Players => "/players".to_string(),
+        EndpointType::Player => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}", index)
+        }
+        EndpointType::PlayerUnits => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/units", index)
+        }
+        EndpointType::PlayerTechs => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/techs", index)
+        }
+        EndpointType::PlayerFamilies => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/families", index)
+        }
+        EndpointType::PlayerReligion => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/religion", index)
+        }
+        EndpointType::PlayerGoals => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/goals", index)
+        }
+        EndpointType::PlayerDecisions => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/decisions", index)
+        }
+        EndpointType::PlayerLaws => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/laws", index)
+        }
+        EndpointType::PlayerMissions => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/missions", index)
+        }
+        EndpointType::PlayerResources => {
+            let index = lookup::player_index(client, api_path.str_param(0)?).await?;
+            format!("/player/{}/resources", index)
+        }
+        EndpointType::Cities => "/cities".to_string(),
+        EndpointType::City => {
+            let id = lookup::city_id(client, api_path.str_param(0)?).await?;
+            format!("/city/{}", id)
+        }
+        EndpointType::Characters => "/characters".to_string(),
+        EndpointType::Character => {
+            let id = lookup::character_id(client, api_path.str_param(0)?).await?;
+            format!("/character/{}", id)
+        }
+        EndpointType::Units => "/units".to_string(),
+        EndpointType::Unit => {
+            let id = api_path.int_param(0)?;
+            format!("/unit/{}", id)
+        }
+        EndpointType::Tiles => match fields { Some(fields) => format!("/tiles?fields={}&limit=100&offset=0", encode_query_value(fields)), None => "/tiles?limit=100&offset=0".to_string() },
+        EndpointType::Tile => {
+            let id = api_path.int_param(0)?;
+            match fields { Some(fields) => format!("/tile/{}?fields={}", id, encode_query_value(fields)), None => format!("/tile/{}", id) }
+        }
+        EndpointType::TileByCoords => {
+            let x = api_path.int_param(0)?;
+            let y = api_path.int_param(1)?;
+            match fields { Some(fields) => format!("/tile/{}/{}?fields={}", x, y, encode_query_value(fields)), None => format!("/tile/{}/{}", x, y) }
+        }
+        EndpointType::TurnSummary => "/turn-summary".to_string(),
+        EndpointType::TurnSummaryCharacters => "/turn-summary/characters".to_string(),
+        EndpointType::TurnSummaryCharacter => {
+            let id = lookup::character_id(client, api_path.str_param(0)?).await?;
+            format!("/turn-summary/character/{}", id)
+        }
+        EndpointType::TurnSummaryUnits => "/turn-summary/units".to_string(),
+        EndpointType::TurnSummaryUnit => {
+            let id = api_path.int_param(0)?;
+            format!("/turn-summary/unit/{}", id)
+        }
+        EndpointType::TurnSummaryCities => "/turn-summary/cities".to_string(),
+        EndpointType::TurnSummaryCity => {
+            let id = lookup::city_id(client, api_path.str_param(0)?).await?;
+            format!("/turn-summary/city/{}", id)
+        }
+        EndpointType::TurnSummaryWonders => "/turn-summary/wonders".to_string(),
+        EndpointType::Tribes => "/tribes".to_string(),
+        EndpointType::Tribe => {
+            let tribe_type = lookup::tribe_type(client, api_path.str_param(0)?).await?;
+            format!("/tribe/{}", tribe_type)
+        }
+        EndpointType::DiplomacyTeams => "/diplomacy/teams".to_string(),
+        EndpointType::AlliancesTeams => "/alliances/teams".to_string(),
+        EndpointType::DiplomacyTribes => "/diplomacy/tribes".to_string(),
+        EndpointType::AlliancesTribes => "/alliances/tribes".to_string(),
+        EndpointType::Religions => "/religions".to_string(),
+        EndpointType::Config => "/config".to_string(),
+        EndpointType::Map => "/map".to_string(),
+    };
+    Ok(route)
+}
+
+/// How `owcli snapshot save` captures each endpoint and `--from-snapshot` answers it
+pub const SNAPSHOT_ENDPOINTS: &[SnapshotEndpoint] = &[
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::State,
+        route: "/state",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Players,
+        route: "/players",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Player,
+        route: "/player/{index}",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerUnits,
+        route: "/player/{index}/units",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerTechs,
+        route: "/player/{index}/techs",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerFamilies,
+        route: "/player/{index}/families",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerReligion,
+        route: "/player/{index}/religion",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerGoals,
+        route: "/player/{index}/goals",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerDecisions,
+        route: "/player/{index}/decisions",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerLaws,
+        route: "/player/{index}/laws",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerMissions,
+        route: "/player/{index}/missions",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::PlayerResources,
+        route: "/player/{index}/resources",
+        accepts_fields: false,
+        source: SnapshotSource::PerPlayer,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Cities,
+        route: "/cities",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::City,
+        route: "/city/{id}",
+        accepts_fields: false,
+        source: SnapshotSource::Item { list: "/cities", items: None, keys: &["id"] },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Characters,
+        route: "/characters",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Character,
+        route: "/character/{id}",
+        accepts_fields: false,
+        source: SnapshotSource::Item { list: "/characters", items: None, keys: &["id"] },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Units,
+        route: "/units",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Unit,
+        route: "/unit/{id}",
+        accepts_fields: false,
+        source: SnapshotSource::Item { list: "/units", items: None, keys: &["id"] },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Tiles,
+        route: "/tiles",
+        accepts_fields: true,
+        source: SnapshotSource::Paginated { items: "tiles" },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Tile,
+        route: "/tile/{id}",
+        accepts_fields: true,
+        source: SnapshotSource::Item { list: "/tiles", items: Some("tiles"), keys: &["id"] },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TileByCoords,
+        route: "/tile/{x}/{y}",
+        accepts_fields: true,
+        source: SnapshotSource::Item { list: "/tiles", items: Some("tiles"), keys: &["x", "y"] },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummary,
+        route: "/turn-summary",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryCharacters,
+        route: "/turn-summary/characters",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryCharacter,
+        route: "/turn-summary/character/{id}",
+        accepts_fields: false,
+        source: SnapshotSource::Filter { list: "/turn-summary/characters", key: "characterId" },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryUnits,
+        route: "/turn-summary/units",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryUnit,
+        route: "/turn-summary/unit/{id}",
+        accepts_fields: false,
+        source: SnapshotSource::Filter { list: "/turn-summary/units", key: "unitId" },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryCities,
+        route: "/turn-summary/cities",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryCity,
+        route: "/turn-summary/city/{id}",
+        accepts_fields: false,
+        source: SnapshotSource::Filter { list: "/turn-summary/cities", key: "cityId" },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::TurnSummaryWonders,
+        route: "/turn-summary/wonders",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Tribes,
+        route: "/tribes",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Tribe,
+        route: "/tribe/{tribeType}",
+        accepts_fields: false,
+        source: SnapshotSource::Item { list: "/tribes", items: None, keys: &["tribeType"] },
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::DiplomacyTeams,
+        route: "/diplomacy/teams",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::AlliancesTeams,
+        route: "/alliances/teams",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::DiplomacyTribes,
+        route: "/diplomacy/tribes",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::AlliancesTribes,
+        route: "/alliances/tribes",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Religions,
+        route: "/religions",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Config,
+        route: "/config",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+    SnapshotEndpoint {
+        endpoint_type: EndpointType::Map,
+        route: "/map",
+        accepts_fields: false,
+        source: SnapshotSource::Route,
+    },
+];
+
+/// Path parameters of a query, with names resolved from the snapshot
+pub fn snapshot_params(snapshot: &Snapshot, api_path: &ApiPath) -> Result<Vec<String>> {
+    let params = match api_path.endpoint_type {
+        EndpointType::State => Vec::new(),
+        EndpointType::Players => Vec::new(),
+        EndpointType::Player => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerUnits => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerTechs => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerFamilies => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerReligion => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerGoals => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerDecisions => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerLaws => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerMissions => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::PlayerResources => vec![snapshot.player_index(api_path.str_param(0)?)?.to_string()],
+        EndpointType::Cities => Vec::new(),
+        EndpointType::City => vec![snapshot.city_id(api_path.str_param(0)?)?.to_string()],
+        EndpointType::Characters => Vec::new(),
+        EndpointType::Character => vec![snapshot.character_id(api_path.str_param(0)?)?.to_string()],
+        EndpointType::Units => Vec::new(),
+        EndpointType::Unit => vec![api_path.int_param(0)?.to_string()],
+        EndpointType::Tiles => Vec::new(),
+        EndpointType::Tile => vec![api_path.int_param(0)?.to_string()],
+        EndpointType::TileByCoords => vec![api_path.int_param(0)?.to_string(), api_path.int_param(1)?.to_string()],
+        EndpointType::TurnSummary => Vec::new(),
+        EndpointType::TurnSummaryCharacters => Vec::new(),
+        EndpointType::TurnSummaryCharacter => vec![snapshot.character_id(api_path.str_param(0)?)?.to_string()],
+        EndpointType::TurnSummaryUnits => Vec::new(),
+        EndpointType::TurnSummaryUnit => vec![api_path.int_param(0)?.to_string()],
+        EndpointType::TurnSummaryCities => Vec::new(),
+        EndpointType::TurnSummaryCity => vec![snapshot.city_id(api_path.str_param(0)?)?.to_string()],
+        EndpointType::TurnSummaryWonders => Vec::new(),
+        EndpointType::Tribes => Vec::new(),
+        EndpointType::Tribe => vec![snapshot.tribe_type(api_path.str_param(0)?)?],
+        EndpointType::DiplomacyTeams => Vec::new(),
+        EndpointType::AlliancesTeams => Vec::new(),
+        EndpointType::DiplomacyTribes => Vec::new(),
+        EndpointType::AlliancesTribes => Vec::new(),
+        EndpointType::Religions => Vec::new(),
+        EndpointType::Config => Vec::new(),
+        EndpointType::Map => Vec::new(),
+    };
+    Ok(params)
+}
+
+/// Decode an API response body into the schema type of its endpoint
+pub fn decode_response(endpoint_type: &EndpointType, mut body: serde_json::Value) -> Result<TypedResponse> {
+    let response = match endpoint_type {
+        EndpointType::State => TypedResponse::State(decode_value(body)?),
+        EndpointType::Players => TypedResponse::Players(decode_value(body)?),
+        EndpointType::Player => TypedResponse::Player(decode_value(body)?),
+        EndpointType::PlayerUnits => TypedResponse::PlayerUnits(decode_value(body)?),
+        EndpointType::PlayerTechs => TypedResponse::PlayerTechs(decode_value(body)?),
+        EndpointType::PlayerFamilies => TypedResponse::PlayerFamilies(decode_value(body)?),
+        EndpointType::PlayerReligion => TypedResponse::PlayerReligion(decode_value(body)?),
+        EndpointType::PlayerGoals => TypedResponse::PlayerGoals(decode_value(body)?),
+        EndpointType::PlayerDecisions => TypedResponse::PlayerDecisions(decode_value(body)?),
+        EndpointType::PlayerLaws => TypedResponse::PlayerLaws(decode_value(body)?),
+        EndpointType::PlayerMissions => TypedResponse::PlayerMissions(decode_value(body)?),
+        EndpointType::PlayerResources => TypedResponse::PlayerResources(decode_value(body)?),
+        EndpointType::Cities => TypedResponse::Cities(decode_value(body)?),
+        EndpointType::City => TypedResponse::City(decode_value(body)?),
+        EndpointType::Characters => TypedResponse::Characters(decode_value(body)?),
+        EndpointType::Character => TypedResponse::Character(decode_value(body)?),
+        EndpointType::Units => TypedResponse::Units(decode_value(body)?),
+        EndpointType::Unit => TypedResponse::Unit(decode_value(body)?),
+        EndpointType::Tiles => TypedResponse::Tiles(decode_value(body["tiles"].take())?),
+        EndpointType::Tile => TypedResponse::Tile(decode_value(body)?),
+        EndpointType::TileByCoords => TypedResponse::TileByCoords(decode_value(body)?),
+        EndpointType::TurnSummary => TypedResponse::TurnSummary(decode_value(body)?),
+        EndpointType::TurnSummaryCharacters => TypedResponse::TurnSummaryCharacters(decode_value(body)?),
+        EndpointType::TurnSummaryCharacter => TypedResponse::TurnSummaryCharacter(decode_value(body)?),
+        EndpointType::TurnSummaryUnits => TypedResponse::TurnSummaryUnits(decode_value(body)?),
+        EndpointType::TurnSummaryUnit => TypedResponse::TurnSummaryUnit(decode_value(body)?),
+        EndpointType::TurnSummaryCities => TypedResponse::TurnSummaryCities(decode_value(body)?),
+        EndpointType::TurnSummaryCity => TypedResponse::TurnSummaryCity(decode_value(body)?),
+        EndpointType::TurnSummaryWonders => TypedResponse::TurnSummaryWonders(decode_value(body)?),
+        EndpointType::Tribes => TypedResponse::Tribes(decode_value(body)?),
+        EndpointType::Tribe => TypedResponse::Tribe(decode_value(body)?),
+        EndpointType::DiplomacyTeams => TypedResponse::DiplomacyTeams(decode_value(body)?),
+        EndpointType::AlliancesTeams => TypedResponse::AlliancesTeams(decode_value(body)?),
+        EndpointType::DiplomacyTribes => TypedResponse::DiplomacyTribes(decode_value(body)?),
+        EndpointType::AlliancesTribes => TypedResponse::AlliancesTribes(decode_value(body)?),
+        EndpointType::Religions => TypedResponse::Religions(decode_value(body)?),
+        EndpointType::Config => TypedResponse::Config(decode_value(body)?),
+        EndpointType::Map => TypedResponse::Map(decode_value(body)?),
+    };
+    Ok(response)
+}
@@ -1,21 +1,107 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Defaults used when neither a flag, an environment variable nor the profile sets a value
+pub const DEFAULT_HOST: &str = "localhost";
+pub const DEFAULT_PORT: u16 = 9877;
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Output format for query results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Human-readable tables
+    #[default]
+    Table,
+    /// Pretty-printed JSON
+    Json,
+    /// Comma-separated values, one row per entity
+    Csv,
+    /// Tab-separated values, one row per entity
+    Tsv,
+    /// YAML, convenient for reading nested data
+    Yaml,
+    /// Newline-delimited JSON, one compact entity per line
+    Ndjson,
+}
+
+/// Whether to color map and watch output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// Color unless NO_COLOR is set
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    /// Turn colored terminal output on or off for the whole process
+    pub fn apply(self) {
+        match self {
+            ColorMode::Auto => {}
+            ColorMode::Always => crossterm::style::force_color_output(true),
+            ColorMode::Never => crossterm::style::force_color_output(false),
+        }
+    }
+}
+
+/// Whether styled output is on, after --color and NO_COLOR
+pub fn colors_enabled() -> bool {
+    !crossterm::style::Colored::ansi_color_disabled_memoized()
+}
+
+/// When to retry requests that fail because the game isn't answering yet
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Retry {
+    /// Extra attempts after the first
+    pub retries: u32,
+    /// Keep retrying for this long regardless of `retries` (`--wait`)
+    pub wait: Option<Duration>,
+}
+
 /// Application configuration
 #[derive(Debug, Clone)]
 pub struct Config {
     pub host: String,
     pub port: u16,
-    pub json_output: bool,
+    pub format: OutputFormat,
+    /// Show names next to player, city and character IDs in tables
+    pub resolve_names: bool,
+    /// Answer queries from a saved snapshot instead of the live API
+    pub snapshot: Option<PathBuf>,
+    /// Skip typed decoding and show API responses as returned
+    pub raw: bool,
+    /// HTTP request timeout
+    pub timeout: Duration,
+    /// Retries for 503s and refused connections
+    pub retry: Retry,
+    /// Player substituted for `me` in query paths
+    pub default_player: Option<String>,
 }
 
 impl Config {
-    pub fn new(host: String, port: u16, json_output: bool) -> Self {
+    pub fn new(host: String, port: u16, format: OutputFormat, resolve_names: bool) -> Self {
         Self {
             host,
             port,
-            json_output,
+            format,
+            resolve_names,
+            snapshot: None,
+            raw: false,
+            timeout: DEFAULT_TIMEOUT,
+            retry: Retry::default(),
+            default_player: None,
         }
     }
 
     pub fn base_url(&self) -> String {
         format!("http://{}:{}", self.host, self.port)
     }
+
+    /// Whether command and bulk results should be printed as JSON
+    pub fn json_output(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
 }
@@ -0,0 +1,498 @@
+//! Structural diff between two games
+//!
+//! Entities are matched per type by ID (players by index, tribes by type) and
+//! compared field by field. Nested objects are flattened to dotted fields such
+//! as `yieldStockpiles.YIELD_FOOD`, so a change reports the one value that moved.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+use tabled::builder::Builder;
+use tabled::settings::Style;
+
+use crate::client::{decode_value, types, ApiClient};
+use crate::commands::execute_query;
+use crate::commands::query::execute_all_tiles_query;
+use crate::config::OutputFormat;
+use crate::error::{OwcliError, Result};
+use crate::output::names::{character_name, player_index, player_name};
+use crate::output::table::shorten_type;
+use crate::output::TypedResponse;
+use crate::snapshot::Snapshot;
+
+/// One side of a diff: the game state plus tiles, when known
+pub struct Game {
+    pub state: types::GameState,
+    pub tiles: Vec<types::Tile>,
+}
+
+impl Game {
+    /// Load a snapshot file or a `owcli --json state` dump (which has no tiles)
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let value: Value = serde_json::from_reader(std::io::BufReader::new(file))?;
+
+        // Snapshots carry a format version; GameState has no such field
+        if value.get("version").is_some() {
+            let snapshot = Snapshot::from_value(value, path)?;
+            return Ok(Self {
+                state: snapshot.state().map_err(mismatch("state"))?,
+                tiles: snapshot.tiles().map_err(mismatch("tiles"))?,
+            });
+        }
+        Ok(Self {
+            state: decode_value(value).map_err(mismatch("state"))?,
+            tiles: Vec::new(),
+        })
+    }
+
+    /// Fetch the state and all tiles from the running game
+    pub async fn fetch(client: &ApiClient) -> Result<Self> {
+        let (state, tiles) = tokio::try_join!(
+            execute_query(client, "state", None),
+            execute_all_tiles_query(client, None),
+        )?;
+        // Responses that don't match the spec come back as raw JSON; decoding
+        // them again names the field at fault
+        let state = match state {
+            TypedResponse::State(state) => state,
+            other => decode_value(other.to_value()?).map_err(mismatch("state"))?,
+        };
+        let tiles = match tiles {
+            TypedResponse::Tiles(tiles) => tiles,
+            other => decode_value(other.to_value()?).map_err(mismatch("tiles"))?,
+        };
+        Ok(Self { state, tiles })
+    }
+}
+
+/// Explain a decode error of `path`: diffs compare typed entities, so unlike
+/// queries they can't fall back to raw JSON
+fn mismatch(path: &'static str) -> impl FnOnce(OwcliError) -> OwcliError {
+    move |e| match e {
+        OwcliError::Decode { field, message, .. } => OwcliError::Other(format!(
+            "{} doesn't match the API spec at '{}' ({}), so it can't be diffed",
+            path, field, message
+        )),
+        e => e,
+    }
+}
+
+/// All differences between two games
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameDiff {
+    pub from_turn: Option<i64>,
+    pub to_turn: Option<i64>,
+    pub changes: Vec<EntityChange>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// An entity that was added, removed or changed
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityChange {
+    /// Entity type (e.g., "city")
+    #[serde(rename = "type")]
+    pub entity_type: &'static str,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub change: ChangeKind,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+}
+
+/// A field whose value differs
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+    /// `after - before` for numeric fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<Value>,
+}
+
+/// Compare every entity type of two games. Tiles are compared only when both
+/// sides include them.
+pub fn diff_games(before: &Game, after: &Game) -> GameDiff {
+    let (old, new) = (&before.state, &after.state);
+    let mut changes = Vec::new();
+
+    changes.extend(diff_entities(
+        "player",
+        &old.players,
+        &new.players,
+        |i, p| Some(player_index(p, i).to_string()),
+        player_name,
+    ));
+    changes.extend(diff_entities(
+        "city",
+        &old.cities,
+        &new.cities,
+        |_, c| c.id.map(|id| id.to_string()),
+        |c| c.name.clone(),
+    ));
+    changes.extend(diff_entities(
+        "character",
+        &old.characters,
+        &new.characters,
+        |_, c| c.id.map(|id| id.to_string()),
+        character_name,
+    ));
+    changes.extend(diff_entities(
+        "unit",
+        &old.units,
+        &new.units,
+        |_, u| u.id.map(|id| id.to_string()),
+        |u| u.type_.as_deref().map(shorten_type),
+    ));
+    changes.extend(diff_entities(
+        "tribe",
+        &old.tribes,
+        &new.tribes,
+        |_, t| t.tribe_type.clone(),
+        |t| t.tribe_type.as_deref().map(shorten_type),
+    ));
+    if !before.tiles.is_empty() && !after.tiles.is_empty() {
+        changes.extend(diff_entities(
+            "tile",
+            &before.tiles,
+            &after.tiles,
+            |_, t| t.id.map(|id| id.to_string()),
+            |t| Some(format!("({}, {})", t.x?, t.y?)),
+        ));
+    }
+
+    GameDiff {
+        from_turn: old.turn,
+        to_turn: new.turn,
+        changes,
+    }
+}
+
+/// Match entities by key and compare their flattened fields. Removed and
+/// changed entities keep the `before` order; added ones follow in `after` order.
+fn diff_entities<T: Serialize>(
+    entity_type: &'static str,
+    before: &[T],
+    after: &[T],
+    key: impl Fn(usize, &T) -> Option<String>,
+    name: impl Fn(&T) -> Option<String>,
+) -> Vec<EntityChange> {
+    let keyed = |items: &[T]| -> Vec<(String, usize)> {
+        items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| Some((key(i, item)?, i)))
+            .collect()
+    };
+    let (before_keys, after_keys) = (keyed(before), keyed(after));
+    let after_index: HashMap<&str, usize> =
+        after_keys.iter().map(|(k, i)| (k.as_str(), *i)).collect();
+    let before_index: HashMap<&str, usize> =
+        before_keys.iter().map(|(k, i)| (k.as_str(), *i)).collect();
+
+    let change = |id: &str, item: &T, change, fields| EntityChange {
+        entity_type,
+        id: id.to_string(),
+        name: name(item),
+        change,
+        fields,
+    };
+
+    let mut changes = Vec::new();
+    for (id, i) in &before_keys {
+        match after_index.get(id.as_str()) {
+            None => changes.push(change(id, &before[*i], ChangeKind::Removed, Vec::new())),
+            Some(&j) => {
+                let fields = diff_fields(&before[*i], &after[j]);
+                if !fields.is_empty() {
+                    changes.push(change(id, &after[j], ChangeKind::Changed, fields));
+                }
+            }
+        }
+    }
+    for (id, j) in &after_keys {
+        if !before_index.contains_key(id.as_str()) {
+            changes.push(change(id, &after[*j], ChangeKind::Added, Vec::new()));
+        }
+    }
+    changes
+}
+
+/// Fields whose values differ; a missing field counts as null
+fn diff_fields<T: Serialize>(before: &T, after: &T) -> Vec<FieldChange> {
+    let (before, after) = (flatten_entity(before), flatten_entity(after));
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let old = before.get(field).cloned().unwrap_or(Value::Null);
+            let new = after.get(field).cloned().unwrap_or(Value::Null);
+            (old != new).then(|| FieldChange {
+                field: field.clone(),
+                delta: delta(&old, &new),
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+fn flatten_entity<T: Serialize>(item: &T) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    flatten(
+        "",
+        &serde_json::to_value(item).unwrap_or_default(),
+        &mut fields,
+    );
+    fields
+}
+
+/// Flatten nested objects into dotted keys; arrays are compared whole
+fn flatten(prefix: &str, value: &Value, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&key, value, out);
+            }
+        }
+        _ => {
+            out.insert(prefix.to_string(), value.clone());
+        }
+    }
+}
+
+fn delta(before: &Value, after: &Value) -> Option<Value> {
+    if let (Some(b), Some(a)) = (before.as_i64(), after.as_i64()) {
+        if let Some(d) = a.checked_sub(b) {
+            return Some(Value::from(d));
+        }
+    }
+    // Other numbers, and integers too far apart for an i64 delta
+    Some(Value::from(after.as_f64()? - before.as_f64()?))
+}
+
+/// Render a diff as JSON, YAML, or a summary line and table
+pub fn format_diff(diff: &GameDiff, format: OutputFormat) -> Result<String> {
+    match format {
+        OutputFormat::Table => {}
+        OutputFormat::Json => return Ok(serde_json::to_string_pretty(diff)?),
+        OutputFormat::Yaml => return Ok(serde_yaml::to_string(diff)?.trim_end().to_string()),
+        OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Ndjson => {
+            return Err(OwcliError::Other(format!(
+                "diff can't be written as {}; use --format table, json or yaml",
+                format!("{:?}", format).to_lowercase()
+            )))
+        }
+    }
+
+    let count = |kind| diff.changes.iter().filter(|c| c.change == kind).count();
+    let turn = |t: Option<i64>| t.map(|t| t.to_string()).unwrap_or_else(|| "?".into());
+    let summary = format!(
+        "Turn {} -> {}: {} added, {} removed, {} changed",
+        turn(diff.from_turn),
+        turn(diff.to_turn),
+        count(ChangeKind::Added),
+        count(ChangeKind::Removed),
+        count(ChangeKind::Changed)
+    );
+    if diff.changes.is_empty() {
+        return Ok(format!("{}\nNo differences", summary));
+    }
+
+    let mut builder = Builder::default();
+    builder.push_record([
+        "Type", "ID", "Name", "Change", "Field", "Before", "After", "Delta",
+    ]);
+    for entity in &diff.changes {
+        let status = match entity.change {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        };
+        let name = entity.name.clone().unwrap_or_default();
+        let header = [entity.entity_type, &entity.id, &name, status].map(String::from);
+
+        let rows: Vec<[String; 4]> = match entity.fields.as_slice() {
+            [] => vec![Default::default()],
+            fields => fields
+                .iter()
+                .map(|field| {
+                    [
+                        field.field.clone(),
+                        value_cell(&field.before),
+                        value_cell(&field.after),
+                        field.delta.as_ref().map(delta_cell).unwrap_or_default(),
+                    ]
+                })
+                .collect(),
+        };
+        // Entity columns only on the first row of each entity
+        for (i, row) in rows.into_iter().enumerate() {
+            let lead = if i == 0 {
+                header.clone()
+            } else {
+                Default::default()
+            };
+            builder.push_record(lead.into_iter().chain(row));
+        }
+    }
+
+    Ok(format!(
+        "{}\n{}",
+        summary,
+        builder.build().with(Style::rounded())
+    ))
+}
+
+fn value_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+fn delta_cell(delta: &Value) -> String {
+    match delta.as_f64() {
+        Some(d) if d > 0.0 => format!("+{}", delta),
+        _ => delta.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cities(value: Value) -> Vec<types::City> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_diff_entities() {
+        let before = cities(json!([
+            {"id": 1, "name": "Roma", "population": 7},
+            {"id": 2, "name": "Ostia", "population": 3},
+        ]));
+        let after = cities(json!([
+            {"id": 1, "name": "Roma", "population": 8},
+            {"id": 3, "name": "Antium", "population": 1},
+        ]));
+
+        let changes = diff_entities(
+            "city",
+            &before,
+            &after,
+            |_, c| c.id.map(|id| id.to_string()),
+            |c| c.name.clone(),
+        );
+        let summary: Vec<(&str, ChangeKind)> =
+            changes.iter().map(|c| (c.id.as_str(), c.change)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("1", ChangeKind::Changed),
+                ("2", ChangeKind::Removed),
+                ("3", ChangeKind::Added),
+            ]
+        );
+
+        let field = &changes[0].fields[0];
+        assert_eq!(field.field, "population");
+        assert_eq!(field.delta, Some(json!(1)));
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(delta(&json!(7), &json!(5)), Some(json!(-2)));
+        assert_eq!(delta(&json!(0.5), &json!(2)), Some(json!(1.5)));
+        assert_eq!(
+            delta(&json!(i64::MIN), &json!(i64::MAX)),
+            Some(json!(i64::MAX as f64 - i64::MIN as f64))
+        );
+        assert_eq!(delta(&json!("a"), &json!(1)), None);
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("owcli-diff-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let dump = dir.join("state.json");
+        std::fs::write(&dump, r#"{"turn": 3, "cities": [{"id": 1}]}"#).unwrap();
+        let game = Game::load(&dump).unwrap();
+        assert_eq!(game.state.turn, Some(3));
+        assert!(game.tiles.is_empty());
+
+        // A snapshot's own error, not a state dump parse error
+        let old = dir.join("old.json");
+        std::fs::write(&old, r#"{"version": 1, "state": {}}"#).unwrap();
+        match Game::load(&old) {
+            Err(OwcliError::Other(message)) => assert!(message.contains("version 1")),
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("loaded an old snapshot"),
+        }
+
+        // A mismatch names the field rather than failing opaquely
+        let bad = dir.join("bad.json");
+        std::fs::write(&bad, r#"{"turn": "three"}"#).unwrap();
+        match Game::load(&bad) {
+            Err(OwcliError::Other(message)) => assert!(message.contains("at 'turn'")),
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("loaded a mismatched state"),
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_diff() {
+        let diff = GameDiff {
+            from_turn: Some(1),
+            to_turn: Some(2),
+            changes: Vec::new(),
+        };
+        assert!(format_diff(&diff, OutputFormat::Table)
+            .unwrap()
+            .ends_with("No differences"));
+        assert!(format_diff(&diff, OutputFormat::Yaml)
+            .unwrap()
+            .starts_with("fromTurn: 1"));
+        assert!(format_diff(&diff, OutputFormat::Csv).is_err());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut fields = BTreeMap::new();
+        flatten(
+            "",
+            &json!({"money": 100, "yieldStockpiles": {"YIELD_FOOD": 20}, "cities": [1, 2]}),
+            &mut fields,
+        );
+        assert_eq!(fields["yieldStockpiles.YIELD_FOOD"], json!(20));
+        assert_eq!(fields["cities"], json!([1, 2]));
+        assert_eq!(fields.len(), 3);
+    }
+}
@@ -0,0 +1,347 @@
+//! Connection and version diagnostics (`owcli doctor`)
+//!
+//! Walks through the usual support questions in order: is anything listening,
+//! is a game loaded, how fast does it answer, and does the mod speak the API
+//! version owcli was built for. Each failed check comes with advice.
+
+use std::time::{Duration, Instant};
+
+use crossterm::style::{Color, Stylize};
+use serde::Serialize;
+use serde_yaml::Value as Schema;
+use tokio::net::TcpStream;
+
+use crate::client::{fetch, ApiClient};
+use crate::config::Config;
+use crate::error::{OwcliError, Result};
+use crate::validate::{spec_endpoints, SPEC};
+
+/// Routes of older API versions and the routes that replaced them
+const LEGACY_ROUTES: &[(&str, &str)] = &[
+    ("/team-diplomacy", "/diplomacy/teams"),
+    ("/team-alliances", "/alliances/teams"),
+    ("/tribe-diplomacy", "/diplomacy/tribes"),
+    ("/tribe-alliances", "/alliances/tribes"),
+];
+
+/// Cheap route used for the game and latency checks
+const PING_ROUTE: &str = "/map";
+const LATENCY_SAMPLES: u32 = 5;
+
+/// Average latency above which the game is reported as slow
+const SLOW_LATENCY: Duration = Duration::from_millis(500);
+
+/// Limit for the TCP connect, independent of --timeout
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Warn,
+    Fail,
+    Skip,
+}
+
+/// Result of one diagnostic
+#[derive(Debug, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advice: Option<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+            advice: None,
+        }
+    }
+
+    fn advice(mut self, advice: impl Into<String>) -> Self {
+        self.advice = Some(advice.into());
+        self
+    }
+}
+
+/// Run every check; later checks are skipped once the game can't be reached
+pub async fn run_doctor(config: &Config, client: &ApiClient) -> Result<Vec<Check>> {
+    let spec: Schema = serde_yaml::from_str(SPEC)?;
+    let version = spec["info"]["version"]
+        .as_str()
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut checks = vec![Check::new(
+        "owcli",
+        Status::Ok,
+        format!(
+            "{} (API spec {}), server {}",
+            env!("CARGO_PKG_VERSION"),
+            version,
+            config.base_url()
+        ),
+    )];
+
+    let tcp = check_tcp(config).await;
+    let reachable = tcp.status == Status::Ok;
+    checks.push(tcp);
+
+    let game = if reachable {
+        check_game(client).await
+    } else {
+        skipped("Game")
+    };
+    let loaded = game.status == Status::Ok;
+    checks.push(game);
+
+    if loaded {
+        checks.push(check_latency(client).await);
+        checks.push(check_routes(client, &spec, &version).await);
+        checks.push(check_decoding(client, &version).await);
+    } else {
+        checks.extend([
+            skipped("Latency"),
+            skipped("API version"),
+            skipped("Decoding"),
+        ]);
+    }
+    Ok(checks)
+}
+
+fn skipped(name: &'static str) -> Check {
+    Check::new(name, Status::Skip, "skipped")
+}
+
+async fn check_tcp(config: &Config) -> Check {
+    let address = format!("{}:{}", config.host, config.port);
+    let start = Instant::now();
+    match tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(&address)).await {
+        Ok(Ok(_)) => Check::new(
+            "TCP",
+            Status::Ok,
+            format!("{} reachable ({})", address, millis(start.elapsed())),
+        ),
+        Ok(Err(e)) => Check::new("TCP", Status::Fail, format!("{}: {}", address, e)).advice(format!(
+            "Nothing is listening on {}. Start Old World with the OldWorldAPIEndpoint mod enabled, \
+             or point owcli at the right machine with --host/--port, OWCLI_HOST/OWCLI_PORT or --profile.",
+            address
+        )),
+        Err(_) => Check::new(
+            "TCP",
+            Status::Fail,
+            format!("{}: no answer within {}s", address, CONNECT_TIMEOUT.as_secs()),
+        )
+        .advice("The host doesn't answer. Check the address and that no firewall blocks the port."),
+    }
+}
+
+async fn check_game(client: &ApiClient) -> Check {
+    match get_status(client, PING_ROUTE).await {
+        Ok((200, _)) => Check::new("Game", Status::Ok, "a game is loaded"),
+        Ok((503, _)) => Check::new(
+            "Game",
+            Status::Warn,
+            "the mod is running but no game is loaded (503)",
+        )
+        .advice("Load a save or start a game. Scripts can use --wait to block until it is ready."),
+        Ok((code, _)) => Check::new(
+            "Game",
+            Status::Fail,
+            format!("GET {} returned {}", PING_ROUTE, code),
+        )
+        .advice("Something other than the Old World API may be using this port."),
+        Err(e) => Check::new("Game", Status::Fail, e.to_string())
+            .advice("The port accepts connections but doesn't speak HTTP; check --port."),
+    }
+}
+
+async fn check_latency(client: &ApiClient) -> Check {
+    let mut samples = Vec::new();
+    for _ in 0..LATENCY_SAMPLES {
+        if let Ok((_, elapsed)) = get_status(client, PING_ROUTE).await {
+            samples.push(elapsed);
+        }
+    }
+    let (Some(min), Some(max)) = (samples.iter().min(), samples.iter().max()) else {
+        return Check::new("Latency", Status::Fail, "no request succeeded");
+    };
+    let avg = samples.iter().sum::<Duration>() / samples.len() as u32;
+
+    let detail = format!(
+        "GET {}: min {}, avg {}, max {} ({} requests)",
+        PING_ROUTE,
+        millis(*min),
+        millis(avg),
+        millis(*max),
+        samples.len()
+    );
+    if avg > SLOW_LATENCY {
+        Check::new("Latency", Status::Warn, detail).advice(
+            "The game answers slowly; it may be processing a turn. Raise --timeout for large queries such as tiles.",
+        )
+    } else {
+        Check::new("Latency", Status::Ok, detail)
+    }
+}
+
+/// Probe the spec's parameterless routes and the routes of older API versions
+async fn check_routes(client: &ApiClient, spec: &Schema, version: &str) -> Check {
+    let mut total = 0;
+    let mut missing = Vec::new();
+    for endpoint in spec_endpoints(spec) {
+        if endpoint.path.contains('{') {
+            continue;
+        }
+        total += 1;
+        if let Ok((404, _)) = get_status(client, &endpoint.path).await {
+            missing.push(endpoint.path);
+        }
+    }
+
+    let mut legacy = Vec::new();
+    for (old, new) in LEGACY_ROUTES {
+        if let Ok((200, _)) = get_status(client, old).await {
+            legacy.push((*old, *new));
+        }
+    }
+
+    let mod_is_older = legacy
+        .iter()
+        .any(|(_, new)| missing.iter().any(|m| m == new));
+    if mod_is_older {
+        let routes: Vec<String> = legacy
+            .iter()
+            .map(|(old, new)| format!("{} instead of {}", old, new))
+            .collect();
+        return Check::new(
+            "API version",
+            Status::Fail,
+            format!(
+                "the mod predates API {}: it serves {}",
+                version,
+                routes.join(", ")
+            ),
+        )
+        .advice(format!(
+            "Update the OldWorldAPIEndpoint mod to a release implementing API {}, \
+             or use an owcli built from an older openapi.yaml.",
+            version
+        ));
+    }
+    if !missing.is_empty() {
+        return Check::new(
+            "API version",
+            Status::Warn,
+            format!("{} of {} routes in API {} are missing: {}", missing.len(), total, version, missing.join(", ")),
+        )
+        .advice("The mod and owcli disagree on the API. Run `owcli validate-api` for details and update whichever is older.");
+    }
+    Check::new(
+        "API version",
+        Status::Ok,
+        format!("all {} routes of API {} answer", total, version),
+    )
+}
+
+/// Decode typed responses to catch schema drift (a mod newer than owcli)
+async fn check_decoding(client: &ApiClient, version: &str) -> Check {
+    let result = tokio::try_join!(
+        fetch(client, || client.inner.get_players()),
+        fetch(client, || client.inner.get_cities()),
+    );
+    match result {
+        Ok(_) => Check::new("Decoding", Status::Ok, "players and cities match the spec"),
+        Err(OwcliError::Decode { field, message, .. }) => Check::new(
+            "Decoding",
+            Status::Warn,
+            format!(
+                "responses don't match API {} at '{}': {}",
+                version, field, message
+            ),
+        )
+        .advice(
+            "The mod is probably newer than this owcli. Update owcli, patch openapi.overlay.yaml, \
+             or use --raw in the meantime.",
+        ),
+        Err(e) => Check::new("Decoding", Status::Fail, e.to_string()),
+    }
+}
+
+/// GET a route once, without retries, returning its status and round-trip time
+async fn get_status(client: &ApiClient, route: &str) -> Result<(u16, Duration)> {
+    let url = format!("{}{}", client.inner.baseurl(), route);
+    let start = Instant::now();
+    let response = client.inner.client().get(url).send().await?;
+    Ok((response.status().as_u16(), start.elapsed()))
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Render checks as an aligned list followed by advice
+pub fn format_checks(checks: &[Check], color_enabled: bool) -> String {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    let mut lines: Vec<String> = checks
+        .iter()
+        .map(|check| {
+            let (label, color) = match check.status {
+                Status::Ok => ("ok", Color::Green),
+                Status::Warn => ("warn", Color::Yellow),
+                Status::Fail => ("FAIL", Color::Red),
+                Status::Skip => ("skip", Color::DarkGrey),
+            };
+            // Pad outside the styled text so escape codes don't skew the columns
+            let padding = " ".repeat(4 - label.len());
+            let label = if color_enabled {
+                label.with(color).to_string()
+            } else {
+                label.to_string()
+            };
+            format!(
+                "[{}]{} {:width$}  {}",
+                label, padding, check.name, check.detail
+            )
+        })
+        .collect();
+
+    let advice: Vec<&str> = checks.iter().filter_map(|c| c.advice.as_deref()).collect();
+    lines.push(String::new());
+    if advice.is_empty() {
+        lines.push("Everything looks fine.".to_string());
+    } else {
+        lines.push(if color_enabled {
+            "Advice:".bold().to_string()
+        } else {
+            "Advice:".to_string()
+        });
+        lines.extend(advice.iter().map(|a| format!("  - {}", a)));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_checks() {
+        let checks = vec![
+            Check::new("TCP", Status::Ok, "localhost:9877 reachable"),
+            Check::new("Game", Status::Warn, "no game is loaded").advice("Load a save."),
+        ];
+        assert_eq!(
+            format_checks(&checks, false),
+            "[ok]   TCP   localhost:9877 reachable\n\
+             [warn] Game  no game is loaded\n\
+             \n\
+             Advice:\n  - Load a save."
+        );
+    }
+}
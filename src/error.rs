@@ -8,6 +8,12 @@ pub enum OwcliError {
     #[error("JSON parsing failed: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("YAML output failed: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("CSV output failed: {0}")]
+    Csv(#[from] csv::Error),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -20,9 +26,31 @@ pub enum OwcliError {
     #[error("Invalid path: {0}")]
     InvalidPath(String),
 
+    #[error("Invalid --where expression: {0}")]
+    InvalidFilter(String),
+
+    #[error("Invalid --sort-by: {0}")]
+    InvalidSort(String),
+
+    #[error("--where, --sort-by, --offset and --limit only apply to list queries")]
+    NotAList,
+
+    #[error(
+        "Response doesn't match the API spec at '{field}': {message} (use --raw to see the JSON)"
+    )]
+    Decode {
+        field: String,
+        message: String,
+        /// The response body, kept so it can still be shown
+        raw: serde_json::Value,
+    },
+
     #[error("Game not available (is it running?)")]
     GameUnavailable,
 
+    #[error("Cannot connect to the game API: {0}")]
+    Unreachable(String),
+
     #[error("Resource not found: {0}")]
     NotFound(String),
 
@@ -0,0 +1,417 @@
+//! Client-side filtering for list queries
+//!
+//! A `--where` expression is evaluated against the JSON form of each list item,
+//! using the field names from the API spec:
+//!
+//! ```text
+//! player==0 && isSleep && hp<hpMax
+//! !(nation=="NATION_ROME") || population>=5
+//! name~"rom"
+//! ```
+//!
+//! Operators: `==` `!=` `<` `<=` `>` `>=` `~` (case-insensitive contains),
+//! `&&`, `||`, `!` and parentheses. A bare field is tested for truthiness.
+//! Nested fields use dots (e.g. `location.x`); missing fields are null.
+
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use crate::error::{OwcliError, Result};
+use crate::output::ListOp;
+
+/// A parsed `--where` expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+    Not(Box<Filter>),
+    Compare(Operand, CompareOp, Operand),
+    Truthy(Operand),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Field(String),
+    Literal(Value),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+}
+
+impl std::fmt::Display for CompareOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Contains => "~",
+        };
+        write!(f, "{}", op)
+    }
+}
+
+impl Filter {
+    /// Parse a filter expression
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let filter = parser.parse_or()?;
+        match parser.peek() {
+            None => Ok(filter),
+            Some(tok) => Err(OwcliError::InvalidFilter(format!("unexpected '{}'", tok))),
+        }
+    }
+
+    /// Evaluate the filter against a JSON object
+    pub fn matches(&self, item: &Value) -> bool {
+        match self {
+            Self::And(a, b) => a.matches(item) && b.matches(item),
+            Self::Or(a, b) => a.matches(item) || b.matches(item),
+            Self::Not(f) => !f.matches(item),
+            Self::Truthy(operand) => is_truthy(&operand.resolve(item)),
+            Self::Compare(lhs, op, rhs) => compare(&lhs.resolve(item), *op, &rhs.resolve(item)),
+        }
+    }
+}
+
+impl Operand {
+    fn resolve(&self, item: &Value) -> Value {
+        match self {
+            Self::Literal(v) => v.clone(),
+            Self::Field(path) => lookup_field(item, path).cloned().unwrap_or(Value::Null),
+        }
+    }
+}
+
+/// Drop list items that don't match
+impl ListOp for Filter {
+    fn apply<T: serde::Serialize>(&self, items: &mut Vec<T>) {
+        items.retain(|item| {
+            serde_json::to_value(item)
+                .map(|v| self.matches(&v))
+                .unwrap_or(false)
+        });
+    }
+}
+
+/// Look up a dotted field path (e.g. `location.x`) in a JSON object
+pub fn lookup_field<'a>(item: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(item, |v, key| v.get(key))
+}
+
+fn is_truthy(v: &Value) -> bool {
+    match v {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|f| f != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    }
+}
+
+fn compare(lhs: &Value, op: CompareOp, rhs: &Value) -> bool {
+    match op {
+        CompareOp::Eq => values_equal(lhs, rhs),
+        CompareOp::Ne => !values_equal(lhs, rhs),
+        CompareOp::Contains => match (lhs, rhs) {
+            (Value::String(haystack), Value::String(needle)) => {
+                haystack.to_lowercase().contains(&needle.to_lowercase())
+            }
+            (Value::Array(items), needle) => items.iter().any(|v| values_equal(v, needle)),
+            _ => false,
+        },
+        CompareOp::Lt => order(lhs, rhs) == Some(Ordering::Less),
+        CompareOp::Le => matches!(order(lhs, rhs), Some(Ordering::Less | Ordering::Equal)),
+        CompareOp::Gt => order(lhs, rhs) == Some(Ordering::Greater),
+        CompareOp::Ge => matches!(order(lhs, rhs), Some(Ordering::Greater | Ordering::Equal)),
+    }
+}
+
+fn values_equal(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        // Compare numbers by value so 1 == 1.0
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        // Some IDs arrive as strings (e.g. tile owner "0")
+        (Value::String(s), Value::Number(n)) | (Value::Number(n), Value::String(s)) => {
+            s.parse::<f64>().ok() == n.as_f64()
+        }
+        _ => lhs == rhs,
+    }
+}
+
+/// Order two JSON values: strings lexically, everything else numerically
+pub fn order(lhs: &Value, rhs: &Value) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => as_number(lhs)?.partial_cmp(&as_number(rhs)?),
+    }
+}
+
+fn as_number(v: &Value) -> Option<f64> {
+    match v {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+// === Tokenizer ===
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    Str(String),
+    Op(CompareOp),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ident(s) => write!(f, "{}", s),
+            Self::Number(n) => write!(f, "{}", n),
+            Self::Str(s) => write!(f, "\"{}\"", s),
+            Self::Op(op) => write!(f, "{}", op),
+            Self::And => write!(f, "&&"),
+            Self::Or => write!(f, "||"),
+            Self::Not => write!(f, "!"),
+            Self::LParen => write!(f, "("),
+            Self::RParen => write!(f, ")"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        let (token, len) = match (c, next) {
+            (c, _) if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('=', Some('=')) => (Token::Op(CompareOp::Eq), 2),
+            ('!', Some('=')) => (Token::Op(CompareOp::Ne), 2),
+            ('<', Some('=')) => (Token::Op(CompareOp::Le), 2),
+            ('>', Some('=')) => (Token::Op(CompareOp::Ge), 2),
+            ('<', _) => (Token::Op(CompareOp::Lt), 1),
+            ('>', _) => (Token::Op(CompareOp::Gt), 1),
+            ('~', _) => (Token::Op(CompareOp::Contains), 1),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::LParen, 1),
+            (')', _) => (Token::RParen, 1),
+            ('"', _) | ('\'', _) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&ch| ch == c)
+                    .ok_or_else(|| OwcliError::InvalidFilter("unterminated string".to_string()))?;
+                let s: String = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Str(s), end + 2)
+            }
+            (c, _)
+                if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) =>
+            {
+                let len = 1 + chars[i + 1..]
+                    .iter()
+                    .take_while(|ch| ch.is_ascii_digit() || **ch == '.')
+                    .count();
+                let s: String = chars[i..i + len].iter().collect();
+                let n = s
+                    .parse::<f64>()
+                    .map_err(|_| OwcliError::InvalidFilter(format!("invalid number '{}'", s)))?;
+                (Token::Number(n), len)
+            }
+            (c, _) if c.is_alphabetic() || c == '_' => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|ch| ch.is_alphanumeric() || **ch == '_' || **ch == '.')
+                    .count();
+                (Token::Ident(chars[i..i + len].iter().collect()), len)
+            }
+            (c, _) => {
+                return Err(OwcliError::InvalidFilter(format!(
+                    "unexpected character '{}'",
+                    c
+                )))
+            }
+        };
+
+        tokens.push(token);
+        i += len;
+    }
+
+    Ok(tokens)
+}
+
+// === Parser ===
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn parse_or(&mut self) -> Result<Filter> {
+        let mut lhs = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            lhs = Filter::Or(Box::new(lhs), Box::new(self.parse_and()?));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Filter> {
+        let mut lhs = self.parse_unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            lhs = Filter::And(Box::new(lhs), Box::new(self.parse_unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Filter> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(Filter::Not(Box::new(self.parse_unary()?)))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(OwcliError::InvalidFilter("expected ')'".to_string())),
+                }
+            }
+            _ => self.parse_comparison(),
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<Filter> {
+        let lhs = self.parse_operand()?;
+        match self.peek() {
+            Some(Token::Op(op)) => {
+                let op = *op;
+                self.pos += 1;
+                let rhs = self.parse_operand()?;
+                Ok(Filter::Compare(lhs, op, rhs))
+            }
+            _ => Ok(Filter::Truthy(lhs)),
+        }
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand> {
+        match self.next() {
+            Some(Token::Ident(name)) => Ok(match name.as_str() {
+                "true" => Operand::Literal(Value::Bool(true)),
+                "false" => Operand::Literal(Value::Bool(false)),
+                "null" => Operand::Literal(Value::Null),
+                _ => Operand::Field(name),
+            }),
+            Some(Token::Number(n)) => Ok(Operand::Literal(
+                serde_json::Number::from_f64(n)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null),
+            )),
+            Some(Token::Str(s)) => Ok(Operand::Literal(Value::String(s))),
+            Some(tok) => Err(OwcliError::InvalidFilter(format!(
+                "expected field or value, got '{}'",
+                tok
+            ))),
+            None => Err(OwcliError::InvalidFilter(
+                "unexpected end of expression".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_comparisons() {
+        let unit = json!({"player": 0, "hp": 5, "hpMax": 10, "isSleep": true});
+        assert!(Filter::parse("player==0").unwrap().matches(&unit));
+        assert!(Filter::parse("hp<hpMax").unwrap().matches(&unit));
+        assert!(Filter::parse("player==0 && isSleep && hp<hpMax")
+            .unwrap()
+            .matches(&unit));
+        assert!(!Filter::parse("hp>=hpMax").unwrap().matches(&unit));
+        assert!(Filter::parse("hp>=5").unwrap().matches(&unit));
+    }
+
+    #[test]
+    fn test_logic_and_grouping() {
+        let city = json!({"name": "Roma", "population": 3, "isCapital": false});
+        assert!(Filter::parse("isCapital || population>2")
+            .unwrap()
+            .matches(&city));
+        assert!(Filter::parse("!isCapital").unwrap().matches(&city));
+        assert!(!Filter::parse("!(population==3 || isCapital)")
+            .unwrap()
+            .matches(&city));
+        assert!(Filter::parse("name~'rom'").unwrap().matches(&city));
+        assert!(Filter::parse("name==\"Roma\"").unwrap().matches(&city));
+    }
+
+    #[test]
+    fn test_missing_and_nested_fields() {
+        let event = json!({"location": {"x": 4, "y": -2}, "owner": "1"});
+        assert!(Filter::parse("location.x==4 && location.y<0")
+            .unwrap()
+            .matches(&event));
+        assert!(Filter::parse("missing==null").unwrap().matches(&event));
+        assert!(!Filter::parse("missing>0").unwrap().matches(&event));
+        assert!(Filter::parse("owner==1").unwrap().matches(&event));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Filter::parse("").is_err());
+        assert!(Filter::parse("hp <").is_err());
+        assert!(Filter::parse("(hp>1").is_err());
+        assert!(Filter::parse("name=='open").is_err());
+        assert!(Filter::parse("hp # 3").is_err());
+
+        // Operators are quoted as written
+        let err = Filter::parse("hp == <= 3").unwrap_err();
+        assert!(err.to_string().contains("got '<='"), "{}", err);
+    }
+}
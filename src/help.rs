@@ -2,7 +2,7 @@
 //!
 //! This module provides centralized help generation using:
 //! - Clap's Subcommand trait for introspecting Action commands
-//! - A static QueryInfo registry for query paths
+//! - A QueryInfo registry for query paths, generated from openapi.yaml
 //!
 //! This ensures help text stays in sync with the implementation.
 
@@ -18,156 +18,9 @@ pub struct QueryInfo {
     pub description: &'static str,
 }
 
-/// Central registry of all query paths - single source of truth
-pub const QUERIES: &[QueryInfo] = &[
-    // Simple endpoints
-    QueryInfo {
-        path: "state",
-        description: "Full game snapshot",
-    },
-    QueryInfo {
-        path: "config",
-        description: "Game configuration",
-    },
-    QueryInfo {
-        path: "players",
-        description: "All players",
-    },
-    QueryInfo {
-        path: "cities",
-        description: "All cities",
-    },
-    QueryInfo {
-        path: "characters",
-        description: "All characters",
-    },
-    QueryInfo {
-        path: "units",
-        description: "All units",
-    },
-    QueryInfo {
-        path: "map",
-        description: "Map information",
-    },
-    QueryInfo {
-        path: "tribes",
-        description: "All tribes",
-    },
-    QueryInfo {
-        path: "religions",
-        description: "All religions",
-    },
-    // Player paths
-    QueryInfo {
-        path: "player/<index>",
-        description: "Single player (0-indexed)",
-    },
-    QueryInfo {
-        path: "player/<index>/units",
-        description: "Player's units",
-    },
-    QueryInfo {
-        path: "player/<index>/techs",
-        description: "Player's technologies",
-    },
-    QueryInfo {
-        path: "player/<index>/families",
-        description: "Player's families",
-    },
-    QueryInfo {
-        path: "player/<index>/religion",
-        description: "Player's religion",
-    },
-    QueryInfo {
-        path: "player/<index>/goals",
-        description: "Player's goals",
-    },
-    QueryInfo {
-        path: "player/<index>/decisions",
-        description: "Player's pending decisions",
-    },
-    QueryInfo {
-        path: "player/<index>/laws",
-        description: "Player's laws",
-    },
-    QueryInfo {
-        path: "player/<index>/missions",
-        description: "Player's missions",
-    },
-    QueryInfo {
-        path: "player/<index>/resources",
-        description: "Player's resources",
-    },
-    // Single item lookups
-    QueryInfo {
-        path: "city/<id>",
-        description: "Single city by ID",
-    },
-    QueryInfo {
-        path: "character/<id>",
-        description: "Single character by ID",
-    },
-    QueryInfo {
-        path: "unit/<id>",
-        description: "Single unit by ID",
-    },
-    QueryInfo {
-        path: "tile/<id>",
-        description: "Tile by ID",
-    },
-    QueryInfo {
-        path: "tile/<x>/<y>",
-        description: "Tile by coordinates",
-    },
-    QueryInfo {
-        path: "tribe/<type>",
-        description: "Single tribe by type",
-    },
-    // Diplomacy
-    QueryInfo {
-        path: "diplomacy/teams",
-        description: "Team diplomatic relations",
-    },
-    QueryInfo {
-        path: "diplomacy/tribes",
-        description: "Tribe diplomatic relations",
-    },
-    // Alliances
-    QueryInfo {
-        path: "alliances/teams",
-        description: "Team alliances",
-    },
-    QueryInfo {
-        path: "alliances/tribes",
-        description: "Tribe alliances",
-    },
-    // Events
-    QueryInfo {
-        path: "events/characters",
-        description: "Character events",
-    },
-    QueryInfo {
-        path: "events/units",
-        description: "Unit events",
-    },
-    QueryInfo {
-        path: "events/cities",
-        description: "City events",
-    },
-];
-
-/// Player resource sub-paths for completion
-pub const PLAYER_RESOURCES: &[&str] = &[
-    "units",
-    "techs",
-    "families",
-    "religion",
-    "goals",
-    "decisions",
-    "laws",
-    "missions",
-    "resources",
-];
+// QUERIES and PLAYER_RESOURCES are generated from openapi.yaml
+// Regenerate with: cargo run --bin gen_queries -- help > src/help_generated.rs
+include!("help_generated.rs");
 
 /// Command categories for organized help display
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -452,19 +305,7 @@ pub fn get_query_completions() -> Vec<&'static str> {
             if let Some(slash_pos) = info.path.find('/') {
                 let prefix = &info.path[..=slash_pos];
                 if seen.insert(prefix) {
-                    // We need to return a 'static str, so match against known prefixes
-                    match prefix {
-                        "player/" => completions.push("player/"),
-                        "city/" => completions.push("city/"),
-                        "character/" => completions.push("character/"),
-                        "unit/" => completions.push("unit/"),
-                        "tile/" => completions.push("tile/"),
-                        "tribe/" => completions.push("tribe/"),
-                        "diplomacy/" => completions.push("diplomacy/"),
-                        "alliances/" => completions.push("alliances/"),
-                        "events/" => completions.push("events/"),
-                        _ => {}
-                    }
+                    completions.push(prefix);
                 }
             }
         }
@@ -598,6 +439,12 @@ pub fn generate_queries_list() -> String {
     output.push_str("\nSpecial:\n");
     output.push_str("  tiles                       Fetch all tiles (auto-paginated)\n");
     output.push_str("  tiles --offset N --limit M  Manual pagination (max 1000 per page)\n");
+    output.push_str(
+        "  city/Roma, player/rome      Player, city, character and tribe names work as IDs\n",
+    );
+    output.push_str(
+        "  unit-type/UNIT_ARCHER       Not supported (no API route); use units --where 'type==\"UNIT_ARCHER\"'\n",
+    );
 
     output
 }
@@ -621,6 +468,9 @@ mod tests {
         assert!(completions.contains(&"state"));
         assert!(completions.contains(&"players"));
         assert!(completions.contains(&"player/"));
+        assert!(completions.contains(&"events"));
+        assert!(completions.contains(&"events/wonders"));
+        assert!(completions.contains(&"events/"));
     }
 
     #[test]
@@ -0,0 +1,174 @@
+// This file is auto-generated by `cargo run --bin gen_queries -- help`
+// Do not edit manually. Regenerate with:
+//   cargo run --bin gen_queries -- help > src/help_generated.rs
+//
+// Included via include!() in help.rs
+
+/// Central registry of all query paths - single source of truth
+pub const QUERIES: &[QueryInfo] = &[
+    QueryInfo {
+        path: "state",
+        description: "Full game state",
+    },
+    QueryInfo {
+        path: "players",
+        description: "All players",
+    },
+    QueryInfo {
+        path: "player/<index>",
+        description: "Player by index",
+    },
+    QueryInfo {
+        path: "player/<index>/units",
+        description: "Player units",
+    },
+    QueryInfo {
+        path: "player/<index>/techs",
+        description: "Player technologies",
+    },
+    QueryInfo {
+        path: "player/<index>/families",
+        description: "Player families",
+    },
+    QueryInfo {
+        path: "player/<index>/religion",
+        description: "Player religion state",
+    },
+    QueryInfo {
+        path: "player/<index>/goals",
+        description: "Player goals",
+    },
+    QueryInfo {
+        path: "player/<index>/decisions",
+        description: "Player decisions",
+    },
+    QueryInfo {
+        path: "player/<index>/laws",
+        description: "Player laws",
+    },
+    QueryInfo {
+        path: "player/<index>/missions",
+        description: "Player missions",
+    },
+    QueryInfo {
+        path: "player/<index>/resources",
+        description: "Player resources",
+    },
+    QueryInfo {
+        path: "cities",
+        description: "All cities",
+    },
+    QueryInfo {
+        path: "city/<id>",
+        description: "City by ID",
+    },
+    QueryInfo {
+        path: "characters",
+        description: "All characters",
+    },
+    QueryInfo {
+        path: "character/<id>",
+        description: "Character by ID",
+    },
+    QueryInfo {
+        path: "units",
+        description: "All units",
+    },
+    QueryInfo {
+        path: "unit/<id>",
+        description: "Unit by ID",
+    },
+    QueryInfo {
+        path: "tiles",
+        description: "Tiles (paginated)",
+    },
+    QueryInfo {
+        path: "tile/<id>",
+        description: "Tile by ID",
+    },
+    QueryInfo {
+        path: "tile/<x>/<y>",
+        description: "Tile by coordinates",
+    },
+    QueryInfo {
+        path: "events",
+        description: "All turn summary data",
+    },
+    QueryInfo {
+        path: "events/characters",
+        description: "Character events",
+    },
+    QueryInfo {
+        path: "events/character/<id>",
+        description: "Events for specific character",
+    },
+    QueryInfo {
+        path: "events/units",
+        description: "Unit events",
+    },
+    QueryInfo {
+        path: "events/unit/<id>",
+        description: "Events for specific unit",
+    },
+    QueryInfo {
+        path: "events/cities",
+        description: "City events",
+    },
+    QueryInfo {
+        path: "events/city/<id>",
+        description: "Events for specific city",
+    },
+    QueryInfo {
+        path: "events/wonders",
+        description: "Wonder completion events",
+    },
+    QueryInfo {
+        path: "tribes",
+        description: "All tribes",
+    },
+    QueryInfo {
+        path: "tribe/<tribe_type>",
+        description: "Tribe by type",
+    },
+    QueryInfo {
+        path: "diplomacy/teams",
+        description: "Team diplomacy",
+    },
+    QueryInfo {
+        path: "alliances/teams",
+        description: "Team alliances",
+    },
+    QueryInfo {
+        path: "diplomacy/tribes",
+        description: "Tribe diplomacy",
+    },
+    QueryInfo {
+        path: "alliances/tribes",
+        description: "Tribe alliances",
+    },
+    QueryInfo {
+        path: "religions",
+        description: "Global religion state",
+    },
+    QueryInfo {
+        path: "config",
+        description: "Game configuration",
+    },
+    QueryInfo {
+        path: "map",
+        description: "Map metadata",
+    },
+];
+
+/// Player resource sub-paths for completion
+pub const PLAYER_RESOURCES: &[&str] = &[
+    "units",
+    "techs",
+    "families",
+    "religion",
+    "goals",
+    "decisions",
+    "laws",
+    "missions",
+    "resources",
+];
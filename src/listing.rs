@@ -0,0 +1,243 @@
+//! Client-side list options: filtering, sorting and paging
+//!
+//! Applied to list responses after fetching and before formatting, in the order
+//! `--where`, `--sort-by`, `--offset`, `--limit`.
+
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use crate::error::{OwcliError, Result};
+use crate::filter::{lookup_field, order, Filter};
+use crate::output::{ListOp, TypedResponse};
+
+/// Sort key parsed from `--sort-by <field>[:desc]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortKey {
+    pub field: String,
+    pub descending: bool,
+}
+
+impl SortKey {
+    pub fn parse(input: &str) -> Result<Self> {
+        let (field, descending) = match input.rsplit_once(':') {
+            Some((field, "desc")) => (field, true),
+            Some((field, "asc")) => (field, false),
+            Some((_, dir)) => {
+                return Err(OwcliError::InvalidSort(format!(
+                    "unknown direction '{}' (expected 'asc' or 'desc')",
+                    dir
+                )))
+            }
+            None => (input, false),
+        };
+
+        if field.is_empty() {
+            return Err(OwcliError::InvalidSort("missing field name".to_string()));
+        }
+
+        Ok(Self {
+            field: field.to_string(),
+            descending,
+        })
+    }
+
+    /// Compare two sort values; missing values always sort last
+    fn compare(&self, a: &Value, b: &Value) -> Ordering {
+        match (a.is_null(), b.is_null()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                let ord = order(a, b).unwrap_or(Ordering::Equal);
+                if self.descending {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            }
+        }
+    }
+}
+
+impl ListOp for SortKey {
+    fn apply<T: serde::Serialize>(&self, items: &mut Vec<T>) {
+        let mut keyed: Vec<(Value, T)> = items
+            .drain(..)
+            .map(|item| {
+                let key = serde_json::to_value(&item)
+                    .ok()
+                    .and_then(|v| lookup_field(&v, &self.field).cloned())
+                    .unwrap_or(Value::Null);
+                (key, item)
+            })
+            .collect();
+
+        // Stable sort keeps the API's order for equal keys
+        keyed.sort_by(|(a, _), (b, _)| self.compare(a, b));
+        items.extend(keyed.into_iter().map(|(_, item)| item));
+    }
+}
+
+/// A window over the list from `--offset` and `--limit`
+#[derive(Debug, Clone, Copy)]
+struct Page {
+    offset: usize,
+    limit: Option<usize>,
+}
+
+impl ListOp for Page {
+    fn apply<T: serde::Serialize>(&self, items: &mut Vec<T>) {
+        items.drain(..self.offset.min(items.len()));
+        if let Some(limit) = self.limit {
+            items.truncate(limit);
+        }
+    }
+}
+
+/// Client-side options for list queries
+#[derive(Debug, Default)]
+pub struct ListOptions {
+    pub filter: Option<Filter>,
+    pub sort: Option<SortKey>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+impl ListOptions {
+    pub fn new(
+        filter: Option<&str>,
+        sort_by: Option<&str>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Self> {
+        Ok(Self {
+            filter: filter.map(Filter::parse).transpose()?,
+            sort: sort_by.map(SortKey::parse).transpose()?,
+            offset,
+            limit,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.filter.is_none()
+            && self.sort.is_none()
+            && self.offset.is_none()
+            && self.limit.is_none()
+    }
+
+    /// Whether the options need every item before they can be applied
+    pub fn needs_full_list(&self) -> bool {
+        self.filter.is_some() || self.sort.is_some()
+    }
+
+    /// Filter, sort and page a list response in place
+    pub fn apply(&self, response: &mut TypedResponse) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        if !response.is_list() {
+            return Err(OwcliError::NotAList);
+        }
+
+        if let Some(filter) = &self.filter {
+            response.apply_list_op(filter);
+        }
+        if let Some(sort) = &self.sort {
+            response.apply_list_op(sort);
+        }
+        response.apply_list_op(&Page {
+            offset: self.offset.unwrap_or(0) as usize,
+            limit: self.limit.map(|l| l as usize),
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize)]
+    struct Item {
+        id: i64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strength: Option<i64>,
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item {
+                id: 1,
+                strength: Some(5),
+            },
+            Item {
+                id: 2,
+                strength: None,
+            },
+            Item {
+                id: 3,
+                strength: Some(9),
+            },
+            Item {
+                id: 4,
+                strength: Some(5),
+            },
+        ]
+    }
+
+    fn ids(items: &[Item]) -> Vec<i64> {
+        items.iter().map(|i| i.id).collect()
+    }
+
+    #[test]
+    fn test_parse_sort_key() {
+        let key = SortKey::parse("strengthRating:desc").unwrap();
+        assert_eq!(key.field, "strengthRating");
+        assert!(key.descending);
+
+        let key = SortKey::parse("population").unwrap();
+        assert!(!key.descending);
+
+        assert!(matches!(
+            SortKey::parse("population:sideways"),
+            Err(OwcliError::InvalidSort(_))
+        ));
+        assert!(matches!(
+            SortKey::parse(":desc"),
+            Err(OwcliError::InvalidSort(_))
+        ));
+    }
+
+    #[test]
+    fn test_sort_missing_last() {
+        let mut list = items();
+        SortKey::parse("strength").unwrap().apply(&mut list);
+        assert_eq!(ids(&list), vec![1, 4, 3, 2]);
+
+        let mut list = items();
+        SortKey::parse("strength:desc").unwrap().apply(&mut list);
+        assert_eq!(ids(&list), vec![3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_page() {
+        let mut list = items();
+        Page {
+            offset: 1,
+            limit: Some(2),
+        }
+        .apply(&mut list);
+        assert_eq!(ids(&list), vec![2, 3]);
+
+        let mut list = items();
+        Page {
+            offset: 10,
+            limit: None,
+        }
+        .apply(&mut list);
+        assert!(list.is_empty());
+    }
+}
@@ -2,34 +2,51 @@ mod cli;
 mod client;
 mod commands;
 mod config;
+mod diff;
+mod doctor;
 mod error;
+mod filter;
 mod help;
+mod listing;
 mod map;
+mod mock_server;
 mod output;
 mod path_parser;
+mod profile;
 mod repl;
+mod snapshot;
+mod validate;
+mod watch;
 
 use std::io::Read;
+use std::time::Duration;
 
 use clap::Parser;
 use cli::{Cli, Commands};
-use client::{command_succeeded, ApiClient};
+use client::{command_succeeded, ensure_live, ApiClient};
 use commands::{execute_action, execute_query};
-use config::Config;
-use output::{format_bulk_response, format_command_response, format_typed_output};
+use config::{Config, OutputFormat, Retry, DEFAULT_HOST, DEFAULT_PORT, DEFAULT_TIMEOUT};
+use listing::ListOptions;
+use output::names::{Names, Needed};
+use output::{
+    format_bulk_response, format_command_response, write_labeled_output, write_typed_output,
+    TypedResponse,
+};
+use profile::Profile;
 
 fn main() {
     let _ = dotenvy::dotenv();
 
     let cli = Cli::parse();
-    let config = Config::new(cli.host.clone(), cli.port, cli.json);
 
-    let result = if cli.command.is_none() && cli.path.is_empty() {
-        repl::run_repl(&config)
-    } else {
-        let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
-        rt.block_on(run(cli, config))
-    };
+    let result = load_config(&cli).and_then(|config| {
+        if cli.command.is_none() && cli.path.is_empty() {
+            repl::run_repl(&config)
+        } else {
+            let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+            rt.block_on(run(cli, config))
+        }
+    });
 
     if let Err(e) = result {
         eprintln!("Error: {}", e);
@@ -37,14 +54,56 @@ fn main() {
     }
 }
 
+/// Combine flags and environment variables with the selected profile
+fn load_config(cli: &Cli) -> error::Result<Config> {
+    let profile = Profile::load(cli.config.as_deref(), cli.profile.as_deref())?;
+
+    let format = if cli.json {
+        OutputFormat::Json
+    } else {
+        cli.format.or(profile.format).unwrap_or_default()
+    };
+    let mut config = Config::new(
+        cli.host
+            .clone()
+            .or(profile.host)
+            .unwrap_or(DEFAULT_HOST.to_string()),
+        cli.port.or(profile.port).unwrap_or(DEFAULT_PORT),
+        format,
+        !cli.no_resolve,
+    );
+    config.snapshot = cli.from_snapshot.clone();
+    config.raw = cli.raw;
+    config.timeout = cli.timeout.or(profile.timeout).unwrap_or(DEFAULT_TIMEOUT);
+    config.retry = Retry {
+        retries: cli.retries.or(profile.retries).unwrap_or_default(),
+        // A bare --wait has no limit
+        wait: match cli.wait {
+            Some(timeout) => Some(timeout.unwrap_or(Duration::MAX)),
+            None => profile.wait,
+        },
+    };
+    config.default_player = cli.default_player.clone().or(profile.player);
+
+    cli.color.or(profile.color).unwrap_or_default().apply();
+    Ok(config)
+}
+
 async fn run(cli: Cli, config: Config) -> error::Result<()> {
+    let list_options = ListOptions::new(
+        cli.filter.as_deref(),
+        cli.sort_by.as_deref(),
+        cli.offset,
+        cli.limit,
+    )?;
+
     if let Some(command) = cli.command {
-        return handle_command(command, &config, cli.fields.as_deref()).await;
+        return handle_command(command, &config, cli.fields.as_deref(), &list_options).await;
     }
 
     if !cli.path.is_empty() {
-        let path = cli.path.join("/");
-        return handle_query(&path, &config, cli.fields.as_deref()).await;
+        let paths = path_parser::split_query_paths(&cli.path);
+        return handle_query(&paths, &config, cli.fields.as_deref(), &list_options).await;
     }
 
     Ok(())
@@ -54,44 +113,50 @@ async fn handle_command(
     command: Commands,
     config: &Config,
     global_fields: Option<&str>,
+    list_options: &ListOptions,
 ) -> error::Result<()> {
     match command {
-        Commands::Tiles {
-            offset,
-            limit,
-            fields,
-        } => {
+        Commands::Tiles { fields } => {
             let client = ApiClient::new(config)?;
             // Prefer subcommand --fields over global --fields
             let fields = fields.as_deref().or(global_fields);
-            let result = match (offset, limit) {
-                // If either is specified, use manual pagination
-                (Some(o), Some(l)) => {
-                    commands::query::execute_tiles_query(&client, o, l, fields).await?
-                }
-                (Some(o), None) => {
-                    commands::query::execute_tiles_query(&client, o, 100, fields).await?
-                }
-                (None, Some(l)) => {
-                    commands::query::execute_tiles_query(&client, 0, l, fields).await?
+            let (offset, limit) = (list_options.offset, list_options.limit);
+            let result = if list_options.needs_full_list() {
+                // Filtering and sorting need every tile before paging
+                let mut result = commands::query::execute_all_tiles_query(&client, fields).await?;
+                list_options.apply(&mut result)?;
+                result
+            } else {
+                match (offset, limit) {
+                    // If either is specified, use server-side pagination
+                    (Some(o), Some(l)) => {
+                        commands::query::execute_tiles_query(&client, o, l, fields).await?
+                    }
+                    (Some(o), None) => {
+                        commands::query::execute_tiles_query(&client, o, 100, fields).await?
+                    }
+                    (None, Some(l)) => {
+                        commands::query::execute_tiles_query(&client, 0, l, fields).await?
+                    }
+                    // Default: fetch all tiles
+                    (None, None) => {
+                        commands::query::execute_all_tiles_query(&client, fields).await?
+                    }
                 }
-                // Default: fetch all tiles
-                (None, None) => commands::query::execute_all_tiles_query(&client, fields).await?,
             };
-            let output = format_typed_output(&result, config.json_output)?;
-            println!("{}", output);
-            Ok(())
+            print_responses(&client, config, &[("tiles".to_string(), result)], fields).await
         }
 
         Commands::Command { action } => {
             let client = ApiClient::new(config)?;
+            ensure_live(&client)?;
             let response = execute_action(&client, &action).await?;
             let succeeded = command_succeeded(&response);
             let output = format_command_response(
                 succeeded,
                 response.error.as_deref(),
                 response.request_id.as_deref(),
-                config.json_output,
+                config.json_output(),
             );
             println!("{}", output);
 
@@ -125,10 +190,12 @@ async fn handle_command(
             }
 
             let client = ApiClient::new(config)?;
-            let response = client::fetch(client.inner.execute_bulk_commands(&bulk)).await?;
+            ensure_live(&client)?;
+            let response =
+                client::fetch(&client, || client.inner.execute_bulk_commands(&bulk)).await?;
 
             let all_succeeded = response.all_succeeded.unwrap_or(true);
-            let output = format_bulk_response(&response, config.json_output);
+            let output = format_bulk_response(&response, config.json_output());
             println!("{}", output);
 
             if !all_succeeded {
@@ -174,33 +241,227 @@ async fn handle_command(
             Ok(())
         }
 
-        Commands::Map => {
+        Commands::Watch { path, interval } => {
+            let path = path.join("/");
+            watch::run_watch(config, &path, interval, global_fields, list_options).await
+        }
+
+        Commands::Diff {
+            before,
+            after,
+            live: _,
+        } => {
+            let before = diff::Game::load(&before)?;
+            // Without a second file, clap guarantees --live
+            let after = match after {
+                Some(path) => diff::Game::load(&path)?,
+                None => diff::Game::fetch(&ApiClient::new(config)?).await?,
+            };
+            let diff = diff::diff_games(&before, &after);
+            println!("{}", diff::format_diff(&diff, config.format)?);
+            Ok(())
+        }
+
+        Commands::MockServer { snapshot } => {
+            let snapshot = snapshot::Snapshot::load(&snapshot)?;
+            mock_server::run_mock_server(config, snapshot).await
+        }
+
+        Commands::ValidateApi { output } => {
+            let client = ApiClient::new(config)?;
+            let report = validate::validate_api(&client).await?;
+            let text = if config.json_output() {
+                report.to_json()?
+            } else {
+                report.to_markdown()
+            };
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, text)?;
+                    eprintln!("Report written to {}", path.display());
+                }
+                None => print!("{}", text),
+            }
+            Ok(())
+        }
+
+        Commands::Doctor => {
+            let client = ApiClient::new(config)?;
+            ensure_live(&client)?;
+            let checks = doctor::run_doctor(config, &client).await?;
+            if config.json_output() {
+                println!("{}", serde_json::to_string_pretty(&checks)?);
+            } else {
+                println!(
+                    "{}",
+                    doctor::format_checks(&checks, config::colors_enabled())
+                );
+            }
+            let failed = checks.iter().any(|c| c.status == doctor::Status::Fail);
+            if failed {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+
+        Commands::Snapshot {
+            action: cli::SnapshotAction::Save { file },
+        } => {
+            let client = ApiClient::new(config)?;
+            ensure_live(&client)?;
+            let snapshot = snapshot::Snapshot::capture(&client).await?;
+            snapshot.save(&file)?;
+            println!(
+                "Saved turn {} ({} API routes) to {}",
+                snapshot.state()?.turn.unwrap_or_default(),
+                snapshot.routes.len(),
+                file.display()
+            );
+            Ok(())
+        }
+
+        Commands::Map {
+            interactive,
+            layer,
+            heat,
+            output,
+            units: show_units,
+        } => {
             let client = ApiClient::new(config)?;
+            if client.raw {
+                // Drawing needs the decoded tiles, cities and players
+                return Err(error::OwcliError::Other(
+                    "map can't be drawn from raw JSON; drop --raw".into(),
+                ));
+            }
+            let export = match &output {
+                Some(path) => Some(map::ExportFormat::from_path(path)?),
+                None => None,
+            };
+            let coloring = match (layer, heat.as_deref()) {
+                (Some(layer), _) => map::Coloring::Layer(layer),
+                (None, Some(field)) => {
+                    map::heat::check_field(field)?;
+                    map::Coloring::Heat(field)
+                }
+                (None, None) => map::Coloring::Nations,
+            };
 
-            // Fetch tiles with only fields needed for map rendering
+            // Fetch tiles with only fields needed for map rendering (and the tile panel or rivers)
             const MAP_TILE_FIELDS: &str = "x,y,terrain,height,vegetation,owner";
+            const INTERACTIVE_TILE_FIELDS: &str =
+                "id,x,y,terrain,height,vegetation,owner,ownerTribe,improvement,resource";
+            const RIVER_TILE_FIELDS: &str =
+                "isRiverE,isRiverNE,isRiverNW,isRiverSE,isRiverSW,isRiverW";
+            let fields = match coloring {
+                _ if interactive => Some(INTERACTIVE_TILE_FIELDS.to_string()),
+                // The page shows whole tiles on hover
+                _ if export == Some(map::ExportFormat::Html) => None,
+                _ if export.is_some() => Some(format!("{},{}", MAP_TILE_FIELDS, RIVER_TILE_FIELDS)),
+                map::Coloring::Nations => Some(MAP_TILE_FIELDS.to_string()),
+                map::Coloring::Layer(layer) => {
+                    Some(format!("{},{}", MAP_TILE_FIELDS, layer.tile_fields()))
+                }
+                // The gradient replaces all other coloring, so only the field is needed
+                map::Coloring::Heat(field) => Some(format!("x,y,{}", field)),
+            };
             let tiles_result =
-                commands::query::execute_all_tiles_query(&client, Some(MAP_TILE_FIELDS)).await?;
-            let cities = client::fetch(client.inner.get_cities()).await?;
-            let players = client::fetch(client.inner.get_players()).await?;
+                commands::query::execute_all_tiles_query(&client, fields.as_deref()).await?;
+            let cities_result = execute_query(&client, "cities", None).await?;
+            let players_result = execute_query(&client, "players", None).await?;
 
-            // Extract tiles from TypedResponse
-            let tiles = match tiles_result {
-                output::TypedResponse::Tiles(t) => t,
+            // Extract lists from TypedResponse
+            let (tiles, cities, players) = match (tiles_result, cities_result, players_result) {
+                (TypedResponse::Tiles(t), TypedResponse::Cities(c), TypedResponse::Players(p)) => {
+                    (t, c, p)
+                }
                 _ => return Err(error::OwcliError::Other("Unexpected response type".into())),
             };
 
-            let output = map::render_map(&tiles, &cities, &players)?;
+            let units = if interactive || show_units || export == Some(map::ExportFormat::Html) {
+                match execute_query(&client, "units", None).await? {
+                    TypedResponse::Units(u) => u,
+                    _ => return Err(error::OwcliError::Other("Unexpected response type".into())),
+                }
+            } else {
+                Vec::new()
+            };
+
+            if interactive {
+                return map::interactive_map(&tiles, &cities, &players, &units);
+            }
+            if let (Some(format), Some(path)) = (export, output) {
+                let text = map::export_map(format, &tiles, &cities, &players, &units, show_units)?;
+                std::fs::write(&path, text)?;
+                eprintln!("Map written to {}", path.display());
+                return Ok(());
+            }
+
+            let output = map::render_map(&tiles, &cities, &players, coloring)?;
             println!("{}", output);
             Ok(())
         }
     }
 }
 
-async fn handle_query(path: &str, config: &Config, fields: Option<&str>) -> error::Result<()> {
+async fn handle_query(
+    paths: &[String],
+    config: &Config,
+    fields: Option<&str>,
+    list_options: &ListOptions,
+) -> error::Result<()> {
     let client = ApiClient::new(config)?;
-    let result = execute_query(&client, path, fields).await?;
-    let output = format_typed_output(&result, config.json_output)?;
-    println!("{}", output);
-    Ok(())
+
+    // Several paths run concurrently on the shared client
+    let results = futures::future::try_join_all(paths.iter().map(|path| {
+        let client = &client;
+        async move {
+            let mut result = execute_query(client, path, fields).await?;
+            list_options.apply(&mut result)?;
+            Ok::<_, error::OwcliError>((path.clone(), result))
+        }
+    }))
+    .await?;
+
+    print_responses(&client, config, &results, fields).await
+}
+
+/// Print query results, resolving IDs to names for table output. A single
+/// result prints as-is; several are labeled by path.
+async fn print_responses(
+    client: &ApiClient,
+    config: &Config,
+    results: &[(String, TypedResponse)],
+    fields: Option<&str>,
+) -> error::Result<()> {
+    let field_list = fields.map(output::parse_fields);
+
+    // Only fetch names for the kinds of IDs the tables show
+    let mut names = Names::default();
+    if config.format == OutputFormat::Table && config.resolve_names {
+        let needed = results.iter().fold(Needed::NONE, |needed, (_, result)| {
+            needed.union(result.id_kinds(field_list.as_deref()))
+        });
+        if needed.any() {
+            names = Names::fetch(client, needed).await;
+        }
+    }
+
+    let mut out = std::io::stdout().lock();
+    match results {
+        [(_, result)] => write_typed_output(
+            &mut out,
+            result,
+            config.format,
+            field_list.as_deref(),
+            &names,
+        ),
+        _ => write_labeled_output(
+            &mut out,
+            results,
+            config.format,
+            field_list.as_deref(),
+            &names,
+        ),
+    }
 }
@@ -59,7 +59,6 @@ pub fn nation_to_color(nation: &str) -> Color {
 }
 
 /// Map tribe type string to RGB color
-#[allow(dead_code)]
 pub fn tribe_to_color(tribe: &str) -> Color {
     match tribe {
         "TRIBE_GAUL" | "TRIBE_GAULS" => Color::Rgb {
@@ -165,6 +164,48 @@ pub fn terrain_to_color(
     }
 }
 
+/// Distinct colors for map layers whose categories aren't known in advance
+const PALETTE: [(u8, u8, u8); 12] = [
+    (230, 25, 75),   // Red
+    (60, 180, 75),   // Green
+    (255, 225, 25),  // Yellow
+    (67, 99, 216),   // Blue
+    (245, 130, 49),  // Orange
+    (145, 30, 180),  // Purple
+    (66, 212, 244),  // Cyan
+    (240, 50, 230),  // Magenta
+    (191, 239, 69),  // Lime
+    (250, 190, 212), // Pink
+    (70, 153, 144),  // Teal
+    (154, 99, 36),   // Brown
+];
+
+/// Color for the `index`-th category of a layer (repeats after 12)
+pub fn palette_color(index: usize) -> Color {
+    let (r, g, b) = PALETTE[index % PALETTE.len()];
+    Color::Rgb { r, g, b }
+}
+
+/// Gradient stops for heatmaps: blue (low), pale yellow, red (high)
+const HEAT_STOPS: [(f64, f64, f64); 3] = [
+    (49.0, 54.0, 149.0),
+    (255.0, 255.0, 191.0),
+    (165.0, 0.0, 38.0),
+];
+
+/// Color at position `t` (0.0 to 1.0) of the heatmap gradient
+pub fn heat_color(t: f64) -> Color {
+    let t = t.clamp(0.0, 1.0) * (HEAT_STOPS.len() - 1) as f64;
+    let i = (t.floor() as usize).min(HEAT_STOPS.len() - 2);
+    let (from, to, f) = (HEAT_STOPS[i], HEAT_STOPS[i + 1], t - i as f64);
+    let mix = |a: f64, b: f64| (a + (b - a) * f).round() as u8;
+    Color::Rgb {
+        r: mix(from.0, to.0),
+        g: mix(from.1, to.1),
+        b: mix(from.2, to.2),
+    }
+}
+
 /// Get appropriate foreground color for contrast against background
 pub fn get_foreground_for_background(bg: Color) -> Color {
     match bg {
@@ -181,3 +222,16 @@ pub fn get_foreground_for_background(bg: Color) -> Color {
         _ => Color::White,
     }
 }
+
+/// CSS hex notation of a color, for SVG and HTML exports
+pub fn to_hex(color: Color) -> String {
+    let (r, g, b) = match color {
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::Black => (0, 0, 0),
+        Color::White => (255, 255, 255),
+        Color::DarkGrey => (96, 96, 96),
+        Color::DarkMagenta => (139, 0, 139),
+        _ => (128, 128, 128),
+    };
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
@@ -1,6 +1,9 @@
-use crate::client::types::{City, Player, Tile};
+use crate::client::types::{City, Player, Tile, Unit};
+use crossterm::style::Color;
 use std::collections::HashMap;
 
+use super::colors::{nation_to_color, terrain_to_color};
+
 /// Marker type for cities on the map
 #[derive(Debug, Clone, Copy)]
 pub enum CityMarker {
@@ -8,6 +11,26 @@ pub enum CityMarker {
     Regular,
 }
 
+/// Edge of a pointy-top hex, named by the neighbor on the other side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    NorthEast,
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// Unit standing on a tile
+#[derive(Debug, Clone)]
+pub struct RenderUnit {
+    pub unit_type: Option<String>,
+    /// Nation of the owning player, None for tribe units
+    pub nation: Option<String>,
+    pub tribe: Option<String>,
+}
+
 /// Processed tile data ready for rendering
 #[derive(Debug)]
 pub struct RenderTile {
@@ -16,6 +39,40 @@ pub struct RenderTile {
     pub vegetation: Option<String>,
     pub owner_nation: Option<String>,
     pub city_marker: Option<CityMarker>,
+    pub city_name: Option<String>,
+    /// Edges with a river (isRiverE, isRiverNE, ...)
+    pub river_edges: Vec<Edge>,
+    /// Units on the tile, only filled by `MapData::add_units`
+    pub units: Vec<RenderUnit>,
+    /// Color set by a map layer, replacing the nation/terrain color
+    pub fill: Option<Color>,
+}
+
+impl RenderTile {
+    /// Layer color if set, else the owner's nation color, else the terrain color
+    pub fn color(&self) -> Color {
+        if let Some(fill) = self.fill {
+            return fill;
+        }
+        match self.owner_nation {
+            Some(ref nation) => nation_to_color(nation),
+            None => terrain_to_color(
+                self.terrain.as_deref(),
+                self.height.as_deref(),
+                self.vegetation.as_deref(),
+            ),
+        }
+    }
+}
+
+/// Rectangle of tiles to render, with an optional highlighted cursor tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+    pub cursor: Option<(i32, i32)>,
 }
 
 /// All data needed to render the map
@@ -26,18 +83,29 @@ pub struct MapData {
     pub max_x: i32,
     pub min_y: i32,
     pub max_y: i32,
+    /// Nation of each player, by index
+    pub player_nations: Vec<Option<String>>,
 }
 
 impl MapData {
+    /// Viewport covering the whole map, without a cursor
+    pub fn full_view(&self) -> Viewport {
+        Viewport {
+            min_x: self.min_x,
+            max_x: self.max_x,
+            min_y: self.min_y,
+            max_y: self.max_y,
+            cursor: None,
+        }
+    }
+
     pub fn from_game_data(tiles: &[Tile], cities: &[City], players: &[Player]) -> Self {
         // Build player index -> nation mapping (players are returned in index order)
-        let player_nations: Vec<Option<&str>> = players
-            .iter()
-            .map(|p| p.nation.as_deref())
-            .collect();
+        let player_nations: Vec<Option<String>> =
+            players.iter().map(|p| p.nation.clone()).collect();
 
-        // Build city location -> marker map
-        let city_markers: HashMap<(i32, i32), CityMarker> = cities
+        // Build city location -> marker and name map
+        let city_markers: HashMap<(i32, i32), (CityMarker, Option<&str>)> = cities
             .iter()
             .filter_map(|c| {
                 let x = c.x? as i32;
@@ -47,7 +115,7 @@ impl MapData {
                 } else {
                     CityMarker::Regular
                 };
-                Some(((x, y), marker))
+                Some(((x, y), (marker, c.name.as_deref())))
             })
             .collect();
 
@@ -73,18 +141,37 @@ impl MapData {
                     None
                 } else {
                     // Parse player index and look up nation
-                    owner.parse::<usize>().ok().and_then(|idx| {
-                        player_nations.get(idx).and_then(|n| n.map(String::from))
-                    })
+                    owner
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|idx| player_nations.get(idx).cloned().flatten())
                 }
             });
 
+            let city = city_markers.get(&(x, y));
+            let rivers = [
+                (tile.is_river_ne, Edge::NorthEast),
+                (tile.is_river_e, Edge::East),
+                (tile.is_river_se, Edge::SouthEast),
+                (tile.is_river_sw, Edge::SouthWest),
+                (tile.is_river_w, Edge::West),
+                (tile.is_river_nw, Edge::NorthWest),
+            ];
+
             let render_tile = RenderTile {
                 terrain: tile.terrain.clone(),
                 height: tile.height.clone(),
                 vegetation: tile.vegetation.clone(),
                 owner_nation,
-                city_marker: city_markers.get(&(x, y)).copied(),
+                city_marker: city.map(|(marker, _)| *marker),
+                city_name: city.and_then(|(_, name)| name.map(String::from)),
+                river_edges: rivers
+                    .iter()
+                    .filter(|(is_river, _)| *is_river == Some(true))
+                    .map(|(_, edge)| *edge)
+                    .collect(),
+                units: Vec::new(),
+                fill: None,
             };
 
             tile_map.insert((x, y), render_tile);
@@ -96,6 +183,29 @@ impl MapData {
             max_x,
             min_y,
             max_y,
+            player_nations,
+        }
+    }
+
+    /// Place units on their tiles, for exports that draw them
+    pub fn add_units(&mut self, units: &[Unit]) {
+        for unit in units {
+            let (Some(x), Some(y)) = (unit.x, unit.y) else {
+                continue;
+            };
+            let Some(tile) = self.tiles.get_mut(&(x as i32, y as i32)) else {
+                continue;
+            };
+            let nation = unit
+                .player
+                .as_deref()
+                .and_then(|p| p.parse::<usize>().ok())
+                .and_then(|idx| self.player_nations.get(idx).cloned().flatten());
+            tile.units.push(RenderUnit {
+                unit_type: unit.type_.clone(),
+                nation,
+                tribe: unit.tribe.clone().filter(|t| t != "NONE"),
+            });
         }
     }
 }
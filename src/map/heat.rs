@@ -0,0 +1,175 @@
+//! Heatmaps of numeric tile fields (`owcli map --heat`)
+//!
+//! Each tile is colored on a blue-yellow-red gradient between the smallest and
+//! largest value of the field on the map. Any integer or number property of
+//! the Tile schema can be used.
+
+use crossterm::style::{Color, Stylize};
+use serde_yaml::Value as Schema;
+
+use crate::client::types::Tile;
+use crate::error::{OwcliError, Result};
+use crate::validate::SPEC;
+
+use super::colors::heat_color;
+use super::data::MapData;
+
+/// Color of tiles without a value for the field
+const NO_VALUE: Color = Color::Rgb {
+    r: 70,
+    g: 70,
+    b: 70,
+};
+
+/// Width of the gradient bar in the legend
+const LEGEND_STEPS: usize = 12;
+
+/// Integer and number properties of the Tile schema, sorted by name
+pub fn numeric_tile_fields() -> Result<Vec<String>> {
+    let spec: Schema = serde_yaml::from_str(SPEC)?;
+    let mut fields: Vec<String> = spec["components"]["schemas"]["Tile"]["properties"]
+        .as_mapping()
+        .into_iter()
+        .flatten()
+        .filter(|(_, schema)| matches!(schema["type"].as_str(), Some("integer" | "number")))
+        .filter_map(|(name, _)| name.as_str().map(String::from))
+        .collect();
+    fields.sort();
+    Ok(fields)
+}
+
+/// Fail before fetching tiles if `field` can't be drawn as a heatmap
+pub fn check_field(field: &str) -> Result<()> {
+    let fields = numeric_tile_fields()?;
+    if fields.iter().any(|f| f == field) {
+        return Ok(());
+    }
+    Err(OwcliError::Other(format!(
+        "'{}' is not a numeric tile field. Use one of: {}",
+        field,
+        fields.join(", ")
+    )))
+}
+
+/// Value range shown under a heatmap
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatLegend {
+    pub field: String,
+    /// Smallest and largest value, None if no tile has one
+    pub range: Option<(f64, f64)>,
+    /// Tiles without a value
+    pub missing: usize,
+}
+
+impl HeatLegend {
+    /// Render as `field: min ████████████ max`
+    pub fn render(&self) -> String {
+        let Some((min, max)) = self.range else {
+            return format!("{}: no tile has a value", self.field);
+        };
+        let bar: String = (0..LEGEND_STEPS)
+            .map(|i| {
+                let t = i as f64 / (LEGEND_STEPS - 1) as f64;
+                "\u{2588}".with(heat_color(t)).to_string()
+            })
+            .collect();
+
+        let mut line = format!(
+            "{}: {} {} {}",
+            self.field,
+            format_value(min),
+            bar,
+            format_value(max)
+        );
+        if self.missing > 0 {
+            line.push_str(&format!("   no value: {} tiles", self.missing));
+        }
+        line
+    }
+}
+
+/// Color the tiles of `map_data` by the numeric `field` and return the legend
+pub fn apply_heat(map_data: &mut MapData, tiles: &[Tile], field: &str) -> Result<HeatLegend> {
+    let mut values = Vec::with_capacity(tiles.len());
+    for (position, tile) in tiles.iter().filter_map(|t| Some(((t.x?, t.y?), t))) {
+        let value = serde_json::to_value(tile)?[field].as_f64();
+        values.push((position, value));
+    }
+
+    let range = values
+        .iter()
+        .filter_map(|(_, value)| *value)
+        .fold(None, |range, v| match range {
+            Some((min, max)) => Some((v.min(min), v.max(max))),
+            None => Some((v, v)),
+        });
+
+    let mut missing = 0;
+    for (position, value) in &values {
+        let fill = match (value, range) {
+            // A field with one value everywhere gets the middle of the gradient
+            (Some(v), Some((min, max))) if max > min => heat_color((v - min) / (max - min)),
+            (Some(_), _) => heat_color(0.5),
+            (None, _) => {
+                missing += 1;
+                NO_VALUE
+            }
+        };
+        if let Some(tile) = map_data.tiles.get_mut(position) {
+            tile.fill = Some(fill);
+        }
+    }
+
+    Ok(HeatLegend {
+        field: field.to_string(),
+        range,
+        missing,
+    })
+}
+
+/// Integers without decimals, other numbers with two
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_heat() {
+        let tiles: Vec<Tile> = serde_json::from_value(json!([
+            // Without coordinates: not in the range or the missing count
+            {"tradeOutpostIncome": 50},
+            {},
+            {"x": 0, "y": 0, "tradeOutpostIncome": 2},
+            {"x": 1, "y": 0, "tradeOutpostIncome": 10},
+            {"x": 2, "y": 0, "tradeOutpostIncome": 6},
+            {"x": 3, "y": 0}
+        ]))
+        .unwrap();
+        let mut map_data = MapData::from_game_data(&tiles, &[], &[]);
+        let legend = apply_heat(&mut map_data, &tiles, "tradeOutpostIncome").unwrap();
+
+        assert_eq!(legend.range, Some((2.0, 10.0)));
+        assert_eq!(legend.missing, 1);
+        assert_eq!(map_data.tiles[&(0, 0)].fill, Some(heat_color(0.0)));
+        assert_eq!(map_data.tiles[&(1, 0)].fill, Some(heat_color(1.0)));
+        assert_eq!(map_data.tiles[&(2, 0)].fill, Some(heat_color(0.5)));
+        assert_eq!(map_data.tiles[&(3, 0)].fill, Some(NO_VALUE));
+    }
+
+    #[test]
+    fn test_check_field() {
+        assert!(check_field("latitude").is_ok());
+        assert!(check_field("constructionProgress").is_ok());
+        assert!(check_field("terrain").is_err());
+        assert_eq!(format_value(3.0), "3");
+        assert_eq!(format_value(0.25), "0.25");
+    }
+}
@@ -1,11 +1,10 @@
 use crate::error::Result;
 use crossterm::style::Stylize;
 
-use super::colors::{get_foreground_for_background, nation_to_color, terrain_to_color};
-use super::data::{CityMarker, MapData};
+use super::colors::get_foreground_for_background;
+use super::data::{CityMarker, MapData, Viewport};
 
-/// Render the hex grid to a colored string (for future interactive mode)
-#[allow(dead_code)]
+/// Render the tiles inside `view` as a colored hex grid
 ///
 /// Hex pattern (flat-top, 4 chars wide, 2 rows per hex):
 /// ```text
@@ -16,8 +15,9 @@ use super::data::{CityMarker, MapData};
 ///  / \ / \ / \ / \
 /// ```
 ///
-/// Odd columns are offset down by half a hex height.
-pub fn render_hex_grid(map_data: &MapData) -> Result<String> {
+/// Odd columns are offset down by half a hex height. The cursor tile is
+/// drawn in brackets, e.g. `[★]`.
+pub fn render_hex_grid(map_data: &MapData, view: &Viewport) -> Result<String> {
     let mut output = String::new();
 
     // For each logical row, we need to render in two passes:
@@ -27,35 +27,35 @@ pub fn render_hex_grid(map_data: &MapData) -> Result<String> {
     // Build output line by line
 
     // Top edge for first row (even columns)
-    output.push_str(&render_top_edge(map_data));
+    output.push_str(&render_top_edge(map_data, view));
     output.push('\n');
 
-    for row in map_data.min_y..=map_data.max_y {
+    for row in view.min_y..=view.max_y {
         // Content row (the |   | part)
-        output.push_str(&render_content_row(map_data, row));
+        output.push_str(&render_content_row(map_data, view, row));
         output.push('\n');
 
         // Bottom edge / connector row
-        if row < map_data.max_y {
-            output.push_str(&render_connector_row(map_data, row));
+        if row < view.max_y {
+            output.push_str(&render_connector_row(map_data, view, row));
             output.push('\n');
         }
     }
 
     // Bottom edge for last row
-    output.push_str(&render_bottom_edge(map_data));
+    output.push_str(&render_bottom_edge(map_data, view));
     output.push('\n');
 
     Ok(output)
 }
 
-fn render_top_edge(map_data: &MapData) -> String {
+fn render_top_edge(map_data: &MapData, view: &Viewport) -> String {
     let mut line = String::new();
 
-    for col in map_data.min_x..=map_data.max_x {
+    for col in view.min_x..=view.max_x {
         let is_odd = (col - map_data.min_x) % 2 != 0;
 
-        if col == map_data.min_x {
+        if col == view.min_x {
             // First hex
             if is_odd {
                 line.push_str("   ");
@@ -65,7 +65,7 @@ fn render_top_edge(map_data: &MapData) -> String {
 
         line.push_str(" \\");
 
-        if !is_odd && col < map_data.max_x {
+        if !is_odd && col < view.max_x {
             line.push_str(" /");
         }
     }
@@ -73,28 +73,15 @@ fn render_top_edge(map_data: &MapData) -> String {
     line
 }
 
-fn render_content_row(map_data: &MapData, row: i32) -> String {
+fn render_content_row(map_data: &MapData, view: &Viewport, row: i32) -> String {
     let mut line = String::new();
 
-    for col in map_data.min_x..=map_data.max_x {
+    for col in view.min_x..=view.max_x {
         let tile = map_data.tiles.get(&(col, row));
         let is_odd = (col - map_data.min_x) % 2 != 0;
 
         // Determine colors
-        let bg_color = match tile {
-            Some(t) => {
-                if let Some(ref nation) = t.owner_nation {
-                    nation_to_color(nation)
-                } else {
-                    terrain_to_color(
-                        t.terrain.as_deref(),
-                        t.height.as_deref(),
-                        t.vegetation.as_deref(),
-                    )
-                }
-            }
-            None => crossterm::style::Color::Black,
-        };
+        let bg_color = tile.map_or(crossterm::style::Color::Black, |t| t.color());
 
         let fg_color = get_foreground_for_background(bg_color);
 
@@ -106,7 +93,7 @@ fn render_content_row(map_data: &MapData, row: i32) -> String {
         };
 
         // Left edge
-        if col == map_data.min_x {
+        if col == view.min_x {
             if is_odd {
                 line.push_str("  ");
             }
@@ -114,8 +101,13 @@ fn render_content_row(map_data: &MapData, row: i32) -> String {
         }
 
         // Content with background color
-        let content = format!(" {} ", center);
-        line.push_str(&content.on(bg_color).with(fg_color).to_string());
+        if view.cursor == Some((col, row)) {
+            let content = format!("[{}]", center);
+            line.push_str(&content.on(bg_color).with(fg_color).bold().to_string());
+        } else {
+            let content = format!(" {} ", center);
+            line.push_str(&content.on(bg_color).with(fg_color).to_string());
+        }
 
         // Right edge
         line.push('|');
@@ -124,13 +116,13 @@ fn render_content_row(map_data: &MapData, row: i32) -> String {
     line
 }
 
-fn render_connector_row(map_data: &MapData, _row: i32) -> String {
+fn render_connector_row(map_data: &MapData, view: &Viewport, _row: i32) -> String {
     let mut line = String::new();
 
-    for col in map_data.min_x..=map_data.max_x {
+    for col in view.min_x..=view.max_x {
         let is_odd = (col - map_data.min_x) % 2 != 0;
 
-        if col == map_data.min_x {
+        if col == view.min_x {
             if is_odd {
                 line.push_str("  ");
             }
@@ -139,11 +131,11 @@ fn render_connector_row(map_data: &MapData, _row: i32) -> String {
             line.push_str(" \\");
         }
 
-        if is_odd || col == map_data.max_x {
+        if is_odd || col == view.max_x {
             line.push_str(" /");
         }
 
-        if is_odd && col < map_data.max_x {
+        if is_odd && col < view.max_x {
             line.push_str(" \\");
         }
     }
@@ -151,13 +143,13 @@ fn render_connector_row(map_data: &MapData, _row: i32) -> String {
     line
 }
 
-fn render_bottom_edge(map_data: &MapData) -> String {
+fn render_bottom_edge(map_data: &MapData, view: &Viewport) -> String {
     let mut line = String::new();
 
-    for col in map_data.min_x..=map_data.max_x {
+    for col in view.min_x..=view.max_x {
         let is_odd = (col - map_data.min_x) % 2 != 0;
 
-        if col == map_data.min_x {
+        if col == view.min_x {
             if is_odd {
                 line.push_str("  ");
             }
@@ -166,7 +158,7 @@ fn render_bottom_edge(map_data: &MapData) -> String {
             line.push_str(" \\");
         }
 
-        if col < map_data.max_x {
+        if col < view.max_x {
             if is_odd {
                 line.push_str(" / \\");
             } else {
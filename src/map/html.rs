@@ -0,0 +1,176 @@
+//! Standalone HTML export of the map (`owcli map --output map.html`)
+//!
+//! The page embeds the SVG map, the game data as JSON and a small script, so it
+//! opens in any browser without a network connection. Hovering a tile shows
+//! the tile as returned by the API, the city whose territory it belongs to and
+//! the units on it.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use crate::client::types::{City, Tile, Unit};
+use crate::error::Result;
+
+use super::data::MapData;
+use super::svg::{render_body, Layout};
+
+/// Data for one tile, looked up by the `data-tile` index of its hex
+#[derive(Serialize)]
+struct TileData<'a> {
+    tile: &'a Tile,
+    /// ID of the owning city, a key of `PageData::cities`
+    city: Option<i32>,
+    units: Vec<&'a Unit>,
+}
+
+#[derive(Serialize)]
+struct PageData<'a> {
+    tiles: Vec<TileData<'a>>,
+    cities: BTreeMap<i32, &'a City>,
+}
+
+const STYLE: &str = "\
+body{margin:0;display:flex;height:100vh;background:#101418;color:#ddd;font:13px sans-serif}
+#map{flex:1;overflow:auto}
+#info{width:380px;overflow:auto;padding:0 12px;border-left:1px solid #333}
+h2{font-size:14px;margin:14px 0 4px}
+pre{margin:0;font-size:11px;white-space:pre-wrap;word-break:break-all}
+polygon[data-tile]:hover{stroke:#fff;stroke-width:2;stroke-opacity:1}
+#units,#cities{pointer-events:none}
+";
+
+const SCRIPT: &str = r#"
+const data = JSON.parse(document.getElementById("map-data").textContent);
+const info = document.getElementById("info");
+
+function section(title, value) {
+  const heading = document.createElement("h2");
+  heading.textContent = title;
+  const body = document.createElement("pre");
+  body.textContent = typeof value === "string" ? value : JSON.stringify(value, null, 2);
+  info.append(heading, body);
+}
+
+document.getElementById("map").addEventListener("mouseover", (event) => {
+  const index = event.target.dataset && event.target.dataset.tile;
+  if (index === undefined) return;
+  const entry = data.tiles[index];
+  info.replaceChildren();
+  section(`Tile (${entry.tile.x}, ${entry.tile.y})`, entry.tile);
+  const city = data.cities[entry.city];
+  section(city ? `City: ${city.name}` : "City", city || "none");
+  section(`Units (${entry.units.length})`, entry.units.length ? entry.units : "none");
+});
+"#;
+
+/// Render the map as a self-contained HTML page
+pub fn render_html(
+    map_data: &MapData,
+    tiles: &[Tile],
+    cities: &[City],
+    units: &[Unit],
+) -> Result<String> {
+    let mut units_by_position: HashMap<(i64, i64), Vec<&Unit>> = HashMap::new();
+    for unit in units {
+        if let (Some(x), Some(y)) = (unit.x, unit.y) {
+            units_by_position.entry((x, y)).or_default().push(unit);
+        }
+    }
+    let cities: BTreeMap<i32, &City> = cities.iter().filter_map(|c| Some((c.id?, c))).collect();
+
+    let mut indexes = HashMap::new();
+    let mut tile_data = Vec::with_capacity(tiles.len());
+    for ((x, y), tile) in tiles.iter().filter_map(|t| Some(((t.x?, t.y?), t))) {
+        indexes.insert((x, y), tile_data.len());
+        // The territory's city, else a city on the tile itself
+        let city = [tile.city_territory, tile.city_id]
+            .into_iter()
+            .flatten()
+            .find(|id| cities.contains_key(id));
+        tile_data.push(TileData {
+            tile,
+            city,
+            units: units_by_position
+                .remove(&(x as i64, y as i64))
+                .unwrap_or_default(),
+        });
+    }
+
+    // "</" would end the script element early
+    let json = serde_json::to_string(&PageData {
+        tiles: tile_data,
+        cities,
+    })?
+    .replace("</", "<\\/");
+
+    let layout = Layout::new(map_data);
+    let svg = render_body(map_data, &layout, |x, y| match indexes.get(&(x, y)) {
+        Some(i) => format!(r#" data-tile="{}""#, i),
+        None => String::new(),
+    });
+
+    Ok(format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Old World map</title>
+<style>{style}</style>
+</head>
+<body>
+<div id="map"><svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}">
+{svg}</svg></div>
+<aside id="info"><h2>Hover a tile for details</h2></aside>
+<script type="application/json" id="map-data">{json}</script>
+<script>{script}</script>
+</body>
+</html>
+"#,
+        style = STYLE,
+        w = layout.width,
+        h = layout.height,
+        svg = svg,
+        json = json,
+        script = SCRIPT
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_html() {
+        let tiles: Vec<Tile> = serde_json::from_value(json!([
+            // Without coordinates: not on the page, so it can't take over (0, 0)
+            {"id": 99, "terrain": "TERRAIN_DESERT"},
+            {"x": 0, "y": 0, "terrain": "TERRAIN_GRASSLAND", "cityTerritory": 1},
+            {"x": 1, "y": 0, "terrain": "TERRAIN_WATER"}
+        ]))
+        .unwrap();
+        let cities: Vec<City> =
+            serde_json::from_value(json!([{"id": 1, "name": "</script>", "x": 0, "y": 0}]))
+                .unwrap();
+        let units: Vec<Unit> =
+            serde_json::from_value(json!([{"id": 7, "type": "UNIT_WARRIOR", "x": 1, "y": 0}]))
+                .unwrap();
+        let map_data = MapData::from_game_data(&tiles, &cities, &[]);
+        let html = render_html(&map_data, &tiles, &cities, &units).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(r#" data-tile="0"/>"#));
+        assert!(html.contains(r#" data-tile="1"/>"#));
+        // The city name can't close the script element
+        assert_eq!(html.matches("</script>").count(), 2);
+
+        let start = html.find(r#"id="map-data">"#).unwrap() + r#"id="map-data">"#.len();
+        let end = start + html[start..].find("</script>").unwrap();
+        let data: serde_json::Value = serde_json::from_str(&html[start..end]).unwrap();
+        assert_eq!(data["tiles"].as_array().unwrap().len(), 2);
+        assert_eq!(data["tiles"][0]["city"], 1);
+        assert_eq!(data["tiles"][1]["units"][0]["id"], 7);
+        assert_eq!(data["cities"]["1"]["name"], "</script>");
+    }
+}
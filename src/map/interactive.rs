@@ -0,0 +1,419 @@
+//! Interactive map viewer (`owcli map --interactive`)
+//!
+//! Shows a window of the map on the alternate screen, as a minimap or a hex
+//! grid, with a cursor that scrolls the window when it reaches an edge. A side
+//! panel describes the tile under the cursor.
+
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::{Print, Stylize};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::client::types::{City, Player, Tile, Unit};
+use crate::error::{OwcliError, Result};
+use crate::output::table::shorten_type;
+
+use super::data::{MapData, Viewport};
+use super::hex::render_hex_grid;
+use super::minimap::render_minimap;
+
+/// Width of the tile panel on the right, in characters
+const PANEL_WIDTH: u16 = 34;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Minimap,
+    Hex,
+}
+
+impl Mode {
+    fn name(self) -> &'static str {
+        match self {
+            Mode::Minimap => "minimap",
+            Mode::Hex => "hex grid",
+        }
+    }
+
+    fn toggle(self) -> Self {
+        match self {
+            Mode::Minimap => Mode::Hex,
+            Mode::Hex => Mode::Minimap,
+        }
+    }
+
+    /// Number of tiles (columns, rows) that fit into `width` x `height` characters
+    fn capacity(self, width: u16, height: u16) -> (i32, i32) {
+        let (width, height) = (width as i32, height as i32);
+        let (cols, rows) = match self {
+            // Two characters per tile, plus one for the odd-row offset
+            Mode::Minimap => ((width - 1) / 2, height),
+            // Four characters per hex plus the edges; two lines per row plus the bottom edge
+            Mode::Hex => ((width - 5) / 4, (height - 1) / 2),
+        };
+        (cols.max(1), rows.max(1))
+    }
+}
+
+/// Cursor and scroll position of the viewer
+#[derive(Debug)]
+struct Viewer {
+    mode: Mode,
+    cursor: (i32, i32),
+    /// Top-left tile of the window
+    origin: (i32, i32),
+    /// Tiles that fit on screen, updated on every draw
+    size: (i32, i32),
+}
+
+impl Viewer {
+    fn new(cursor: (i32, i32)) -> Self {
+        Self {
+            mode: Mode::Minimap,
+            cursor,
+            origin: cursor,
+            size: (1, 1),
+        }
+    }
+
+    fn move_cursor(&mut self, map: &MapData, dx: i32, dy: i32) {
+        self.cursor = (
+            (self.cursor.0 + dx).clamp(map.min_x, map.max_x),
+            (self.cursor.1 + dy).clamp(map.min_y, map.max_y),
+        );
+    }
+
+    /// Scroll the window so the cursor is visible, without panning past the map edges
+    fn viewport(&mut self, map: &MapData) -> Viewport {
+        let (cols, rows) = self.size;
+        let x = scroll(self.origin.0, self.cursor.0, cols, map.min_x, map.max_x);
+        let y = scroll(self.origin.1, self.cursor.1, rows, map.min_y, map.max_y);
+        self.origin = (x, y);
+        Viewport {
+            min_x: x,
+            max_x: (x + cols - 1).min(map.max_x),
+            min_y: y,
+            max_y: (y + rows - 1).min(map.max_y),
+            cursor: Some(self.cursor),
+        }
+    }
+}
+
+/// New window start on one axis for a window of `size` tiles
+fn scroll(origin: i32, cursor: i32, size: i32, min: i32, max: i32) -> i32 {
+    let origin = if cursor < origin {
+        cursor
+    } else if cursor >= origin + size {
+        cursor - size + 1
+    } else {
+        origin
+    };
+    origin.min(max - size + 1).max(min)
+}
+
+/// Tiles, cities and units by position, for the side panel
+pub struct Inspector<'a> {
+    tiles: HashMap<(i32, i32), &'a Tile>,
+    cities: Vec<((i32, i32), &'a City)>,
+    units: HashMap<(i32, i32), Vec<&'a Unit>>,
+    nations: Vec<Option<&'a str>>,
+}
+
+impl<'a> Inspector<'a> {
+    pub fn new(
+        tiles: &'a [Tile],
+        cities: &'a [City],
+        players: &'a [Player],
+        units: &'a [Unit],
+    ) -> Self {
+        let mut by_position: HashMap<(i32, i32), Vec<&Unit>> = HashMap::new();
+        for unit in units {
+            if let (Some(x), Some(y)) = (unit.x, unit.y) {
+                by_position
+                    .entry((x as i32, y as i32))
+                    .or_default()
+                    .push(unit);
+            }
+        }
+
+        Self {
+            tiles: tiles
+                .iter()
+                .filter_map(|t| Some(((t.x?, t.y?), t)))
+                .collect(),
+            cities: cities
+                .iter()
+                .filter_map(|c| Some(((c.x? as i32, c.y? as i32), c)))
+                .collect(),
+            units: by_position,
+            nations: players.iter().map(|p| p.nation.as_deref()).collect(),
+        }
+    }
+
+    /// Where the cursor starts: the first capital, else the middle of the map
+    fn start(&self, map: &MapData) -> (i32, i32) {
+        self.cities
+            .iter()
+            .find(|(_, c)| c.is_capital == Some(true))
+            .map(|(position, _)| *position)
+            .unwrap_or(((map.min_x + map.max_x) / 2, (map.min_y + map.max_y) / 2))
+    }
+
+    /// The city after the one at `cursor`, in API order
+    fn next_city(&self, cursor: (i32, i32)) -> Option<(i32, i32)> {
+        let next = match self.cities.iter().position(|(p, _)| *p == cursor) {
+            Some(i) => (i + 1) % self.cities.len(),
+            None => 0,
+        };
+        self.cities.get(next).map(|(position, _)| *position)
+    }
+
+    /// Nation name of a player index such as "0", or None for "NONE"
+    fn nation(&self, player: Option<&str>) -> Option<String> {
+        let index: usize = player?.parse().ok()?;
+        let nation = self.nations.get(index).copied().flatten().unwrap_or("?");
+        Some(format!("{} (player {})", display_name(Some(nation)), index))
+    }
+
+    /// Side panel lines for the tile at `position`
+    fn panel(&self, position: (i32, i32)) -> Vec<String> {
+        let (x, y) = position;
+        let Some(tile) = self.tiles.get(&position) else {
+            return vec![
+                format!("({}, {})", x, y),
+                String::new(),
+                "No tile here".to_string(),
+            ];
+        };
+
+        let owner = self
+            .nation(tile.owner.as_deref())
+            .or_else(|| {
+                tile.owner_tribe
+                    .as_deref()
+                    .filter(|t| *t != "NONE")
+                    .map(|t| format!("{} (tribe)", display_name(Some(t))))
+            })
+            .unwrap_or_else(|| "-".to_string());
+        let city = match self.cities.iter().find(|(p, _)| *p == position) {
+            Some((_, city)) => {
+                let name = city.name.as_deref().unwrap_or("?");
+                if city.is_capital == Some(true) {
+                    format!("{} (capital)", name)
+                } else {
+                    name.to_string()
+                }
+            }
+            None => "-".to_string(),
+        };
+
+        let mut lines = vec![
+            match tile.id {
+                Some(id) => format!("Tile {} at ({}, {})", id, x, y),
+                None => format!("Tile at ({}, {})", x, y),
+            },
+            String::new(),
+            format!("Terrain:     {}", display_name(tile.terrain.as_deref())),
+            format!("Height:      {}", display_name(tile.height.as_deref())),
+            format!("Vegetation:  {}", display_name(tile.vegetation.as_deref())),
+            format!("Resource:    {}", display_name(tile.resource.as_deref())),
+            format!("Owner:       {}", owner),
+            format!("Improvement: {}", display_name(tile.improvement.as_deref())),
+            format!("City:        {}", city),
+        ];
+
+        let units = self
+            .units
+            .get(&position)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if units.is_empty() {
+            lines.push("Units:       -".to_string());
+        } else {
+            lines.push("Units:".to_string());
+            for unit in units {
+                let owner = self
+                    .nation(unit.player.as_deref())
+                    .or_else(|| {
+                        unit.tribe
+                            .as_deref()
+                            .filter(|t| *t != "NONE")
+                            .map(|t| display_name(Some(t)))
+                    })
+                    .unwrap_or_else(|| "-".to_string());
+                lines.push(format!(
+                    "  {} {}, {}",
+                    display_name(unit.type_.as_deref()),
+                    unit.id.map(|id| format!("#{}", id)).unwrap_or_default(),
+                    owner
+                ));
+            }
+        }
+        lines
+    }
+}
+
+/// Readable name of a game type, e.g. "TERRAIN_GRASSLAND" -> "Grassland"; "-" for none
+fn display_name(value: Option<&str>) -> String {
+    match value.map(shorten_type) {
+        Some(name) if !name.is_empty() => name,
+        _ => "-".to_string(),
+    }
+}
+
+/// Run the viewer until the user quits with q or Esc
+pub fn run_interactive(map: &MapData, inspector: &Inspector) -> Result<()> {
+    if !io::stdout().is_terminal() {
+        return Err(OwcliError::Other(
+            "map --interactive needs a terminal".into(),
+        ));
+    }
+    if map.tiles.is_empty() {
+        return Err(OwcliError::Other("The map has no tiles".into()));
+    }
+
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    let result = event_loop(&mut stdout, map, inspector);
+
+    // Restore the terminal even if drawing failed
+    let _ = execute!(stdout, Show, LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+    result
+}
+
+fn event_loop(out: &mut impl Write, map: &MapData, inspector: &Inspector) -> Result<()> {
+    let mut viewer = Viewer::new(inspector.start(map));
+
+    loop {
+        draw(out, map, inspector, &mut viewer)?;
+
+        // Resize events just redraw
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let page = viewer.size.1;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Up => viewer.move_cursor(map, 0, -1),
+            KeyCode::Down => viewer.move_cursor(map, 0, 1),
+            KeyCode::Left => viewer.move_cursor(map, -1, 0),
+            KeyCode::Right => viewer.move_cursor(map, 1, 0),
+            KeyCode::PageUp => viewer.move_cursor(map, 0, -page),
+            KeyCode::PageDown => viewer.move_cursor(map, 0, page),
+            KeyCode::Tab => viewer.mode = viewer.mode.toggle(),
+            KeyCode::Char('c') => {
+                if let Some(position) = inspector.next_city(viewer.cursor) {
+                    viewer.cursor = position;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    map: &MapData,
+    inspector: &Inspector,
+    viewer: &mut Viewer,
+) -> Result<()> {
+    let (cols, rows) = terminal::size()?;
+    let map_width = cols.saturating_sub(PANEL_WIDTH + 1);
+    let map_height = rows.saturating_sub(1);
+    viewer.size = viewer.mode.capacity(map_width, map_height);
+
+    let view = viewer.viewport(map);
+    let rendered = match viewer.mode {
+        Mode::Minimap => render_minimap(map, &view)?,
+        Mode::Hex => render_hex_grid(map, &view)?,
+    };
+
+    let header = format!(
+        "{} ({}, {})  arrows/PgUp/PgDn: move  Tab: {}  c: next city  q: quit",
+        viewer.mode.name(),
+        viewer.cursor.0,
+        viewer.cursor.1,
+        viewer.mode.toggle().name()
+    );
+    let header: String = header.chars().take(cols as usize).collect();
+    queue!(
+        out,
+        Clear(ClearType::All),
+        MoveTo(0, 0),
+        Print(header.bold())
+    )?;
+
+    // Raw mode doesn't return the carriage on newlines, so place each line
+    for (row, line) in rendered.lines().take(map_height as usize).enumerate() {
+        queue!(out, MoveTo(0, row as u16 + 1), Print(line))?;
+    }
+    for (row, line) in inspector
+        .panel(viewer.cursor)
+        .iter()
+        .take(map_height as usize)
+        .enumerate()
+    {
+        let line: String = line.chars().take(PANEL_WIDTH as usize).collect();
+        queue!(out, MoveTo(map_width + 1, row as u16 + 1), Print(line))?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_scroll_follows_cursor() {
+        // Cursor inside the window: no scrolling
+        assert_eq!(scroll(10, 15, 10, 0, 99), 10);
+        // Past either edge: the window moves just enough
+        assert_eq!(scroll(10, 25, 10, 0, 99), 16);
+        assert_eq!(scroll(10, 4, 10, 0, 99), 4);
+        // Never past the end of the map, and maps smaller than the window start at min
+        assert_eq!(scroll(95, 99, 10, 0, 99), 90);
+        assert_eq!(scroll(3, 5, 10, 0, 6), 0);
+    }
+
+    #[test]
+    fn test_panel() {
+        let tiles: Vec<Tile> = serde_json::from_value(json!([{
+            "id": 42, "x": 3, "y": 4, "terrain": "TERRAIN_GRASSLAND", "height": "HEIGHT_FLAT",
+            "vegetation": "NONE", "owner": "0", "improvement": "IMPROVEMENT_FARM"
+        }, {"id": 43, "terrain": "TERRAIN_WATER"}]))
+        .unwrap();
+        let cities: Vec<City> =
+            serde_json::from_value(json!([{"name": "Roma", "x": 3, "y": 4, "isCapital": true}]))
+                .unwrap();
+        let players: Vec<Player> =
+            serde_json::from_value(json!([{"nation": "NATION_ROME"}])).unwrap();
+        let units: Vec<Unit> = serde_json::from_value(json!([
+            {"id": 7, "type": "UNIT_WARRIOR", "player": "0", "x": 3, "y": 4}
+        ]))
+        .unwrap();
+
+        let inspector = Inspector::new(&tiles, &cities, &players, &units);
+        let panel = inspector.panel((3, 4));
+        assert_eq!(panel[0], "Tile 42 at (3, 4)");
+        assert!(panel.contains(&"Terrain:     Grassland".to_string()));
+        assert!(panel.contains(&"Vegetation:  -".to_string()));
+        assert!(panel.contains(&"Owner:       Rome (player 0)".to_string()));
+        assert!(panel.contains(&"Improvement: Farm".to_string()));
+        assert!(panel.contains(&"City:        Roma (capital)".to_string()));
+        assert_eq!(panel.last().unwrap(), "  Warrior #7, Rome (player 0)");
+        assert_eq!(inspector.next_city((0, 0)), Some((3, 4)));
+        // A tile without coordinates isn't placed anywhere
+        assert_eq!(inspector.panel((0, 0))[2], "No tile here");
+    }
+}
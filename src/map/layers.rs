@@ -0,0 +1,276 @@
+//! Map layers (`owcli map --layer`)
+//!
+//! A layer recolors the map by one aspect of the tiles, such as resources or
+//! roads. Tiles outside every category of the layer are drawn in a neutral
+//! gray (or dark blue for water), so the layer stands out while coastlines stay
+//! visible.
+
+use std::collections::HashMap;
+
+use crossterm::style::{Color, Stylize};
+
+use crate::client::types::Tile;
+use crate::output::table::shorten_type;
+
+use super::colors::{palette_color, tribe_to_color};
+use super::data::MapData;
+
+const NEUTRAL_LAND: Color = Color::Rgb {
+    r: 70,
+    g: 70,
+    b: 70,
+};
+const NEUTRAL_WATER: Color = Color::Rgb {
+    r: 20,
+    g: 45,
+    b: 80,
+};
+
+const ROAD: Color = Color::Rgb {
+    r: 214,
+    g: 160,
+    b: 90,
+};
+const RIVER: Color = Color::Rgb {
+    r: 70,
+    g: 160,
+    b: 240,
+};
+const ROAD_AND_RIVER: Color = Color::Rgb {
+    r: 180,
+    g: 120,
+    b: 220,
+};
+const URBAN: Color = Color::Rgb {
+    r: 230,
+    g: 200,
+    b: 120,
+};
+const PILLAGED: Color = Color::Rgb {
+    r: 220,
+    g: 40,
+    b: 40,
+};
+
+/// Legends wrap at this many visible characters
+const LEGEND_WIDTH: usize = 80;
+
+/// Legend label of a tile category and its color, if the layer has a fixed one
+type Category = (String, Option<Color>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Layer {
+    /// Resources (horses, iron, ...)
+    Resources,
+    /// Improvements (farms, mines, ...)
+    Improvements,
+    /// Religions present on the tile
+    Religion,
+    /// Family territory
+    Family,
+    /// Tiles owned by tribes
+    Tribes,
+    /// Roads and rivers
+    Roads,
+    /// Urban tiles
+    Urban,
+    /// Pillaged improvements
+    Pillaged,
+}
+
+impl Layer {
+    /// Tile fields the layer needs on top of the base map fields
+    pub fn tile_fields(self) -> &'static str {
+        match self {
+            Layer::Resources => "resource",
+            Layer::Improvements => "improvement",
+            Layer::Religion => "isReligions",
+            Layer::Family => "family",
+            Layer::Tribes => "ownerTribe",
+            Layer::Roads => "isRoad,isRiver",
+            Layer::Urban => "isUrban",
+            Layer::Pillaged => "isPillaged",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Layer::Resources => "Resources",
+            Layer::Improvements => "Improvements",
+            Layer::Religion => "Religion",
+            Layer::Family => "Family territory",
+            Layer::Tribes => "Tribe ownership",
+            Layer::Roads => "Roads and rivers",
+            Layer::Urban => "Urban tiles",
+            Layer::Pillaged => "Pillaged tiles",
+        }
+    }
+
+    /// Category of a tile, None if it is in none
+    fn category(self, tile: &Tile) -> Option<Category> {
+        let flag = |value: Option<bool>| value == Some(true);
+        match self {
+            Layer::Resources => game_type(tile.resource.as_deref()),
+            Layer::Improvements => game_type(tile.improvement.as_deref()),
+            Layer::Family => game_type(tile.family.as_deref()),
+            Layer::Religion => {
+                let mut religions: Vec<&String> = tile
+                    .is_religions
+                    .iter()
+                    .filter(|(_, present)| **present)
+                    .map(|(religion, _)| religion)
+                    .collect();
+                religions.sort();
+                match religions.as_slice() {
+                    [] => None,
+                    [religion] => Some((shorten_type(religion), None)),
+                    _ => Some(("Several".to_string(), None)),
+                }
+            }
+            Layer::Tribes => tile
+                .owner_tribe
+                .as_deref()
+                .filter(|t| *t != "NONE")
+                .map(|t| (shorten_type(t), Some(tribe_to_color(t)))),
+            Layer::Roads => match (flag(tile.is_road), flag(tile.is_river)) {
+                (true, true) => Some(("Road and river".to_string(), Some(ROAD_AND_RIVER))),
+                (true, false) => Some(("Road".to_string(), Some(ROAD))),
+                (false, true) => Some(("River".to_string(), Some(RIVER))),
+                (false, false) => None,
+            },
+            Layer::Urban => flag(tile.is_urban).then(|| ("Urban".to_string(), Some(URBAN))),
+            Layer::Pillaged => {
+                flag(tile.is_pillaged).then(|| ("Pillaged".to_string(), Some(PILLAGED)))
+            }
+        }
+    }
+}
+
+/// Category for a game type such as "RESOURCE_HORSE"; None for "NONE"
+fn game_type(value: Option<&str>) -> Option<Category> {
+    value
+        .filter(|v| *v != "NONE")
+        .map(|v| (shorten_type(v), None))
+        .filter(|(label, _)| !label.is_empty())
+}
+
+/// One colored category of a legend
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendEntry {
+    pub color: Color,
+    pub label: String,
+}
+
+/// Title and categories shown under a layered map
+#[derive(Debug, Clone, PartialEq)]
+pub struct Legend {
+    pub title: String,
+    pub entries: Vec<LegendEntry>,
+}
+
+impl Legend {
+    /// Render as `Title:` followed by `██ Label` entries, wrapped to fit a terminal
+    pub fn render(&self) -> String {
+        let mut output = format!("{}:", self.title);
+        if self.entries.is_empty() {
+            output.push_str(" no tiles");
+            return output;
+        }
+
+        let mut width = LEGEND_WIDTH;
+        for entry in &self.entries {
+            // Measure without escape codes
+            let len = entry.label.chars().count() + 5;
+            if width + len > LEGEND_WIDTH {
+                output.push('\n');
+                width = 0;
+            }
+            output.push_str(&format!(
+                "{} {}   ",
+                "\u{2588}\u{2588}".with(entry.color),
+                entry.label
+            ));
+            width += len;
+        }
+        output.trim_end().to_string()
+    }
+}
+
+/// Color the tiles of `map_data` by `layer` and return the legend
+///
+/// Categories are listed by tile count, most common first; those without a
+/// fixed color get palette colors in that order.
+pub fn apply_layer(map_data: &mut MapData, tiles: &[Tile], layer: Layer) -> Legend {
+    let categories: Vec<((i32, i32), Option<Category>)> = tiles
+        .iter()
+        .filter_map(|t| Some(((t.x?, t.y?), layer.category(t))))
+        .collect();
+
+    let mut counts: HashMap<&str, (usize, Option<Color>)> = HashMap::new();
+    for (label, color) in categories.iter().filter_map(|(_, c)| c.as_ref()) {
+        counts.entry(label).or_insert((0, *color)).0 += 1;
+    }
+    let mut ranked: Vec<(&str, usize, Option<Color>)> = counts
+        .into_iter()
+        .map(|(label, (count, color))| (label, count, color))
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+    let entries: Vec<LegendEntry> = ranked
+        .iter()
+        .enumerate()
+        .map(|(i, (label, count, color))| LegendEntry {
+            color: color.unwrap_or_else(|| palette_color(i)),
+            label: format!("{} ({})", label, count),
+        })
+        .collect();
+    let colors: HashMap<&str, Color> = ranked
+        .iter()
+        .zip(&entries)
+        .map(|((label, _, _), entry)| (*label, entry.color))
+        .collect();
+
+    for (position, category) in &categories {
+        let Some(tile) = map_data.tiles.get_mut(position) else {
+            continue;
+        };
+        tile.fill = Some(match category {
+            Some((label, _)) => colors[label.as_str()],
+            None if tile.terrain.as_deref().is_some_and(|t| t.contains("WATER")) => NEUTRAL_WATER,
+            None => NEUTRAL_LAND,
+        });
+    }
+
+    Legend {
+        title: layer.title().to_string(),
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_layer() {
+        let tiles: Vec<Tile> = serde_json::from_value(json!([
+            // Without coordinates: neither drawn nor counted
+            {"terrain": "TERRAIN_GRASSLAND", "resource": "RESOURCE_IRON"},
+            {"x": 0, "y": 0, "terrain": "TERRAIN_GRASSLAND", "resource": "RESOURCE_HORSE"},
+            {"x": 1, "y": 0, "terrain": "TERRAIN_GRASSLAND", "resource": "RESOURCE_IRON"},
+            {"x": 2, "y": 0, "terrain": "TERRAIN_GRASSLAND", "resource": "RESOURCE_HORSE"},
+            {"x": 3, "y": 0, "terrain": "TERRAIN_WATER", "resource": "NONE"}
+        ]))
+        .unwrap();
+        let mut map_data = MapData::from_game_data(&tiles, &[], &[]);
+        let legend = apply_layer(&mut map_data, &tiles, Layer::Resources);
+
+        let labels: Vec<&str> = legend.entries.iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(labels, ["Horse (2)", "Iron (1)"]);
+        assert_eq!(map_data.tiles[&(0, 0)].fill, Some(legend.entries[0].color));
+        assert_eq!(map_data.tiles[&(2, 0)].fill, Some(legend.entries[0].color));
+        assert_eq!(map_data.tiles[&(1, 0)].fill, Some(legend.entries[1].color));
+        assert_eq!(map_data.tiles[&(3, 0)].fill, Some(NEUTRAL_WATER));
+    }
+}
@@ -1,22 +1,107 @@
 pub mod colors;
 pub mod data;
+pub mod heat;
 pub mod hex;
+pub mod html;
+pub mod interactive;
+pub mod layers;
 pub mod minimap;
+pub mod svg;
 
-use crate::client::types::{City, Player, Tile};
-use crate::error::Result;
+use std::path::Path;
+
+use crate::client::types::{City, Player, Tile, Unit};
+use crate::error::{OwcliError, Result};
 
 pub use data::MapData;
+pub use layers::Layer;
 
-/// Render a compact minimap (default for `owcli map`)
-pub fn render_map(tiles: &[Tile], cities: &[City], players: &[Player]) -> Result<String> {
-    let map_data = MapData::from_game_data(tiles, cities, players);
-    minimap::render_minimap(&map_data)
+/// How `owcli map` colors the tiles
+#[derive(Debug, Clone, Copy)]
+pub enum Coloring<'a> {
+    /// Owner's nation, else terrain
+    Nations,
+    /// Categories of a layer (`--layer`)
+    Layer(Layer),
+    /// Gradient over a numeric tile field (`--heat`)
+    Heat(&'a str),
+}
+
+/// Render a compact minimap (default for `owcli map`), followed by a legend unless colored by nation
+pub fn render_map(
+    tiles: &[Tile],
+    cities: &[City],
+    players: &[Player],
+    coloring: Coloring,
+) -> Result<String> {
+    let mut map_data = MapData::from_game_data(tiles, cities, players);
+    let legend = match coloring {
+        Coloring::Nations => None,
+        Coloring::Layer(layer) => Some(layers::apply_layer(&mut map_data, tiles, layer).render()),
+        Coloring::Heat(field) => Some(heat::apply_heat(&mut map_data, tiles, field)?.render()),
+    };
+
+    let mut output = minimap::render_minimap(&map_data, &map_data.full_view())?;
+    if let Some(legend) = legend {
+        output.push('\n');
+        output.push_str(&legend);
+    }
+    Ok(output)
+}
+
+/// File formats of `owcli map --output`, chosen by extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Svg,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase);
+        match extension.as_deref() {
+            Some("svg") => Ok(ExportFormat::Svg),
+            Some("html" | "htm") => Ok(ExportFormat::Html),
+            _ => Err(OwcliError::Other(format!(
+                "Cannot export the map to '{}': use a .svg or .html file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Render the map as a file for `owcli map --output`, with unit icons if `unit_icons` is set
+///
+/// HTML pages show `units` on hover either way.
+pub fn export_map(
+    format: ExportFormat,
+    tiles: &[Tile],
+    cities: &[City],
+    players: &[Player],
+    units: &[Unit],
+    unit_icons: bool,
+) -> Result<String> {
+    let mut map_data = MapData::from_game_data(tiles, cities, players);
+    if unit_icons {
+        map_data.add_units(units);
+    }
+    match format {
+        ExportFormat::Svg => Ok(svg::render_svg(&map_data)),
+        ExportFormat::Html => html::render_html(&map_data, tiles, cities, units),
+    }
 }
 
-/// Render detailed hex grid (for future interactive mode)
-#[allow(dead_code)]
-pub fn render_hex_map(tiles: &[Tile], cities: &[City], players: &[Player]) -> Result<String> {
+/// Browse the map in the terminal (`owcli map --interactive`)
+pub fn interactive_map(
+    tiles: &[Tile],
+    cities: &[City],
+    players: &[Player],
+    units: &[Unit],
+) -> Result<()> {
     let map_data = MapData::from_game_data(tiles, cities, players);
-    hex::render_hex_grid(&map_data)
+    let inspector = interactive::Inspector::new(tiles, cities, players, units);
+    interactive::run_interactive(&map_data, &inspector)
 }
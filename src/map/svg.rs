@@ -0,0 +1,321 @@
+//! SVG export of the map (`owcli map --output map.svg`)
+//!
+//! Tiles are drawn as pointy-top hexes with north (higher y) up. As in the
+//! minimap, odd rows are shifted half a hex to the right. Terrain is the base
+//! color, owned tiles get a translucent nation color on top, and rivers run
+//! along the hex edges flagged by `isRiverE`, `isRiverNE`, ...
+
+use std::fmt::Write;
+
+use crate::output::table::shorten_type;
+
+use super::colors::{nation_to_color, terrain_to_color, to_hex, tribe_to_color};
+use super::data::{CityMarker, Edge, MapData, RenderTile};
+
+/// Distance from a hex center to its corners, in pixels
+const HEX_SIZE: f64 = 14.0;
+const MARGIN: f64 = 24.0;
+
+const BACKGROUND: &str = "#101418";
+const RIVER: &str = "#3a8fd9";
+
+/// Pixel positions of the hexes
+pub(crate) struct Layout {
+    min_x: i32,
+    min_y: i32,
+    max_y: i32,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Layout {
+    pub(crate) fn new(map_data: &MapData) -> Self {
+        let cols = (map_data.max_x - map_data.min_x + 1).max(0) as f64;
+        let rows = (map_data.max_y - map_data.min_y + 1).max(0) as f64;
+        Self {
+            min_x: map_data.min_x,
+            min_y: map_data.min_y,
+            max_y: map_data.max_y,
+            // Half a hex more for the shifted rows
+            width: 2.0 * MARGIN + (cols + 0.5) * hex_width(),
+            height: 2.0 * MARGIN + (rows * 1.5 + 0.5) * HEX_SIZE,
+        }
+    }
+
+    pub(crate) fn center(&self, x: i32, y: i32) -> (f64, f64) {
+        let shift = if (y - self.min_y) % 2 != 0 { 0.5 } else { 0.0 };
+        (
+            MARGIN + (x - self.min_x) as f64 * hex_width() + (shift + 0.5) * hex_width(),
+            MARGIN + HEX_SIZE + (self.max_y - y) as f64 * 1.5 * HEX_SIZE,
+        )
+    }
+
+    /// Corners clockwise from the top: top, upper right, lower right, bottom, lower left, upper left
+    pub(crate) fn corners(&self, x: i32, y: i32) -> [(f64, f64); 6] {
+        let (cx, cy) = self.center(x, y);
+        let (dx, dy) = (hex_width() / 2.0, HEX_SIZE / 2.0);
+        [
+            (cx, cy - HEX_SIZE),
+            (cx + dx, cy - dy),
+            (cx + dx, cy + dy),
+            (cx, cy + HEX_SIZE),
+            (cx - dx, cy + dy),
+            (cx - dx, cy - dy),
+        ]
+    }
+
+    fn edge(&self, x: i32, y: i32, edge: Edge) -> ((f64, f64), (f64, f64)) {
+        let c = self.corners(x, y);
+        let (from, to) = match edge {
+            Edge::NorthEast => (0, 1),
+            Edge::East => (1, 2),
+            Edge::SouthEast => (2, 3),
+            Edge::SouthWest => (3, 4),
+            Edge::West => (4, 5),
+            Edge::NorthWest => (5, 0),
+        };
+        (c[from], c[to])
+    }
+
+    /// `points` attribute of the hex at (x, y)
+    pub(crate) fn points(&self, x: i32, y: i32) -> String {
+        self.corners(x, y)
+            .iter()
+            .map(|(px, py)| format!("{:.1},{:.1}", px, py))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn hex_width() -> f64 {
+    3f64.sqrt() * HEX_SIZE
+}
+
+/// Tiles in drawing order: north to south, west to east
+pub(crate) fn sorted_tiles(map_data: &MapData) -> Vec<(&(i32, i32), &RenderTile)> {
+    let mut tiles: Vec<_> = map_data.tiles.iter().collect();
+    tiles.sort_by_key(|((x, y), _)| (-y, *x));
+    tiles
+}
+
+/// Render the map as a standalone SVG document
+pub fn render_svg(map_data: &MapData) -> String {
+    let layout = Layout::new(map_data);
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}">"#,
+        w = layout.width,
+        h = layout.height
+    );
+    svg.push_str(&render_body(map_data, &layout, |_, _| String::new()));
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Style, tiles, rivers, units and cities, without the `<svg>` element;
+/// `tile_attributes` adds attributes to each tile polygon
+pub(crate) fn render_body(
+    map_data: &MapData,
+    layout: &Layout,
+    tile_attributes: impl Fn(i32, i32) -> String,
+) -> String {
+    let tiles = sorted_tiles(map_data);
+    let mut svg = String::new();
+
+    svg.push_str(
+        "<style>\
+         text{font-family:sans-serif;text-anchor:middle;pointer-events:none}\
+         .name{font-size:11px;font-weight:bold;fill:#fff;stroke:#000;stroke-width:2.5px;paint-order:stroke}\
+         .unit{font-size:8px;font-weight:bold;fill:#000;stroke:none}\
+         </style>\n",
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="100%" height="100%" fill="{}"/>"#,
+        BACKGROUND
+    );
+
+    svg.push_str("<g id=\"tiles\" stroke=\"#000\" stroke-opacity=\"0.25\" stroke-width=\"0.5\">\n");
+    for ((x, y), tile) in &tiles {
+        let terrain = terrain_to_color(
+            tile.terrain.as_deref(),
+            tile.height.as_deref(),
+            tile.vegetation.as_deref(),
+        );
+        let points = layout.points(*x, *y);
+        let _ = writeln!(
+            svg,
+            r#"<polygon points="{}" fill="{}"{}/>"#,
+            points,
+            to_hex(terrain),
+            tile_attributes(*x, *y)
+        );
+        if let Some(ref nation) = tile.owner_nation {
+            let _ = writeln!(
+                svg,
+                r#"<polygon points="{}" fill="{}" fill-opacity="0.6" pointer-events="none"/>"#,
+                points,
+                to_hex(nation_to_color(nation))
+            );
+        }
+    }
+    svg.push_str("</g>\n");
+
+    let _ = writeln!(
+        svg,
+        r#"<g id="rivers" stroke="{}" stroke-width="3" stroke-linecap="round">"#,
+        RIVER
+    );
+    for ((x, y), tile) in &tiles {
+        for edge in &tile.river_edges {
+            let ((x1, y1), (x2, y2)) = layout.edge(*x, *y, *edge);
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}"/>"#,
+                x1, y1, x2, y2
+            );
+        }
+    }
+    svg.push_str("</g>\n");
+
+    svg.push_str("<g id=\"units\" stroke=\"#000\" stroke-width=\"0.75\">\n");
+    for ((x, y), tile) in &tiles {
+        let Some(unit) = tile.units.first() else {
+            continue;
+        };
+        let (cx, cy) = layout.center(*x, *y);
+        let (ux, uy) = (cx + HEX_SIZE * 0.4, cy + HEX_SIZE * 0.35);
+        let color = match (&unit.nation, &unit.tribe) {
+            (Some(nation), _) => nation_to_color(nation),
+            (None, Some(tribe)) => tribe_to_color(tribe),
+            (None, None) => crossterm::style::Color::DarkGrey,
+        };
+        let names: Vec<String> = tile
+            .units
+            .iter()
+            .map(|u| shorten_type(u.unit_type.as_deref().unwrap_or("UNIT_UNKNOWN")))
+            .collect();
+        let initial = names[0].chars().next().unwrap_or('?');
+        let _ = writeln!(
+            svg,
+            r#"<g><title>{}</title><circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}"/><text class="unit" x="{:.1}" y="{:.1}">{}</text></g>"#,
+            escape(&names.join(", ")),
+            ux,
+            uy,
+            HEX_SIZE * 0.38,
+            to_hex(color),
+            ux,
+            uy + 3.0,
+            escape(&initial.to_string())
+        );
+    }
+    svg.push_str("</g>\n");
+
+    svg.push_str("<g id=\"cities\" stroke=\"#000\" stroke-width=\"1\">\n");
+    let mut names = String::new();
+    for ((x, y), tile) in &tiles {
+        let Some(marker) = tile.city_marker else {
+            continue;
+        };
+        let (cx, cy) = layout.center(*x, *y);
+        let _ = match marker {
+            CityMarker::Capital => writeln!(
+                svg,
+                r##"<polygon points="{}" fill="#ffd700"/>"##,
+                star_points(cx, cy, HEX_SIZE * 0.6, HEX_SIZE * 0.25)
+            ),
+            CityMarker::Regular => writeln!(
+                svg,
+                r##"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="#fff"/>"##,
+                cx,
+                cy,
+                HEX_SIZE * 0.3
+            ),
+        };
+        if let Some(ref name) = tile.city_name {
+            let _ = writeln!(
+                names,
+                r#"<text class="name" x="{:.1}" y="{:.1}">{}</text>"#,
+                cx,
+                cy - HEX_SIZE * 0.8,
+                escape(name)
+            );
+        }
+    }
+    svg.push_str("</g>\n");
+    // Names go last so no tile or marker covers them
+    let _ = writeln!(svg, "<g id=\"city-names\">\n{}</g>", names);
+    svg
+}
+
+/// Five-pointed star centered on (cx, cy)
+fn star_points(cx: f64, cy: f64, outer: f64, inner: f64) -> String {
+    (0..10)
+        .map(|i| {
+            let radius = if i % 2 == 0 { outer } else { inner };
+            let angle = std::f64::consts::PI * (i as f64 / 5.0 - 0.5);
+            format!(
+                "{:.1},{:.1}",
+                cx + radius * angle.cos(),
+                cy + radius * angle.sin()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Escape text for XML content and attributes
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::types::{City, Player, Tile, Unit};
+    use serde_json::json;
+
+    #[test]
+    fn test_render_svg() {
+        let tiles: Vec<Tile> = serde_json::from_value(json!([
+            {"x": 0, "y": 0, "terrain": "TERRAIN_GRASSLAND", "owner": "0", "isRiverE": true},
+            {"x": 1, "y": 0, "terrain": "TERRAIN_WATER"},
+            {"x": 0, "y": 1, "terrain": "TERRAIN_GRASSLAND"}
+        ]))
+        .unwrap();
+        let cities: Vec<City> = serde_json::from_value(json!([
+            {"name": "Roma & Co", "x": 0, "y": 0, "isCapital": true}
+        ]))
+        .unwrap();
+        let players: Vec<Player> =
+            serde_json::from_value(json!([{"nation": "NATION_ROME"}])).unwrap();
+        let units: Vec<Unit> = serde_json::from_value(
+            json!([{"type": "UNIT_WARRIOR", "player": "0", "x": 1, "y": 0}]),
+        )
+        .unwrap();
+        let mut map_data = MapData::from_game_data(&tiles, &cities, &players);
+        map_data.add_units(&units);
+        let svg = render_svg(&map_data);
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        // Three tiles and Rome's overlay
+        assert_eq!(svg.matches("<polygon points=").count(), 3 + 1 + 1);
+        assert!(svg.contains(r##"fill="#880d56" fill-opacity="0.6""##));
+        assert!(svg.contains(r##"fill="#ffd700""##));
+        assert!(svg.contains(">Roma &amp; Co</text>"));
+        assert!(svg.contains("<title>Warrior</title>"));
+        assert_eq!(svg.matches("<line ").count(), 1);
+
+        // North is up: row 1 is above row 0 and shifted right
+        let layout = Layout::new(&map_data);
+        let (x0, y0) = layout.center(0, 0);
+        let (x1, y1) = layout.center(0, 1);
+        assert!(y1 < y0);
+        assert!((x1 - x0 - hex_width() / 2.0).abs() < 1e-9);
+    }
+}
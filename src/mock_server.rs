@@ -0,0 +1,332 @@
+//! Mock API server backed by a saved snapshot
+//!
+//! Serves every GET route of the game API from a snapshot file, so scripts and
+//! owcli itself can be tested without Old World running. Requests are matched
+//! against the API's own route templates, so names that only owcli resolves
+//! (`/city/Roma`) get a 404 as they would from the game. Commands are accepted
+//! and logged but do not change the snapshot.
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+
+use crate::client::types;
+use crate::commands::query::SNAPSHOT_ENDPOINTS;
+use crate::config::Config;
+use crate::error::{OwcliError, Result};
+use crate::output::{parse_fields, project};
+use crate::snapshot::{Snapshot, SnapshotEndpoint, SnapshotSource};
+
+/// Default page size of paginated routes such as `GET /tiles`, as in the spec
+const DEFAULT_PAGE_LIMIT: usize = 100;
+
+/// Serve `snapshot` on the configured host and port until interrupted
+pub async fn run_mock_server(config: &Config, snapshot: Snapshot) -> Result<()> {
+    let listener = TcpListener::bind((config.host.as_str(), config.port)).await?;
+    eprintln!(
+        "Serving turn {} on {} (Ctrl+C to stop)",
+        snapshot
+            .state()
+            .ok()
+            .and_then(|s| s.turn)
+            .unwrap_or_default(),
+        config.base_url()
+    );
+
+    let snapshot = Arc::new(snapshot);
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let snapshot = Arc::clone(&snapshot);
+        tokio::spawn(async move {
+            let service = service_fn(move |request| handle(Arc::clone(&snapshot), request));
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                eprintln!("Connection error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle(
+    snapshot: Arc<Snapshot>,
+    request: Request<Incoming>,
+) -> std::result::Result<Response<Full<Bytes>>, Infallible> {
+    let method = request.method().clone();
+    let uri = request.uri().clone();
+
+    let (status, body) = match (&method, uri.path()) {
+        (&Method::GET, path) => respond_get(&snapshot, path, uri.query()),
+        (&Method::POST, path @ ("/command" | "/commands")) => {
+            match request.into_body().collect().await {
+                Ok(body) => {
+                    let body = body.to_bytes();
+                    eprintln!("{} {} {}", method, path, String::from_utf8_lossy(&body));
+                    post_command(path, &body)
+                }
+                Err(e) => error(StatusCode::BAD_REQUEST, e.to_string()),
+            }
+        }
+        _ => error(
+            StatusCode::NOT_FOUND,
+            format!("No route for {} {}", method, uri),
+        ),
+    };
+    eprintln!("{} {} -> {}", method, uri, status.as_u16());
+
+    let response = Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Full::new(Bytes::from(body.to_string())))
+        .expect("valid response");
+    Ok(response)
+}
+
+/// Answer a GET request the way the game API would
+pub fn respond_get(snapshot: &Snapshot, path: &str, query: Option<&str>) -> (StatusCode, Value) {
+    let Some((endpoint, params)) = match_route(path) else {
+        return error(StatusCode::NOT_FOUND, format!("No route for GET {}", path));
+    };
+    let body = match snapshot.get(endpoint, &params) {
+        Ok(body) => body,
+        Err(OwcliError::NotFound(message)) => return error(StatusCode::NOT_FOUND, message),
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    };
+
+    let query = query_pairs(query);
+    let fields = query
+        .iter()
+        .find(|(key, _)| key == "fields" && endpoint.accepts_fields)
+        .map(|(_, value)| parse_fields(value));
+    let body = match endpoint.source {
+        SnapshotSource::Paginated { items } => page(body, items, &query, fields.as_deref()),
+        _ => Ok(match fields {
+            Some(fields) => project(&body, &fields),
+            None => body,
+        }),
+    };
+    match body {
+        Ok(body) => (StatusCode::OK, body),
+        Err(message) => error(StatusCode::BAD_REQUEST, message),
+    }
+}
+
+/// The endpoint whose API route template matches `path`, with the path
+/// parameters as given (IDs only; the API doesn't look up names)
+fn match_route(path: &str) -> Option<(&'static SnapshotEndpoint, Vec<String>)> {
+    let segments: Vec<&str> = path.split('/').collect();
+    SNAPSHOT_ENDPOINTS.iter().find_map(|endpoint| {
+        let template: Vec<&str> = endpoint.route.split('/').collect();
+        if template.len() != segments.len() {
+            return None;
+        }
+        let mut params = Vec::new();
+        for (expected, segment) in template.iter().zip(&segments) {
+            match expected.starts_with('{') {
+                true if !segment.is_empty() => params.push(segment.to_string()),
+                false if expected == segment => {}
+                _ => return None,
+            }
+        }
+        Some((endpoint, params))
+    })
+}
+
+/// One page of a paginated route, e.g. `GET /tiles?fields=x,y&offset=0&limit=100`
+fn page(
+    mut body: Value,
+    items: &str,
+    query: &[(String, String)],
+    fields: Option<&[&str]>,
+) -> std::result::Result<Value, String> {
+    let number = |name: &str, value: &str| {
+        value
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid {} '{}'", name, value))
+    };
+
+    let (mut offset, mut limit) = (0, DEFAULT_PAGE_LIMIT);
+    for (key, value) in query {
+        match key.as_str() {
+            "offset" => offset = number("offset", value)?,
+            "limit" => limit = number("limit", value)?,
+            _ => {}
+        }
+    }
+
+    let all = match body[items].take() {
+        Value::Array(all) => all,
+        _ => Vec::new(),
+    };
+    let total = all.len();
+    let end = offset.saturating_add(limit).min(total);
+    let mut page = Value::from(&all[offset.min(total)..end]);
+    if let Some(fields) = fields {
+        page = project(&page, fields);
+    }
+
+    Ok(json!({
+        items: page,
+        "pagination": {
+            "offset": offset,
+            "limit": limit,
+            "total": total,
+            "hasMore": end < total,
+        },
+    }))
+}
+
+/// Accept a command or bulk batch and report success for each command
+fn post_command(path: &str, body: &[u8]) -> (StatusCode, Value) {
+    if path == "/command" {
+        return match serde_json::from_slice::<types::GameCommand>(body) {
+            Ok(command) => (
+                StatusCode::OK,
+                json!({ "success": true, "requestId": command.request_id }),
+            ),
+            Err(e) => error(StatusCode::BAD_REQUEST, e.to_string()),
+        };
+    }
+
+    match serde_json::from_slice::<types::BulkCommand>(body) {
+        Ok(bulk) => {
+            let results: Vec<Value> = bulk
+                .commands
+                .iter()
+                .enumerate()
+                .map(|(index, command)| {
+                    json!({ "index": index, "action": command.action, "success": true })
+                })
+                .collect();
+            let body = json!({
+                "requestId": bulk.request_id,
+                "allSucceeded": true,
+                "results": results,
+                "stoppedAtIndex": null,
+            });
+            (StatusCode::OK, body)
+        }
+        Err(e) => error(StatusCode::BAD_REQUEST, e.to_string()),
+    }
+}
+
+/// Decode a URL query string into key/value pairs
+fn query_pairs(query: Option<&str>) -> Vec<(String, String)> {
+    let url = format!("http://localhost/?{}", query.unwrap_or_default());
+    reqwest::Url::parse(&url)
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default()
+}
+
+/// An `Error` body as defined in the spec
+fn error(status: StatusCode, message: String) -> (StatusCode, Value) {
+    let body = json!({ "error": message, "code": status.as_u16() });
+    (status, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snapshot::fill_route;
+
+    #[test]
+    fn test_respond_get() {
+        let snapshot: Snapshot = serde_json::from_value(json!({
+            "version": 2,
+            "routes": {
+                "/cities": [{"id": 1, "name": "Roma"}],
+                "/tiles": {
+                    "tiles": [{"id": 0, "x": 0, "y": 0}, {"id": 1, "x": 1, "y": 0}],
+                    "pagination": {"offset": 0, "limit": 2, "total": 2, "hasMore": false},
+                },
+                "/turn-summary/cities": [],
+            },
+        }))
+        .unwrap();
+        let get = |path, query| respond_get(&snapshot, path, query);
+
+        assert_eq!(
+            get("/city/1", None),
+            (StatusCode::OK, json!({"id": 1, "name": "Roma"}))
+        );
+        assert_eq!(
+            get("/turn-summary/cities", None),
+            (StatusCode::OK, json!([]))
+        );
+        assert_eq!(
+            get("/tile/1", Some("fields=x,y")),
+            (StatusCode::OK, json!({"x": 1, "y": 0}))
+        );
+
+        let (status, body) = get("/tiles", Some("offset=1&limit=5&fields=id"));
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["tiles"], json!([{"id": 1}]));
+        assert_eq!(body["pagination"]["hasMore"], json!(false));
+
+        // Only routes and IDs the game API itself accepts
+        assert_eq!(get("/city/Roma", None).0, StatusCode::NOT_FOUND);
+        assert_eq!(get("/events/cities", None).0, StatusCode::NOT_FOUND);
+        assert_eq!(get("/tiles", Some("limit=x")).0, StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_every_spec_route() {
+        // One entity that every list holds and every path parameter ("0") names
+        let entity = json!({
+            "id": 0, "index": 0, "x": 0, "y": 0, "tribeType": "0",
+            "characterId": 0, "unitId": 0, "cityId": 0,
+        });
+        let mut routes = serde_json::Map::new();
+        for endpoint in SNAPSHOT_ENDPOINTS {
+            let route = fill_route(endpoint.route, &["0".to_string()]);
+            let body = match endpoint.source {
+                SnapshotSource::Paginated { items } => json!({
+                    items: [entity.clone()],
+                    "pagination": {"offset": 0, "limit": 1, "total": 1, "hasMore": false},
+                }),
+                _ => json!([entity.clone()]),
+            };
+            routes.insert(route, body);
+        }
+        let snapshot: Snapshot =
+            serde_json::from_value(json!({ "version": 2, "routes": routes })).unwrap();
+
+        let spec: serde_yaml::Value = serde_yaml::from_str(crate::validate::SPEC).unwrap();
+        let paths = spec["paths"].as_mapping().unwrap();
+        for (template, _) in paths.iter().filter(|(_, item)| item.get("get").is_some()) {
+            let path: Vec<&str> = template
+                .as_str()
+                .unwrap()
+                .split('/')
+                .map(|segment| match segment.starts_with('{') {
+                    true => "0",
+                    false => segment,
+                })
+                .collect();
+            let path = path.join("/");
+            let (status, body) = respond_get(&snapshot, &path, None);
+            assert_eq!(status, StatusCode::OK, "GET {} -> {}", path, body);
+        }
+    }
+
+    #[test]
+    fn test_query_pairs() {
+        assert_eq!(
+            query_pairs(Some("fields=x%2Cy&limit=10")),
+            vec![
+                ("fields".to_string(), "x,y".to_string()),
+                ("limit".to_string(), "10".to_string()),
+            ]
+        );
+        assert!(query_pairs(None).is_empty());
+    }
+}
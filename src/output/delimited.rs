@@ -0,0 +1,84 @@
+//! CSV and TSV output
+//!
+//! One row per entity. Columns are the schema's fields (or `--fields`); scalar
+//! values are written as-is, while arrays and objects become JSON-encoded cells.
+
+use serde_json::Value;
+
+use super::TypedResponse;
+use crate::error::{OwcliError, Result};
+use crate::filter::lookup_field;
+
+/// Format a response as delimiter-separated values with a header row
+pub fn format_delimited(
+    response: &TypedResponse,
+    fields: Option<&[&str]>,
+    delimiter: u8,
+) -> Result<String> {
+    let value = response.to_value()?;
+    let rows: Vec<&Value> = match &value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+    // Raw JSON has no schema; take the columns from the first entity
+    let keys: Vec<&str> = match rows.first() {
+        Some(Value::Object(first)) => first.keys().map(String::as_str).collect(),
+        _ => Vec::new(),
+    };
+    let columns = match fields {
+        Some(fields) => fields,
+        None if response.columns().is_empty() => &keys,
+        None => response.columns(),
+    };
+
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(Vec::new());
+    writer.write_record(columns)?;
+    for row in rows {
+        writer.write_record(columns.iter().map(|c| cell(lookup_field(row, c))))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| OwcliError::Other(e.to_string()))?;
+    let output = String::from_utf8(bytes).map_err(|e| OwcliError::Other(e.to_string()))?;
+    Ok(output.trim_end().to_string())
+}
+
+/// Render one cell: scalars as plain text, arrays and objects as JSON
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(v) => v.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::types;
+
+    #[test]
+    fn test_format_delimited() {
+        let unit: types::Unit = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "type": "UNIT_WARRIOR",
+            "promotions": ["PROMOTION_FIERCE", "PROMOTION_GUARD"]
+        }))
+        .unwrap();
+        let response = TypedResponse::Units(vec![unit]);
+
+        let csv =
+            format_delimited(&response, Some(&["id", "type", "promotions", "hp"]), b',').unwrap();
+        assert_eq!(
+            csv,
+            "id,type,promotions,hp\n7,UNIT_WARRIOR,\"[\"\"PROMOTION_FIERCE\"\",\"\"PROMOTION_GUARD\"\"]\","
+        );
+
+        let tsv = format_delimited(&response, None, b'\t').unwrap();
+        let header = tsv.lines().next().unwrap();
+        assert_eq!(header.split('\t').count(), response.columns().len());
+    }
+}
@@ -1,129 +1,168 @@
+mod delimited;
+pub mod names;
 pub mod table;
 
 use crate::client::types;
+use crate::config::OutputFormat;
 use crate::error::Result;
+use names::{Names, Needed};
 
-/// Typed response enum for all API endpoints
-#[derive(Debug)]
-pub enum TypedResponse {
-    State(types::GameState),
-    Config(types::GameConfig),
-    Players(Vec<types::Player>),
-    Player(types::Player),
-    PlayerUnits(Vec<types::Unit>),
-    PlayerTechs(types::PlayerTechs),
-    PlayerFamilies(types::PlayerFamilies),
-    PlayerReligion(types::PlayerReligion),
-    PlayerGoals(types::PlayerGoals),
-    PlayerDecisions(types::PlayerDecisions),
-    PlayerLaws(types::PlayerLaws),
-    PlayerMissions(types::PlayerMissions),
-    PlayerResources(types::PlayerResources),
-    Cities(Vec<types::City>),
-    City(types::City),
-    Characters(Vec<types::Character>),
-    Character(types::Character),
-    Units(Vec<types::Unit>),
-    Unit(types::Unit),
-    Map(types::MapMetadata),
-    Tiles(Vec<types::Tile>),
-    Tile(types::Tile),
-    Tribes(Vec<types::Tribe>),
-    Tribe(types::Tribe),
-    Religions(Vec<types::Religion>),
-    TeamDiplomacy(Vec<types::TeamDiplomacy>),
-    TeamAlliances(Vec<types::TeamAlliance>),
-    TribeDiplomacy(Vec<types::TribeDiplomacy>),
-    TribeAlliances(Vec<types::TribeAlliance>),
-    CharacterEvents(Vec<types::CharacterEvent>),
-    UnitEvents(Vec<types::UnitEvent>),
-    CityEvents(Vec<types::CityEvent>),
+// TypedResponse enum and its to_json()/to_table() are generated from openapi.yaml
+// Regenerate with: cargo run --bin gen_queries -- responses > src/output/response_generated.rs
+include!("response_generated.rs");
+
+/// An in-place operation over the items of a list response (filter, sort, paging)
+pub trait ListOp {
+    fn apply<T: serde::Serialize>(&self, items: &mut Vec<T>);
 }
 
-impl TypedResponse {
-    /// Convert to JSON string
-    pub fn to_json(&self) -> Result<String> {
-        let json = match self {
-            Self::State(v) => serde_json::to_string_pretty(v)?,
-            Self::Config(v) => serde_json::to_string_pretty(v)?,
-            Self::Players(v) => serde_json::to_string_pretty(v)?,
-            Self::Player(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerUnits(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerTechs(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerFamilies(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerReligion(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerGoals(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerDecisions(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerLaws(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerMissions(v) => serde_json::to_string_pretty(v)?,
-            Self::PlayerResources(v) => serde_json::to_string_pretty(v)?,
-            Self::Cities(v) => serde_json::to_string_pretty(v)?,
-            Self::City(v) => serde_json::to_string_pretty(v)?,
-            Self::Characters(v) => serde_json::to_string_pretty(v)?,
-            Self::Character(v) => serde_json::to_string_pretty(v)?,
-            Self::Units(v) => serde_json::to_string_pretty(v)?,
-            Self::Unit(v) => serde_json::to_string_pretty(v)?,
-            Self::Map(v) => serde_json::to_string_pretty(v)?,
-            Self::Tiles(v) => serde_json::to_string_pretty(v)?,
-            Self::Tile(v) => serde_json::to_string_pretty(v)?,
-            Self::Tribes(v) => serde_json::to_string_pretty(v)?,
-            Self::Tribe(v) => serde_json::to_string_pretty(v)?,
-            Self::Religions(v) => serde_json::to_string_pretty(v)?,
-            Self::TeamDiplomacy(v) => serde_json::to_string_pretty(v)?,
-            Self::TeamAlliances(v) => serde_json::to_string_pretty(v)?,
-            Self::TribeDiplomacy(v) => serde_json::to_string_pretty(v)?,
-            Self::TribeAlliances(v) => serde_json::to_string_pretty(v)?,
-            Self::CharacterEvents(v) => serde_json::to_string_pretty(v)?,
-            Self::UnitEvents(v) => serde_json::to_string_pretty(v)?,
-            Self::CityEvents(v) => serde_json::to_string_pretty(v)?,
-        };
-        Ok(json)
+/// Format typed response in the chosen output format, keeping only `fields` if
+/// given. Tables render IDs with `names`.
+pub fn format_typed_output(
+    response: &TypedResponse,
+    format: OutputFormat,
+    fields: Option<&[&str]>,
+    names: &Names,
+) -> Result<String> {
+    match (format, fields) {
+        (OutputFormat::Table, None) => Ok(response.to_table(names)),
+        (OutputFormat::Table, Some(fields)) => Ok(response.to_table_fields(fields, names)),
+        (OutputFormat::Json, None) => response.to_json(),
+        (OutputFormat::Json, Some(fields)) => Ok(serde_json::to_string_pretty(&project(
+            &response.to_value()?,
+            fields,
+        ))?),
+        (OutputFormat::Yaml, None) => Ok(to_yaml(&response.to_value()?)?),
+        (OutputFormat::Yaml, Some(fields)) => Ok(to_yaml(&project(&response.to_value()?, fields))?),
+        (OutputFormat::Csv, _) => delimited::format_delimited(response, fields, b','),
+        (OutputFormat::Tsv, _) => delimited::format_delimited(response, fields, b'\t'),
+        (OutputFormat::Ndjson, _) => {
+            let mut buf = Vec::new();
+            response.write_ndjson(&mut buf, fields)?;
+            Ok(String::from_utf8_lossy(&buf).trim_end().to_string())
+        }
     }
+}
+
+/// Write a typed response to `out`. NDJSON is written entity by entity rather
+/// than built up as one string, so large lists like `tiles` stream out.
+pub fn write_typed_output(
+    out: &mut impl std::io::Write,
+    response: &TypedResponse,
+    format: OutputFormat,
+    fields: Option<&[&str]>,
+    names: &Names,
+) -> Result<()> {
+    match format {
+        OutputFormat::Ndjson => response.write_ndjson(out, fields)?,
+        _ => writeln!(
+            out,
+            "{}",
+            format_typed_output(response, format, fields, names)?
+        )?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn to_yaml(value: &serde_json::Value) -> Result<String> {
+    Ok(serde_yaml::to_string(value)?.trim_end().to_string())
+}
 
-    /// Format as table
-    pub fn to_table(&self) -> String {
-        match self {
-            Self::State(v) => table::format_state(v),
-            Self::Config(v) => table::format_config(v),
-            Self::Players(v) => table::format_players(v),
-            Self::Player(v) => table::format_player(v),
-            Self::PlayerUnits(v) | Self::Units(v) => table::format_units(v),
-            Self::PlayerTechs(v) => table::format_player_techs(v),
-            Self::PlayerFamilies(v) => table::format_player_families(v),
-            Self::PlayerReligion(v) => table::format_player_religion(v),
-            Self::PlayerGoals(v) => table::format_player_goals(v),
-            Self::PlayerDecisions(v) => table::format_player_decisions(v),
-            Self::PlayerLaws(v) => table::format_player_laws(v),
-            Self::PlayerMissions(v) => table::format_player_missions(v),
-            Self::PlayerResources(v) => table::format_player_resources(v),
-            Self::Cities(v) => table::format_cities(v),
-            Self::City(v) => table::format_city(v),
-            Self::Characters(v) => table::format_characters(v),
-            Self::Character(v) => table::format_character(v),
-            Self::Unit(v) => table::format_unit(v),
-            Self::Map(v) => table::format_map(v),
-            Self::Tiles(v) => table::format_tiles(v),
-            Self::Tile(v) => table::format_tile(v),
-            Self::Tribes(v) => table::format_tribes(v),
-            Self::Tribe(v) => table::format_tribe(v),
-            Self::Religions(v) => table::format_religions(v),
-            Self::TeamDiplomacy(v) => table::format_team_diplomacy(v),
-            Self::TeamAlliances(v) => table::format_team_alliances(v),
-            Self::TribeDiplomacy(v) => table::format_tribe_diplomacy(v),
-            Self::TribeAlliances(v) => table::format_tribe_alliances(v),
-            Self::CharacterEvents(v) => table::format_character_events(v),
-            Self::UnitEvents(v) => table::format_unit_events(v),
-            Self::CityEvents(v) => table::format_city_events(v),
+/// Write several responses labeled by their query path. JSON and YAML become one
+/// object keyed by path; NDJSON lines are written back to back; other formats
+/// print a titled section per path.
+pub fn write_labeled_output(
+    out: &mut impl std::io::Write,
+    results: &[(String, TypedResponse)],
+    format: OutputFormat,
+    fields: Option<&[&str]>,
+    names: &Names,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let mut object = serde_json::Map::new();
+            for (path, response) in results {
+                let value = response.to_value()?;
+                let value = match fields {
+                    Some(fields) => project(&value, fields),
+                    None => value,
+                };
+                object.insert(path.clone(), value);
+            }
+            let object = serde_json::Value::Object(object);
+            if format == OutputFormat::Json {
+                writeln!(out, "{}", serde_json::to_string_pretty(&object)?)?;
+            } else {
+                writeln!(out, "{}", to_yaml(&object)?)?;
+            }
+        }
+        OutputFormat::Ndjson => {
+            for (_, response) in results {
+                response.write_ndjson(out, fields)?;
+            }
+        }
+        _ => {
+            for (i, (path, response)) in results.iter().enumerate() {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "=== {} ===", path)?;
+                writeln!(
+                    out,
+                    "{}",
+                    format_typed_output(response, format, fields, names)?
+                )?;
+            }
         }
     }
+    out.flush()?;
+    Ok(())
 }
 
-/// Format typed response based on output mode
-pub fn format_typed_output(response: &TypedResponse, json_mode: bool) -> Result<String> {
-    if json_mode {
-        response.to_json()
-    } else {
-        Ok(response.to_table())
+/// Write each item as a compact JSON line, keeping only `fields` if given
+fn write_lines<T: serde::Serialize>(
+    out: &mut impl std::io::Write,
+    items: &[T],
+    fields: Option<&[&str]>,
+) -> Result<()> {
+    for item in items {
+        match fields {
+            Some(fields) => {
+                serde_json::to_writer(&mut *out, &project(&serde_json::to_value(item)?, fields))?
+            }
+            None => serde_json::to_writer(&mut *out, item)?,
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Split a `--fields` argument into field names
+pub fn parse_fields(fields: &str) -> Vec<&str> {
+    fields
+        .split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .collect()
+}
+
+/// Keep only the selected fields of an entity, or of each entity in a list.
+/// Dotted fields (e.g., "location.x") are looked up in nested objects.
+pub(crate) fn project(value: &serde_json::Value, fields: &[&str]) -> serde_json::Value {
+    match value {
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(|v| project(v, fields)).collect())
+        }
+        serde_json::Value::Object(_) => serde_json::Value::Object(
+            fields
+                .iter()
+                .filter_map(|f| {
+                    crate::filter::lookup_field(value, f).map(|v| (f.to_string(), v.clone()))
+                })
+                .collect(),
+        ),
+        other => other.clone(),
     }
 }
 
@@ -194,3 +233,41 @@ pub fn format_bulk_response(response: &types::BulkCommandResult, json_mode: bool
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_project() {
+        let fields = parse_fields("id, name,location.x,missing");
+        assert_eq!(fields, vec!["id", "name", "location.x", "missing"]);
+
+        let value = json!([
+            {"id": 1, "name": "Roma", "population": 7, "location": {"x": 3, "y": 4}},
+            {"id": 2, "name": "Ostia"}
+        ]);
+        assert_eq!(
+            project(&value, &fields),
+            json!([
+                {"id": 1, "name": "Roma", "location.x": 3},
+                {"id": 2, "name": "Ostia"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_ndjson() {
+        let items = vec![
+            json!({"id": 1, "name": "Roma"}),
+            json!({"id": 2, "name": "Ostia"}),
+        ];
+        let mut out = Vec::new();
+        write_lines(&mut out, &items, Some(&["name"])).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"name\":\"Roma\"}\n{\"name\":\"Ostia\"}\n"
+        );
+    }
+}
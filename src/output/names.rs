@@ -0,0 +1,230 @@
+//! ID-to-name resolution for table output
+//!
+//! The generated `*_id_kinds()` functions tell from the response type which
+//! kinds of IDs a table shows. Only those entities are fetched, and the
+//! resulting `Names` are passed to the generated formatters, which render ID
+//! fields as `17 (Roma)` or `0 (Rome)`.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use super::table::shorten_type;
+use crate::client::{fetch, types, ApiClient};
+
+/// Kind of entity an ID field refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Kind {
+    Player,
+    City,
+    Character,
+}
+
+/// Kinds of IDs some output shows, and so the names worth fetching for it
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Needed {
+    pub players: bool,
+    pub cities: bool,
+    pub characters: bool,
+}
+
+impl Needed {
+    pub const NONE: Self = Self {
+        players: false,
+        cities: false,
+        characters: false,
+    };
+
+    /// Kinds of the `id_fields` a table shows: all of them, or those among `fields`
+    pub(super) fn of_fields(id_fields: &[(&str, Kind)], fields: Option<&[&str]>) -> Self {
+        let mut needed = Self::NONE;
+        for (field, kind) in id_fields {
+            if fields.is_none_or(|fields| fields.contains(field)) {
+                needed.add(*kind);
+            }
+        }
+        needed
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            players: self.players || other.players,
+            cities: self.cities || other.cities,
+            characters: self.characters || other.characters,
+        }
+    }
+
+    pub fn any(self) -> bool {
+        self.players || self.cities || self.characters
+    }
+
+    fn add(&mut self, kind: Kind) {
+        match kind {
+            Kind::Player => self.players = true,
+            Kind::City => self.cities = true,
+            Kind::Character => self.characters = true,
+        }
+    }
+}
+
+/// Display names for player indexes, city IDs and character IDs
+#[derive(Debug, Default, Clone)]
+pub struct Names {
+    players: HashMap<i64, String>,
+    cities: HashMap<i64, String>,
+    characters: HashMap<i64, String>,
+}
+
+impl Names {
+    /// Fetch the `needed` entities concurrently. A failed request leaves its
+    /// IDs unresolved rather than failing the query.
+    pub async fn fetch(client: &ApiClient, needed: Needed) -> Self {
+        if let Some(snapshot) = &client.snapshot {
+            return Self::from_entities(
+                &load_if(needed.players, || snapshot.players()),
+                &load_if(needed.cities, || snapshot.cities()),
+                &load_if(needed.characters, || snapshot.characters()),
+            );
+        }
+
+        let (players, cities, characters) = tokio::join!(
+            fetch_if(needed.players, || fetch(client, || client
+                .inner
+                .get_players())),
+            fetch_if(needed.cities, || fetch(client, || client
+                .inner
+                .get_cities())),
+            fetch_if(needed.characters, || fetch(client, || client
+                .inner
+                .get_characters())),
+        );
+        Self::from_entities(&players, &cities, &characters)
+    }
+
+    pub fn from_entities(
+        players: &[types::Player],
+        cities: &[types::City],
+        characters: &[types::Character],
+    ) -> Self {
+        let players = players
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| Some((player_index(p, i), player_name(p)?)))
+            .collect();
+
+        let cities = cities
+            .iter()
+            .filter_map(|c| Some((c.id? as i64, c.name.clone()?)))
+            .collect();
+
+        let characters = characters
+            .iter()
+            .filter_map(|c| Some((c.id? as i64, character_name(c)?)))
+            .collect();
+
+        Self {
+            players,
+            cities,
+            characters,
+        }
+    }
+
+    /// Render an ID with its name, or as-is if unknown
+    pub(super) fn describe(&self, id: impl Display, kind: Kind) -> String {
+        let id = id.to_string();
+        let name = id
+            .parse::<i64>()
+            .ok()
+            .and_then(|key| self.get(kind).get(&key));
+        match name {
+            Some(name) => format!("{} ({})", id, name),
+            None => id,
+        }
+    }
+
+    fn get(&self, kind: Kind) -> &HashMap<i64, String> {
+        match kind {
+            Kind::Player => &self.players,
+            Kind::City => &self.cities,
+            Kind::Character => &self.characters,
+        }
+    }
+}
+
+/// Run `request` only if `needed`; a skipped or failed request yields no entities
+async fn fetch_if<T, F, Fut>(needed: bool, request: F) -> Vec<T>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = crate::error::Result<Vec<T>>>,
+{
+    if needed {
+        request().await.unwrap_or_default()
+    } else {
+        Vec::new()
+    }
+}
+
+fn load_if<T>(needed: bool, load: impl FnOnce() -> crate::error::Result<Vec<T>>) -> Vec<T> {
+    if needed {
+        load().unwrap_or_default()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Index of a player; players are returned in index order, so fall back to position
+pub fn player_index(player: &types::Player, position: usize) -> i64 {
+    player.index.unwrap_or(position as i64)
+}
+
+/// Display name of a player (its nation, e.g., "Rome")
+pub fn player_name(player: &types::Player) -> Option<String> {
+    player.nation.as_deref().map(shorten_type)
+}
+
+/// Display name of a character (custom name, else first name)
+pub fn character_name(character: &types::Character) -> Option<String> {
+    let name = character
+        .custom_name
+        .as_deref()
+        .or(character.first_name.as_deref())?;
+    Some(if name.starts_with("NAME_") {
+        shorten_type(name)
+    } else {
+        name.to_string()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let player_list: Vec<types::Player> =
+            serde_json::from_value(serde_json::json!([{"index": 0, "nation": "NATION_ROME"}]))
+                .unwrap();
+        let city_list: Vec<types::City> =
+            serde_json::from_value(serde_json::json!([{"id": 17, "name": "Roma"}])).unwrap();
+        let names = Names::from_entities(&player_list, &city_list, &[]);
+
+        assert_eq!(names.describe(17, Kind::City), "17 (Roma)");
+        assert_eq!(names.describe("0", Kind::Player), "0 (Rome)");
+        assert_eq!(names.describe("NONE", Kind::Player), "NONE");
+        assert_eq!(names.describe(342, Kind::Character), "342");
+        assert_eq!(Names::default().describe(17, Kind::City), "17");
+    }
+
+    #[test]
+    fn test_needed() {
+        let id_fields = [("cityId", Kind::City), ("playerId", Kind::Player)];
+        assert_eq!(
+            Needed::of_fields(&id_fields, Some(&["cityId", "name"])),
+            Needed {
+                cities: true,
+                ..Needed::NONE
+            }
+        );
+        assert!(Needed::of_fields(&id_fields, None).players);
+        assert!(!Needed::of_fields(&id_fields, Some(&["name"])).any());
+    }
+}
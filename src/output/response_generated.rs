@@ -0,0 +1,427 @@
+// This file is auto-generated by `cargo run --bin gen_queries -- responses`
+// Do not edit manually. Regenerate with:
+//   cargo run --bin gen_queries -- responses > src/output/response_generated.rs
+//
+// Included via include!() in output/mod.rs
+
+/// Typed response enum for all API endpoints
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum TypedResponse {
+    State(types::GameState),
+    Players(Vec<types::Player>),
+    Player(types::Player),
+    PlayerUnits(Vec<types::Unit>),
+    PlayerTechs(types::PlayerTechs),
+    PlayerFamilies(types::PlayerFamilies),
+    PlayerReligion(types::PlayerReligion),
+    PlayerGoals(types::PlayerGoals),
+    PlayerDecisions(types::PlayerDecisions),
+    PlayerLaws(types::PlayerLaws),
+    PlayerMissions(types::PlayerMissions),
+    PlayerResources(types::PlayerResources),
+    Cities(Vec<types::City>),
+    City(types::City),
+    Characters(Vec<types::Character>),
+    Character(types::Character),
+    Units(Vec<types::Unit>),
+    Unit(types::Unit),
+    Tiles(Vec<types::Tile>),
+    Tile(types::Tile),
+    TileByCoords(types::Tile),
+    TurnSummary(types::TurnSummary),
+    TurnSummaryCharacters(Vec<types::CharacterEvent>),
+    TurnSummaryCharacter(Vec<types::CharacterEvent>),
+    TurnSummaryUnits(Vec<types::UnitEvent>),
+    TurnSummaryUnit(Vec<types::UnitEvent>),
+    TurnSummaryCities(Vec<types::CityEvent>),
+    TurnSummaryCity(Vec<types::CityEvent>),
+    TurnSummaryWonders(Vec<types::WonderEvent>),
+    Tribes(Vec<types::Tribe>),
+    Tribe(types::Tribe),
+    DiplomacyTeams(Vec<types::TeamDiplomacy>),
+    AlliancesTeams(Vec<types::TeamAlliance>),
+    DiplomacyTribes(Vec<types::TribeDiplomacy>),
+    AlliancesTribes(Vec<types::TribeAlliance>),
+    Religions(Vec<types::Religion>),
+    Config(types::GameConfig),
+    Map(types::MapMetadata),
+    /// Undecoded JSON, from --raw or a response that doesn't match the spec
+    Raw(serde_json::Value),
+}
+
+impl TypedResponse {
+    /// Convert to JSON string
+    pub fn to_json(&self) -> Result<String> {
+        let json = match self {
+            Self::State(v) => serde_json::to_string_pretty(v)?,
+            Self::Players(v) => serde_json::to_string_pretty(v)?,
+            Self::Player(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerUnits(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerTechs(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerFamilies(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerReligion(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerGoals(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerDecisions(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerLaws(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerMissions(v) => serde_json::to_string_pretty(v)?,
+            Self::PlayerResources(v) => serde_json::to_string_pretty(v)?,
+            Self::Cities(v) => serde_json::to_string_pretty(v)?,
+            Self::City(v) => serde_json::to_string_pretty(v)?,
+            Self::Characters(v) => serde_json::to_string_pretty(v)?,
+            Self::Character(v) => serde_json::to_string_pretty(v)?,
+            Self::Units(v) => serde_json::to_string_pretty(v)?,
+            Self::Unit(v) => serde_json::to_string_pretty(v)?,
+            Self::Tiles(v) => serde_json::to_string_pretty(v)?,
+            Self::Tile(v) => serde_json::to_string_pretty(v)?,
+            Self::TileByCoords(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummary(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryCharacters(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryCharacter(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryUnits(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryUnit(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryCities(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryCity(v) => serde_json::to_string_pretty(v)?,
+            Self::TurnSummaryWonders(v) => serde_json::to_string_pretty(v)?,
+            Self::Tribes(v) => serde_json::to_string_pretty(v)?,
+            Self::Tribe(v) => serde_json::to_string_pretty(v)?,
+            Self::DiplomacyTeams(v) => serde_json::to_string_pretty(v)?,
+            Self::AlliancesTeams(v) => serde_json::to_string_pretty(v)?,
+            Self::DiplomacyTribes(v) => serde_json::to_string_pretty(v)?,
+            Self::AlliancesTribes(v) => serde_json::to_string_pretty(v)?,
+            Self::Religions(v) => serde_json::to_string_pretty(v)?,
+            Self::Config(v) => serde_json::to_string_pretty(v)?,
+            Self::Map(v) => serde_json::to_string_pretty(v)?,
+            Self::Raw(v) => serde_json::to_string_pretty(v)?,
+        };
+        Ok(json)
+    }
+
+    /// Format as table, rendering IDs with `names`
+    pub fn to_table(&self, names: &Names) -> String {
+        match self {
+            Self::State(v) => table::format_state(v, names),
+            Self::Players(v) => table::format_players(v, names),
+            Self::Player(v) => table::format_player(v, names),
+            Self::PlayerUnits(v) => table::format_units(v, names),
+            Self::PlayerTechs(v) => table::format_player_techs(v, names),
+            Self::PlayerFamilies(v) => table::format_player_families(v, names),
+            Self::PlayerReligion(v) => table::format_player_religion(v, names),
+            Self::PlayerGoals(v) => table::format_player_goals(v, names),
+            Self::PlayerDecisions(v) => table::format_player_decisions(v, names),
+            Self::PlayerLaws(v) => table::format_player_laws(v, names),
+            Self::PlayerMissions(v) => table::format_player_missions(v, names),
+            Self::PlayerResources(v) => table::format_player_resources(v, names),
+            Self::Cities(v) => table::format_cities(v, names),
+            Self::City(v) => table::format_city(v, names),
+            Self::Characters(v) => table::format_characters(v, names),
+            Self::Character(v) => table::format_character(v, names),
+            Self::Units(v) => table::format_units(v, names),
+            Self::Unit(v) => table::format_unit(v, names),
+            Self::Tiles(v) => table::format_tiles(v, names),
+            Self::Tile(v) => table::format_tile(v, names),
+            Self::TileByCoords(v) => table::format_tile(v, names),
+            Self::TurnSummary(v) => table::format_turn_summary(v, names),
+            Self::TurnSummaryCharacters(v) => table::format_character_events(v, names),
+            Self::TurnSummaryCharacter(v) => table::format_character_events(v, names),
+            Self::TurnSummaryUnits(v) => table::format_unit_events(v, names),
+            Self::TurnSummaryUnit(v) => table::format_unit_events(v, names),
+            Self::TurnSummaryCities(v) => table::format_city_events(v, names),
+            Self::TurnSummaryCity(v) => table::format_city_events(v, names),
+            Self::TurnSummaryWonders(v) => table::format_wonder_events(v, names),
+            Self::Tribes(v) => table::format_tribes(v, names),
+            Self::Tribe(v) => table::format_tribe(v, names),
+            Self::DiplomacyTeams(v) => table::format_team_diplomacy(v, names),
+            Self::AlliancesTeams(v) => table::format_team_alliances(v, names),
+            Self::DiplomacyTribes(v) => table::format_tribe_diplomacy(v, names),
+            Self::AlliancesTribes(v) => table::format_tribe_alliances(v, names),
+            Self::Religions(v) => table::format_religions(v, names),
+            Self::Config(v) => table::format_config(v, names),
+            Self::Map(v) => table::format_map(v, names),
+            Self::Raw(v) => serde_json::to_string_pretty(v).unwrap_or_default(),
+        }
+    }
+
+    /// Format as table showing only the selected fields
+    pub fn to_table_fields(&self, fields: &[&str], names: &Names) -> String {
+        match self {
+            Self::State(v) => table::format_state_fields(v, fields, names),
+            Self::Players(v) => table::format_players_columns(v, fields, names),
+            Self::Player(v) => table::format_player_fields(v, fields, names),
+            Self::PlayerUnits(v) => table::format_units_columns(v, fields, names),
+            Self::PlayerTechs(v) => table::format_player_techs_fields(v, fields, names),
+            Self::PlayerFamilies(v) => table::format_player_families_fields(v, fields, names),
+            Self::PlayerReligion(v) => table::format_player_religion_fields(v, fields, names),
+            Self::PlayerGoals(v) => table::format_player_goals_fields(v, fields, names),
+            Self::PlayerDecisions(v) => table::format_player_decisions_fields(v, fields, names),
+            Self::PlayerLaws(v) => table::format_player_laws_fields(v, fields, names),
+            Self::PlayerMissions(v) => table::format_player_missions_fields(v, fields, names),
+            Self::PlayerResources(v) => table::format_player_resources_fields(v, fields, names),
+            Self::Cities(v) => table::format_cities_columns(v, fields, names),
+            Self::City(v) => table::format_city_fields(v, fields, names),
+            Self::Characters(v) => table::format_characters_columns(v, fields, names),
+            Self::Character(v) => table::format_character_fields(v, fields, names),
+            Self::Units(v) => table::format_units_columns(v, fields, names),
+            Self::Unit(v) => table::format_unit_fields(v, fields, names),
+            Self::Tiles(v) => table::format_tiles_columns(v, fields, names),
+            Self::Tile(v) => table::format_tile_fields(v, fields, names),
+            Self::TileByCoords(v) => table::format_tile_fields(v, fields, names),
+            Self::TurnSummary(v) => table::format_turn_summary_fields(v, fields, names),
+            Self::TurnSummaryCharacters(v) => table::format_character_events_columns(v, fields, names),
+            Self::TurnSummaryCharacter(v) => table::format_character_events_columns(v, fields, names),
+            Self::TurnSummaryUnits(v) => table::format_unit_events_columns(v, fields, names),
+            Self::TurnSummaryUnit(v) => table::format_unit_events_columns(v, fields, names),
+            Self::TurnSummaryCities(v) => table::format_city_events_columns(v, fields, names),
+            Self::TurnSummaryCity(v) => table::format_city_events_columns(v, fields, names),
+            Self::TurnSummaryWonders(v) => table::format_wonder_events_columns(v, fields, names),
+            Self::Tribes(v) => table::format_tribes_columns(v, fields, names),
+            Self::Tribe(v) => table::format_tribe_fields(v, fields, names),
+            Self::DiplomacyTeams(v) => table::format_team_diplomacy_columns(v, fields, names),
+            Self::AlliancesTeams(v) => table::format_team_alliances_columns(v, fields, names),
+            Self::DiplomacyTribes(v) => table::format_tribe_diplomacy_columns(v, fields, names),
+            Self::AlliancesTribes(v) => table::format_tribe_alliances_columns(v, fields, names),
+            Self::Religions(v) => table::format_religions_columns(v, fields, names),
+            Self::Config(v) => table::format_config_fields(v, fields, names),
+            Self::Map(v) => table::format_map_fields(v, fields, names),
+            Self::Raw(v) => serde_json::to_string_pretty(&project(v, fields)).unwrap_or_default(),
+        }
+    }
+
+    /// Kinds of IDs the table shows, of all fields or only the selected ones
+    pub fn id_kinds(&self, fields: Option<&[&str]>) -> Needed {
+        match self {
+            Self::State(_) => table::game_state_id_kinds(fields),
+            Self::Players(_) => table::player_id_kinds(fields),
+            Self::Player(_) => table::player_id_kinds(fields),
+            Self::PlayerUnits(_) => table::unit_id_kinds(fields),
+            Self::PlayerTechs(_) => table::player_techs_id_kinds(fields),
+            Self::PlayerFamilies(_) => table::player_families_id_kinds(fields),
+            Self::PlayerReligion(_) => table::player_religion_id_kinds(fields),
+            Self::PlayerGoals(_) => table::player_goals_id_kinds(fields),
+            Self::PlayerDecisions(_) => table::player_decisions_id_kinds(fields),
+            Self::PlayerLaws(_) => table::player_laws_id_kinds(fields),
+            Self::PlayerMissions(_) => table::player_missions_id_kinds(fields),
+            Self::PlayerResources(_) => table::player_resources_id_kinds(fields),
+            Self::Cities(_) => table::city_id_kinds(fields),
+            Self::City(_) => table::city_id_kinds(fields),
+            Self::Characters(_) => table::character_id_kinds(fields),
+            Self::Character(_) => table::character_id_kinds(fields),
+            Self::Units(_) => table::unit_id_kinds(fields),
+            Self::Unit(_) => table::unit_id_kinds(fields),
+            Self::Tiles(_) => table::tile_id_kinds(fields),
+            Self::Tile(_) => table::tile_id_kinds(fields),
+            Self::TileByCoords(_) => table::tile_id_kinds(fields),
+            Self::TurnSummary(_) => table::turn_summary_id_kinds(fields),
+            Self::TurnSummaryCharacters(_) => table::character_event_id_kinds(fields),
+            Self::TurnSummaryCharacter(_) => table::character_event_id_kinds(fields),
+            Self::TurnSummaryUnits(_) => table::unit_event_id_kinds(fields),
+            Self::TurnSummaryUnit(_) => table::unit_event_id_kinds(fields),
+            Self::TurnSummaryCities(_) => table::city_event_id_kinds(fields),
+            Self::TurnSummaryCity(_) => table::city_event_id_kinds(fields),
+            Self::TurnSummaryWonders(_) => table::wonder_event_id_kinds(fields),
+            Self::Tribes(_) => table::tribe_id_kinds(fields),
+            Self::Tribe(_) => table::tribe_id_kinds(fields),
+            Self::DiplomacyTeams(_) => table::team_diplomacy_id_kinds(fields),
+            Self::AlliancesTeams(_) => table::team_alliance_id_kinds(fields),
+            Self::DiplomacyTribes(_) => table::tribe_diplomacy_id_kinds(fields),
+            Self::AlliancesTribes(_) => table::tribe_alliance_id_kinds(fields),
+            Self::Religions(_) => table::religion_id_kinds(fields),
+            Self::Config(_) => table::game_config_id_kinds(fields),
+            Self::Map(_) => table::map_metadata_id_kinds(fields),
+            Self::Raw(_) => Needed::NONE,
+        }
+    }
+
+    /// Field names of the entity schema, used as columns for delimited output
+    pub fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::State(_) => &["turn", "year", "currentPlayer", "players", "characters", "cities", "units", "tribes", "teamDiplomacy", "teamAlliances", "tribeDiplomacy", "tribeAlliances"],
+            Self::Players(_) => &["aiAutoPlayToTurn", "borderVisibility", "buyTileCount", "capitalCityID", "chosenHeirID", "cities", "consumptionModifier", "convertOrdersCost", "convertOrdersYield", "crest", "customName", "customReminder", "development", "difficulty", "difficultyMode", "dynasty", "families", "familySupremacy", "firstPopup", "fixedNumCities", "founderID", "harvestModifier", "hasAchievementLocked", "hasAchievementUnlocked", "hasAutomatedUnits", "hasCapitalCity", "hasChosenHeir", "hasCustomName", "hasDecisions", "hasDynasty", "hasFounder", "hasHeir", "hasLeader", "hasLeaderFamily", "hasLeaderSpouseAlive", "hasNation", "hasScenarioCompleted", "hasStateReligion", "hasTurnSummary", "ignoreReminders", "isActive", "isAIAutoPlay", "isAlive", "isAnyDirty", "isAnyPickLater", "isAnyTechAvailable", "isCityMaxReached", "isCompletedGameSaved", "isConvertedLegitimacy", "isDead", "isFirstTurnProcessing", "isFounded", "isHuman", "isHumanOriginal", "isInitialized", "isLeaderAlive", "isPlaying", "isProcessingTurn", "isProcessingTurnStart", "isResearching", "isStartCityNumberFlexible", "isSurrendered", "isTeamHuman", "isTechRedraw", "isTurnEnded", "isTurnSummaryReady", "isTutorial", "isUsedOffensive", "isVersionMismatch", "language", "lastDoTurn", "leaderFamily", "legitimacy", "minStartingCities", "missionModifier", "money", "moneyWhole", "nation", "numCities", "numDecisions", "numEmptySites", "numFamilies", "numGameLoads", "numGoals", "numMemories", "numMissions", "numPopups", "numStartingTiles", "numTiles", "numTrades", "numTributes", "numUnits", "onlineID", "ordersLeft", "originalCapitalCityID", "pingTiles", "player", "playerEmail", "playerInt", "popupTechDiscovered", "recruitLegitimacy", "reloads", "saltWaterTileCount", "sellPenaltyModifier", "startingTileID", "startTurnCities", "stateReligion", "stateReligionChangeCount", "stateReligionSpreadChange", "succession", "successionCount", "successionGender", "successionOrder", "team", "techCostModifier", "techResearching", "techsAvailableChange", "timeStockpile", "trainingOrderModifier", "tribeFatigueLimitChange", "tribeMercenaryCount", "turnSummary", "turnTimeNeeded", "visionChange", "wonderModifier", "worldReligionCount", "worldReligionSpreadChange", "yieldUpkeepModifier", "activeImprovementClassCounts", "activeImprovementCounts", "adoptReligionCosts", "ambitionDecisionss", "councilCharacters", "effectCityRebelProbs", "effectPlayerCounts", "extraLuxuryCounts", "familyControls", "familyHeadIDs", "familyOpinionRates", "familySeatCityIDs", "familyTurnsNoLeaders", "giftYieldQuantitys", "goalStartedCounts", "hasActiveCharacters", "hasCouncilCharacters", "hasFamilyHeads", "hasFamilyOpinions", "hasFamilySeatCitys", "hasHeightClaimeds", "hasReligions", "hasReligionOpinionHistorys", "hasTerrainClaimeds", "hasTraitCharacters", "hasVegetationClaimeds", "improvementClassCounts", "improvementCounts", "improvementLawsRequireds", "isActiveLaws", "isBuyTileUnlocks", "isCityYieldBuildCurrents", "isConnectedForeignUnlocks", "isCouncilUnlocks", "isFamilyReligions", "isFamilyStarteds", "isHideUnitUnlocks", "isImprovementNotValids", "isImprovementUnlockeds", "isLatestUpgrades", "isMinTreatyTurnss", "isNationImprovements", "isNoFamilyRestrictionJobUnlocks", "isNoSellPenaltyYieldUnlocks", "isProjectNotValids", "isSpreadBordersUnlocks", "isTechAcquireds", "isTechAnyDecks", "isTechAvailables", "isTechHides", "isTechLockeds", "isTechNotValids", "isTechPasseds", "isTechPassedCurrents", "isTechTargets", "isTechTrasheds", "isUnitNotValids", "isUnitObsoletes", "isUnitUnlockeds", "isWaterUnitUnlocks", "isWorldOrOurPaganReligions", "jobOpinionRates", "livingCourtiersYields", "livingRoyalsYields", "luxuryCounts", "missionCooldownTurnsLefts", "missionStartedTurns", "projectsProduceds", "questsFaileds", "religionCounts", "religionOpinionRates", "resourceRevealeds", "startLawCosts", "techCosts", "techCostWholes", "techProgresss", "techTurnDiscovereds", "theologyEstablishedCounts", "tradeOutpostYieldTotals", "unitsProduceds", "unitsProducedTurns", "unitTraitConsumptionModifiers", "yieldStockpiles", "yieldStockpileWholes", "yieldTotals", "yieldUpkeepNets", "index", "rates", "stockpiles"],
+            Self::Player(_) => &["aiAutoPlayToTurn", "borderVisibility", "buyTileCount", "capitalCityID", "chosenHeirID", "cities", "consumptionModifier", "convertOrdersCost", "convertOrdersYield", "crest", "customName", "customReminder", "development", "difficulty", "difficultyMode", "dynasty", "families", "familySupremacy", "firstPopup", "fixedNumCities", "founderID", "harvestModifier", "hasAchievementLocked", "hasAchievementUnlocked", "hasAutomatedUnits", "hasCapitalCity", "hasChosenHeir", "hasCustomName", "hasDecisions", "hasDynasty", "hasFounder", "hasHeir", "hasLeader", "hasLeaderFamily", "hasLeaderSpouseAlive", "hasNation", "hasScenarioCompleted", "hasStateReligion", "hasTurnSummary", "ignoreReminders", "isActive", "isAIAutoPlay", "isAlive", "isAnyDirty", "isAnyPickLater", "isAnyTechAvailable", "isCityMaxReached", "isCompletedGameSaved", "isConvertedLegitimacy", "isDead", "isFirstTurnProcessing", "isFounded", "isHuman", "isHumanOriginal", "isInitialized", "isLeaderAlive", "isPlaying", "isProcessingTurn", "isProcessingTurnStart", "isResearching", "isStartCityNumberFlexible", "isSurrendered", "isTeamHuman", "isTechRedraw", "isTurnEnded", "isTurnSummaryReady", "isTutorial", "isUsedOffensive", "isVersionMismatch", "language", "lastDoTurn", "leaderFamily", "legitimacy", "minStartingCities", "missionModifier", "money", "moneyWhole", "nation", "numCities", "numDecisions", "numEmptySites", "numFamilies", "numGameLoads", "numGoals", "numMemories", "numMissions", "numPopups", "numStartingTiles", "numTiles", "numTrades", "numTributes", "numUnits", "onlineID", "ordersLeft", "originalCapitalCityID", "pingTiles", "player", "playerEmail", "playerInt", "popupTechDiscovered", "recruitLegitimacy", "reloads", "saltWaterTileCount", "sellPenaltyModifier", "startingTileID", "startTurnCities", "stateReligion", "stateReligionChangeCount", "stateReligionSpreadChange", "succession", "successionCount", "successionGender", "successionOrder", "team", "techCostModifier", "techResearching", "techsAvailableChange", "timeStockpile", "trainingOrderModifier", "tribeFatigueLimitChange", "tribeMercenaryCount", "turnSummary", "turnTimeNeeded", "visionChange", "wonderModifier", "worldReligionCount", "worldReligionSpreadChange", "yieldUpkeepModifier", "activeImprovementClassCounts", "activeImprovementCounts", "adoptReligionCosts", "ambitionDecisionss", "councilCharacters", "effectCityRebelProbs", "effectPlayerCounts", "extraLuxuryCounts", "familyControls", "familyHeadIDs", "familyOpinionRates", "familySeatCityIDs", "familyTurnsNoLeaders", "giftYieldQuantitys", "goalStartedCounts", "hasActiveCharacters", "hasCouncilCharacters", "hasFamilyHeads", "hasFamilyOpinions", "hasFamilySeatCitys", "hasHeightClaimeds", "hasReligions", "hasReligionOpinionHistorys", "hasTerrainClaimeds", "hasTraitCharacters", "hasVegetationClaimeds", "improvementClassCounts", "improvementCounts", "improvementLawsRequireds", "isActiveLaws", "isBuyTileUnlocks", "isCityYieldBuildCurrents", "isConnectedForeignUnlocks", "isCouncilUnlocks", "isFamilyReligions", "isFamilyStarteds", "isHideUnitUnlocks", "isImprovementNotValids", "isImprovementUnlockeds", "isLatestUpgrades", "isMinTreatyTurnss", "isNationImprovements", "isNoFamilyRestrictionJobUnlocks", "isNoSellPenaltyYieldUnlocks", "isProjectNotValids", "isSpreadBordersUnlocks", "isTechAcquireds", "isTechAnyDecks", "isTechAvailables", "isTechHides", "isTechLockeds", "isTechNotValids", "isTechPasseds", "isTechPassedCurrents", "isTechTargets", "isTechTrasheds", "isUnitNotValids", "isUnitObsoletes", "isUnitUnlockeds", "isWaterUnitUnlocks", "isWorldOrOurPaganReligions", "jobOpinionRates", "livingCourtiersYields", "livingRoyalsYields", "luxuryCounts", "missionCooldownTurnsLefts", "missionStartedTurns", "projectsProduceds", "questsFaileds", "religionCounts", "religionOpinionRates", "resourceRevealeds", "startLawCosts", "techCosts", "techCostWholes", "techProgresss", "techTurnDiscovereds", "theologyEstablishedCounts", "tradeOutpostYieldTotals", "unitsProduceds", "unitsProducedTurns", "unitTraitConsumptionModifiers", "yieldStockpiles", "yieldStockpileWholes", "yieldTotals", "yieldUpkeepNets", "index", "rates", "stockpiles"],
+            Self::PlayerUnits(_) => &["agentNetworkCost", "anchoredTurns", "caravanMissionTarget", "civilianCycleSubgroup", "consumptionMercenaryModifier", "cooldown", "cooldownTurns", "createTurn", "currentFormation", "damage", "damagedExtraBuildTurns", "effectUnitCount", "effectUnits", "endRoutEffect", "explorerID", "facing", "family", "familyClass", "familyOpinion", "fatigueLimit", "formationBonus", "formationTurns", "fortifyBonus", "fortifyTurns", "freeActions", "freeActionsTaken", "freeImprovementBuild", "gender", "generalID", "grammaticalGender", "harvestDistanceModifier", "hasCaravanMissionTarget", "hasCooldown", "hasCriticalImmune", "hasEffectEstablishTheology", "hasEffectMercenary", "hasEffectSpreadReligion", "hasEffectUnitAny", "hasEndRoutEffect", "hasEnlistNext", "hasExplorer", "hasFamily", "hasFamilyOpinion", "hasFamilyTerritoryOnly", "hasFreeActions", "hasFreeImprovementBuild", "hasGeneral", "hasGeneralHopping", "hasGeneralModifier", "hasHarvest", "hasHealKill", "hasHealNeutral", "hasHealPillage", "hasIgnoreZOC", "hasLastStand", "hasLaunchOffensive", "hasMoved", "hasName", "hasNoRoadCooldown", "hasOffensiveRemoveCooldown", "hasOriginalPlayer", "hasOriginalTribe", "hasPillage", "hasPlayer", "hasQueueList", "hasRebelPlayer", "hasReligion", "hasRemoveVegetation", "hasVisibleAttackCooldown", "hasZOC", "hp", "hpMax", "id", "initialGender", "isActiveOutOfTurn", "isActivePlayer", "isAlive", "isAnchored", "isAnyDirty", "isAutoHarvest", "isAutoHeal", "isAutomated", "isAutoMoveOnly", "isBusy", "isCapturingCity", "isCriticalHit", "isDamaged", "isDead", "isFatigued", "isFormation", "isFormationMax", "isFortify", "isFortifyMax", "isHiddenNotTemp", "isHuman", "isImmobile", "isImprovingTile", "isLeaderExplorer", "isLeaderGeneral", "isLevelMax", "isLevelPromotionMax", "isLocked", "isMarch", "isModVariables", "isOccurrenceImpassableImmune", "isPass", "isPromotable", "isRaiding", "isRemoveVegatation", "isRoadBuilder", "isScout", "isSentry", "isShowCriticalHit", "isSkip", "isSleep", "isTempHidden", "isTribe", "isUnlimbered", "isWorker", "level", "levelPromotion", "levelPromotionString", "levelString", "marchCost", "meleeCounterPercent", "modVariables", "name", "nameType", "nation", "nextCriticalModifier", "offensiveUnits", "originalPlayer", "originalTribe", "player", "pushEffect", "queuedTile", "queueList", "rebelPlayer", "rebelTeam", "religion", "roadMovementModifier", "routChain", "spreadReligion", "spreadReligionTribeCost", "stepsToFatigue", "strengthRating", "team", "tempHiddenTurns", "tileID", "trainCostWhole", "trainXP", "tribe", "turnsSinceLastMove", "turnSteps", "type", "unitCycleGroup", "unlimberedTurns", "workerAnimationType", "xp", "xpThreshold", "effectUnitCounts", "effectUnitTurnRemainings", "harvestYieldModifiers", "hasBuyTileYields", "hasEffectUnits", "hasIgnoreHeightCosts", "hasIgnoreVegetationCosts", "hasIgnoreVegetationDefenses", "hasPromotions", "isExplorerEffectUnits", "isGeneralEffectUnits", "isImmuneEffectUnits", "isUnitZocs", "tradeOutpostCosts", "promotions", "x", "y"],
+            Self::PlayerTechs(_) => &["researching", "progress", "researched", "available"],
+            Self::PlayerFamilies(_) => &["families"],
+            Self::PlayerReligion(_) => &["stateReligion", "religionCounts"],
+            Self::PlayerGoals(_) => &["goals"],
+            Self::PlayerDecisions(_) => &["decisions", "hasDecisions"],
+            Self::PlayerLaws(_) => &["activeLaws", "activeLawCount"],
+            Self::PlayerMissions(_) => &["missions", "cooldowns"],
+            Self::PlayerResources(_) => &["luxuries", "revealed"],
+            Self::Cities(_) => &["activeEffectCity", "activeImprovementClassCountAll", "activeImprovementCountAll", "adjacentClassCostModifier", "assimilateTurns", "buildCount", "buildTurnChange", "buildUnitFamily", "buyTileCount", "capturePlayer", "captureTeam", "captureThreshold", "captureTribe", "captureTurns", "citizens", "citizensPlusQueuedWithNoProgress", "citizensQueue", "citizensTotal", "citySite", "completedBuild", "culture", "cultureStep", "currentBuildType", "damage", "defaultGovernorID", "family", "familyClass", "familyOpinion", "firstPlayer", "firstTeam", "foundedTurn", "giftedTurn", "governorID", "grammaticalGender", "growthCount", "happinessLevel", "hasAnyAgentCharacter", "hasAutobuildQueued", "hasBuild", "hasBuildAnyReligionUnitUnlock", "hasBuildPlanned", "hasCapturePlayer", "hasCaptureTribe", "hasCompletedBuild", "hasDefaultGovernor", "hasFamily", "hasFamilyOpinion", "hasGovernor", "hasLastPlayer", "hasLastTribe", "hasNation", "hasPlayer", "hasRebelEffects", "hasStateReligion", "healExtra", "hp", "hpMax", "hpMaxExtra", "hurryCivicsCost", "hurryCivicsCount", "hurryDiscontent", "hurryDiscontentModifier", "hurryMoneyCost", "hurryMoneyCount", "hurryOrdersCost", "hurryOrdersCount", "hurryPopulationCost", "hurryPopulationCount", "hurryTrainingCost", "hurryTrainingCount", "id", "improvementCostModifier", "isActivePlayer", "isAnyDirty", "isAutomated", "isBuildNoHurry", "isCapital", "isCapturedCapital", "isConnected", "isDamaged", "isDefaultGovernorRuling", "isFamilySeat", "isGoverned", "isGovernorLeader", "isHuman", "isIdle", "isReligionHolyCityAny", "isTribe", "isUnitCapturingCity", "isVulnerable", "isYieldRateDefendingAny", "lastPlayer", "lastTeam", "lastTribe", "luxuryCount", "militaryUnitCount", "name", "nameType", "nation", "numRandomPromotions", "paganReligionCount", "passiveHealDamage", "player", "playerInt", "population", "projectCostModifier", "raidedTurn", "rangeChange", "regrowthModifier", "religionCount", "religionNonStateCount", "saltWaterTiles", "specialistCostModifier", "specialistCount", "specialistProducedCount", "specialistRuralTrainTimeModifier", "specialistUrbanCostModifier", "specialistUrbanTrainTimeModifier", "strengthModifier", "team", "territoryTiles", "tileID", "tradeNetwork", "tribe", "urbanBuildTurnChange", "urbanSpecialistCount", "urbanTiles", "activeImprovementClassCounts", "activeImprovementCounts", "assimilateYieldModifiers", "baseYieldNets", "buildUnitLevelss", "buildUnitXPs", "damageYieldModifiers", "excessOverflows", "happinessLevelYieldModifiers", "hasProjects", "improvementClassCostModifiers", "improvementClassCounts", "improvementClassDevelopChanges", "improvementClassModifiers", "improvementCounts", "improvementModifiers", "improvementRiverModifiers", "isBannedReligionSpreads", "isLuxurys", "isNoImprovementClassMaxUnlocks", "isReligions", "isReligionHolyCitys", "isReligionSpreadEligibles", "isSpecialistNoPrereqs", "isUnitSpawnPossibles", "isYieldBuildCurrents", "luxuryTradeLengths", "luxuryTurns", "makeGovernorCosts", "militaryUnitFamilyCounts", "nextTurnOverflows", "projectCounts", "specialistCostModifiers", "stateReligionUnitTraitTrainModifiers", "territoryTileCounts", "unitCostModifiers", "unitProductionCounts", "unitTrainModifiers", "unitTraitCostModifiers", "unitTraitTrainModifiers", "yieldLevels", "yieldOverflows", "yieldProgresss", "yieldThresholds", "yieldThresholdWholes", "yieldTurnsLefts", "buildQueue", "currentBuild", "holyCity", "improvementClasses", "improvements", "projects", "religions", "territoryTileCount", "x", "y", "yields"],
+            Self::City(_) => &["activeEffectCity", "activeImprovementClassCountAll", "activeImprovementCountAll", "adjacentClassCostModifier", "assimilateTurns", "buildCount", "buildTurnChange", "buildUnitFamily", "buyTileCount", "capturePlayer", "captureTeam", "captureThreshold", "captureTribe", "captureTurns", "citizens", "citizensPlusQueuedWithNoProgress", "citizensQueue", "citizensTotal", "citySite", "completedBuild", "culture", "cultureStep", "currentBuildType", "damage", "defaultGovernorID", "family", "familyClass", "familyOpinion", "firstPlayer", "firstTeam", "foundedTurn", "giftedTurn", "governorID", "grammaticalGender", "growthCount", "happinessLevel", "hasAnyAgentCharacter", "hasAutobuildQueued", "hasBuild", "hasBuildAnyReligionUnitUnlock", "hasBuildPlanned", "hasCapturePlayer", "hasCaptureTribe", "hasCompletedBuild", "hasDefaultGovernor", "hasFamily", "hasFamilyOpinion", "hasGovernor", "hasLastPlayer", "hasLastTribe", "hasNation", "hasPlayer", "hasRebelEffects", "hasStateReligion", "healExtra", "hp", "hpMax", "hpMaxExtra", "hurryCivicsCost", "hurryCivicsCount", "hurryDiscontent", "hurryDiscontentModifier", "hurryMoneyCost", "hurryMoneyCount", "hurryOrdersCost", "hurryOrdersCount", "hurryPopulationCost", "hurryPopulationCount", "hurryTrainingCost", "hurryTrainingCount", "id", "improvementCostModifier", "isActivePlayer", "isAnyDirty", "isAutomated", "isBuildNoHurry", "isCapital", "isCapturedCapital", "isConnected", "isDamaged", "isDefaultGovernorRuling", "isFamilySeat", "isGoverned", "isGovernorLeader", "isHuman", "isIdle", "isReligionHolyCityAny", "isTribe", "isUnitCapturingCity", "isVulnerable", "isYieldRateDefendingAny", "lastPlayer", "lastTeam", "lastTribe", "luxuryCount", "militaryUnitCount", "name", "nameType", "nation", "numRandomPromotions", "paganReligionCount", "passiveHealDamage", "player", "playerInt", "population", "projectCostModifier", "raidedTurn", "rangeChange", "regrowthModifier", "religionCount", "religionNonStateCount", "saltWaterTiles", "specialistCostModifier", "specialistCount", "specialistProducedCount", "specialistRuralTrainTimeModifier", "specialistUrbanCostModifier", "specialistUrbanTrainTimeModifier", "strengthModifier", "team", "territoryTiles", "tileID", "tradeNetwork", "tribe", "urbanBuildTurnChange", "urbanSpecialistCount", "urbanTiles", "activeImprovementClassCounts", "activeImprovementCounts", "assimilateYieldModifiers", "baseYieldNets", "buildUnitLevelss", "buildUnitXPs", "damageYieldModifiers", "excessOverflows", "happinessLevelYieldModifiers", "hasProjects", "improvementClassCostModifiers", "improvementClassCounts", "improvementClassDevelopChanges", "improvementClassModifiers", "improvementCounts", "improvementModifiers", "improvementRiverModifiers", "isBannedReligionSpreads", "isLuxurys", "isNoImprovementClassMaxUnlocks", "isReligions", "isReligionHolyCitys", "isReligionSpreadEligibles", "isSpecialistNoPrereqs", "isUnitSpawnPossibles", "isYieldBuildCurrents", "luxuryTradeLengths", "luxuryTurns", "makeGovernorCosts", "militaryUnitFamilyCounts", "nextTurnOverflows", "projectCounts", "specialistCostModifiers", "stateReligionUnitTraitTrainModifiers", "territoryTileCounts", "unitCostModifiers", "unitProductionCounts", "unitTrainModifiers", "unitTraitCostModifiers", "unitTraitTrainModifiers", "yieldLevels", "yieldOverflows", "yieldProgresss", "yieldThresholds", "yieldThresholdWholes", "yieldTurnsLefts", "buildQueue", "currentBuild", "holyCity", "improvementClasses", "improvements", "projects", "religions", "territoryTileCount", "x", "y", "yields"],
+            Self::Characters(_) => &["abdicateTurn", "age", "ageGroup", "ageRemainder", "archetype", "birthCityID", "birthFatherID", "birthMotherID", "birthTurn", "character", "characterPortrait", "cityAgentID", "cityGovernorID", "clergyType", "cognomen", "council", "courtier", "customName", "deadCouncil", "deathReason", "deathTurn", "explorerEffectUnits", "family", "familyClass", "fatherID", "firstName", "gender", "genderInt", "genderOpposite", "generalEffectUnits", "hasArchetype", "hasBirthCity", "hasBirthFather", "hasBirthMother", "hasCharacter", "hasCognomen", "hasCustomName", "hasDeadCouncil", "hasExplorerAll", "hasExplorerPrereq", "hasFamily", "hasFather", "hasGeneralAll", "hasGeneralPrereq", "hasGovernorAll", "hasGovernorPrereq", "hasItems", "hasMother", "hasName", "hasNation", "hasNickname", "hasPlayer", "hasReligion", "hasSpouseAlive", "hasSpouseForeign", "hasSpouseTribe", "hasTitle", "hasTraitDieProb", "hasTraitDoomed", "hasTraitFormerRegent", "hasTraitNoBirth", "hasTraitNoCouncil", "hasTraitNoCourtier", "hasTraitNoEvents", "hasTraitNoExplorer", "hasTraitNoFamilyHead", "hasTraitNoGeneral", "hasTraitNoGovernor", "hasTraitNoJob", "hasTraitNoMarry", "hasTraitNoReligion", "hasTraitNoReligionHeadNew", "hasTraitNoSuccession", "hasTraitRegent", "hasTraitSuccessionBypass", "hasTraitSuccessionReturn", "hasUnit", "headReligion", "id", "isAbdicated", "isActivePlayer", "isAdult", "isAgeless", "isAlive", "isAnyDirty", "isAttractedToAny", "isBisexual", "isCityAgent", "isCityGovernor", "isClergy", "isCouncil", "isCourtier", "isDead", "isFamilyHead", "isFamilyHeadSpouse", "isFemale", "isFertileAge", "isForceInheritanceTreeInclude", "isGay", "isHeir", "isHeirSpouse", "isHuman", "isInfertile", "isJob", "isLeader", "isLeaderAncestor", "isLeaderAnySibling", "isLeaderChild", "isLeaderDescendant", "isLeaderOrChildOfLeader", "isLeaderOrDescendant", "isLeaderOrHeir", "isLeaderOrSpouse", "isLeaderOrSpouseOrHeir", "isLeaderOrSpouseOrSuccessor", "isLeaderOrSuccessor", "isLeaderSpouse", "isMale", "isOrWasLeader", "isOrWasLeaderSpouse", "isOrWasRegent", "isRegent", "isReligionHead", "isRetired", "isRoyal", "isRoyalSpouse", "isSafe", "isSpouseLeaderDescendant", "isSuccessionSkipRegent", "isSuccessor", "isSuccessorParent", "isSuccessorSpouse", "isTemporary", "isTribe", "isTribeLeader", "isUnitExplorer", "isUnitGeneral", "isValidChooseName", "job", "leaderIndex", "leaderTurn", "legitimacy", "level", "makeAgentExtraOpinion", "makeExplorerExtraOpinion", "makeGeneralExtraOpinion", "makeGovernorExtraOpinion", "maxStrengths", "maxWeaknesses", "motherID", "nameType", "nation", "nationTurn", "nickname", "nicknameText", "numChildren", "numEventStoryTexts", "numEventStoryTurns", "numSpouses", "player", "playerInt", "playerOpinionOfCharacterReligion", "randomTraitDelay", "ratingAttackModifierTotal", "ratingCriticalChanceTotal", "ratingHarvestModifierTotal", "ratingUnitXPTotal", "regentTurn", "religion", "safeTurn", "suffix", "team", "title", "traitBirthModifier", "traitNickname", "traitProbDelay", "traitTitle", "tribe", "unitBuildModifier", "unitID", "upgradeXPThreshold", "wasFamilyHead", "wasReligionHead", "xp", "youngestLivingChildAge", "changeJobExtraOpinions", "cognomenMinValues", "familyOpinionCouncils", "hasCouncilPrereqs", "hasRelationshipTypes", "hasTraitLockedReligions", "hasTraitReligionOpinions", "hasTraitReplacess", "isJobs", "isReligionAgents", "isTraits", "jobOpinions", "makeCouncilExtraOpinions", "nationEthnicitys", "ratings", "ratingYieldRateAgentTotals", "traitTurns", "traitTurnLengths", "tribeEthnicitys", "yieldRateCourtiers", "yieldRateLeaders", "yieldRateLeaderSpouses", "yieldRateSuccessors", "childrenIds", "opinions", "relationships", "spouseIds", "traits"],
+            Self::Character(_) => &["abdicateTurn", "age", "ageGroup", "ageRemainder", "archetype", "birthCityID", "birthFatherID", "birthMotherID", "birthTurn", "character", "characterPortrait", "cityAgentID", "cityGovernorID", "clergyType", "cognomen", "council", "courtier", "customName", "deadCouncil", "deathReason", "deathTurn", "explorerEffectUnits", "family", "familyClass", "fatherID", "firstName", "gender", "genderInt", "genderOpposite", "generalEffectUnits", "hasArchetype", "hasBirthCity", "hasBirthFather", "hasBirthMother", "hasCharacter", "hasCognomen", "hasCustomName", "hasDeadCouncil", "hasExplorerAll", "hasExplorerPrereq", "hasFamily", "hasFather", "hasGeneralAll", "hasGeneralPrereq", "hasGovernorAll", "hasGovernorPrereq", "hasItems", "hasMother", "hasName", "hasNation", "hasNickname", "hasPlayer", "hasReligion", "hasSpouseAlive", "hasSpouseForeign", "hasSpouseTribe", "hasTitle", "hasTraitDieProb", "hasTraitDoomed", "hasTraitFormerRegent", "hasTraitNoBirth", "hasTraitNoCouncil", "hasTraitNoCourtier", "hasTraitNoEvents", "hasTraitNoExplorer", "hasTraitNoFamilyHead", "hasTraitNoGeneral", "hasTraitNoGovernor", "hasTraitNoJob", "hasTraitNoMarry", "hasTraitNoReligion", "hasTraitNoReligionHeadNew", "hasTraitNoSuccession", "hasTraitRegent", "hasTraitSuccessionBypass", "hasTraitSuccessionReturn", "hasUnit", "headReligion", "id", "isAbdicated", "isActivePlayer", "isAdult", "isAgeless", "isAlive", "isAnyDirty", "isAttractedToAny", "isBisexual", "isCityAgent", "isCityGovernor", "isClergy", "isCouncil", "isCourtier", "isDead", "isFamilyHead", "isFamilyHeadSpouse", "isFemale", "isFertileAge", "isForceInheritanceTreeInclude", "isGay", "isHeir", "isHeirSpouse", "isHuman", "isInfertile", "isJob", "isLeader", "isLeaderAncestor", "isLeaderAnySibling", "isLeaderChild", "isLeaderDescendant", "isLeaderOrChildOfLeader", "isLeaderOrDescendant", "isLeaderOrHeir", "isLeaderOrSpouse", "isLeaderOrSpouseOrHeir", "isLeaderOrSpouseOrSuccessor", "isLeaderOrSuccessor", "isLeaderSpouse", "isMale", "isOrWasLeader", "isOrWasLeaderSpouse", "isOrWasRegent", "isRegent", "isReligionHead", "isRetired", "isRoyal", "isRoyalSpouse", "isSafe", "isSpouseLeaderDescendant", "isSuccessionSkipRegent", "isSuccessor", "isSuccessorParent", "isSuccessorSpouse", "isTemporary", "isTribe", "isTribeLeader", "isUnitExplorer", "isUnitGeneral", "isValidChooseName", "job", "leaderIndex", "leaderTurn", "legitimacy", "level", "makeAgentExtraOpinion", "makeExplorerExtraOpinion", "makeGeneralExtraOpinion", "makeGovernorExtraOpinion", "maxStrengths", "maxWeaknesses", "motherID", "nameType", "nation", "nationTurn", "nickname", "nicknameText", "numChildren", "numEventStoryTexts", "numEventStoryTurns", "numSpouses", "player", "playerInt", "playerOpinionOfCharacterReligion", "randomTraitDelay", "ratingAttackModifierTotal", "ratingCriticalChanceTotal", "ratingHarvestModifierTotal", "ratingUnitXPTotal", "regentTurn", "religion", "safeTurn", "suffix", "team", "title", "traitBirthModifier", "traitNickname", "traitProbDelay", "traitTitle", "tribe", "unitBuildModifier", "unitID", "upgradeXPThreshold", "wasFamilyHead", "wasReligionHead", "xp", "youngestLivingChildAge", "changeJobExtraOpinions", "cognomenMinValues", "familyOpinionCouncils", "hasCouncilPrereqs", "hasRelationshipTypes", "hasTraitLockedReligions", "hasTraitReligionOpinions", "hasTraitReplacess", "isJobs", "isReligionAgents", "isTraits", "jobOpinions", "makeCouncilExtraOpinions", "nationEthnicitys", "ratings", "ratingYieldRateAgentTotals", "traitTurns", "traitTurnLengths", "tribeEthnicitys", "yieldRateCourtiers", "yieldRateLeaders", "yieldRateLeaderSpouses", "yieldRateSuccessors", "childrenIds", "opinions", "relationships", "spouseIds", "traits"],
+            Self::Units(_) => &["agentNetworkCost", "anchoredTurns", "caravanMissionTarget", "civilianCycleSubgroup", "consumptionMercenaryModifier", "cooldown", "cooldownTurns", "createTurn", "currentFormation", "damage", "damagedExtraBuildTurns", "effectUnitCount", "effectUnits", "endRoutEffect", "explorerID", "facing", "family", "familyClass", "familyOpinion", "fatigueLimit", "formationBonus", "formationTurns", "fortifyBonus", "fortifyTurns", "freeActions", "freeActionsTaken", "freeImprovementBuild", "gender", "generalID", "grammaticalGender", "harvestDistanceModifier", "hasCaravanMissionTarget", "hasCooldown", "hasCriticalImmune", "hasEffectEstablishTheology", "hasEffectMercenary", "hasEffectSpreadReligion", "hasEffectUnitAny", "hasEndRoutEffect", "hasEnlistNext", "hasExplorer", "hasFamily", "hasFamilyOpinion", "hasFamilyTerritoryOnly", "hasFreeActions", "hasFreeImprovementBuild", "hasGeneral", "hasGeneralHopping", "hasGeneralModifier", "hasHarvest", "hasHealKill", "hasHealNeutral", "hasHealPillage", "hasIgnoreZOC", "hasLastStand", "hasLaunchOffensive", "hasMoved", "hasName", "hasNoRoadCooldown", "hasOffensiveRemoveCooldown", "hasOriginalPlayer", "hasOriginalTribe", "hasPillage", "hasPlayer", "hasQueueList", "hasRebelPlayer", "hasReligion", "hasRemoveVegetation", "hasVisibleAttackCooldown", "hasZOC", "hp", "hpMax", "id", "initialGender", "isActiveOutOfTurn", "isActivePlayer", "isAlive", "isAnchored", "isAnyDirty", "isAutoHarvest", "isAutoHeal", "isAutomated", "isAutoMoveOnly", "isBusy", "isCapturingCity", "isCriticalHit", "isDamaged", "isDead", "isFatigued", "isFormation", "isFormationMax", "isFortify", "isFortifyMax", "isHiddenNotTemp", "isHuman", "isImmobile", "isImprovingTile", "isLeaderExplorer", "isLeaderGeneral", "isLevelMax", "isLevelPromotionMax", "isLocked", "isMarch", "isModVariables", "isOccurrenceImpassableImmune", "isPass", "isPromotable", "isRaiding", "isRemoveVegatation", "isRoadBuilder", "isScout", "isSentry", "isShowCriticalHit", "isSkip", "isSleep", "isTempHidden", "isTribe", "isUnlimbered", "isWorker", "level", "levelPromotion", "levelPromotionString", "levelString", "marchCost", "meleeCounterPercent", "modVariables", "name", "nameType", "nation", "nextCriticalModifier", "offensiveUnits", "originalPlayer", "originalTribe", "player", "pushEffect", "queuedTile", "queueList", "rebelPlayer", "rebelTeam", "religion", "roadMovementModifier", "routChain", "spreadReligion", "spreadReligionTribeCost", "stepsToFatigue", "strengthRating", "team", "tempHiddenTurns", "tileID", "trainCostWhole", "trainXP", "tribe", "turnsSinceLastMove", "turnSteps", "type", "unitCycleGroup", "unlimberedTurns", "workerAnimationType", "xp", "xpThreshold", "effectUnitCounts", "effectUnitTurnRemainings", "harvestYieldModifiers", "hasBuyTileYields", "hasEffectUnits", "hasIgnoreHeightCosts", "hasIgnoreVegetationCosts", "hasIgnoreVegetationDefenses", "hasPromotions", "isExplorerEffectUnits", "isGeneralEffectUnits", "isImmuneEffectUnits", "isUnitZocs", "tradeOutpostCosts", "promotions", "x", "y"],
+            Self::Unit(_) => &["agentNetworkCost", "anchoredTurns", "caravanMissionTarget", "civilianCycleSubgroup", "consumptionMercenaryModifier", "cooldown", "cooldownTurns", "createTurn", "currentFormation", "damage", "damagedExtraBuildTurns", "effectUnitCount", "effectUnits", "endRoutEffect", "explorerID", "facing", "family", "familyClass", "familyOpinion", "fatigueLimit", "formationBonus", "formationTurns", "fortifyBonus", "fortifyTurns", "freeActions", "freeActionsTaken", "freeImprovementBuild", "gender", "generalID", "grammaticalGender", "harvestDistanceModifier", "hasCaravanMissionTarget", "hasCooldown", "hasCriticalImmune", "hasEffectEstablishTheology", "hasEffectMercenary", "hasEffectSpreadReligion", "hasEffectUnitAny", "hasEndRoutEffect", "hasEnlistNext", "hasExplorer", "hasFamily", "hasFamilyOpinion", "hasFamilyTerritoryOnly", "hasFreeActions", "hasFreeImprovementBuild", "hasGeneral", "hasGeneralHopping", "hasGeneralModifier", "hasHarvest", "hasHealKill", "hasHealNeutral", "hasHealPillage", "hasIgnoreZOC", "hasLastStand", "hasLaunchOffensive", "hasMoved", "hasName", "hasNoRoadCooldown", "hasOffensiveRemoveCooldown", "hasOriginalPlayer", "hasOriginalTribe", "hasPillage", "hasPlayer", "hasQueueList", "hasRebelPlayer", "hasReligion", "hasRemoveVegetation", "hasVisibleAttackCooldown", "hasZOC", "hp", "hpMax", "id", "initialGender", "isActiveOutOfTurn", "isActivePlayer", "isAlive", "isAnchored", "isAnyDirty", "isAutoHarvest", "isAutoHeal", "isAutomated", "isAutoMoveOnly", "isBusy", "isCapturingCity", "isCriticalHit", "isDamaged", "isDead", "isFatigued", "isFormation", "isFormationMax", "isFortify", "isFortifyMax", "isHiddenNotTemp", "isHuman", "isImmobile", "isImprovingTile", "isLeaderExplorer", "isLeaderGeneral", "isLevelMax", "isLevelPromotionMax", "isLocked", "isMarch", "isModVariables", "isOccurrenceImpassableImmune", "isPass", "isPromotable", "isRaiding", "isRemoveVegatation", "isRoadBuilder", "isScout", "isSentry", "isShowCriticalHit", "isSkip", "isSleep", "isTempHidden", "isTribe", "isUnlimbered", "isWorker", "level", "levelPromotion", "levelPromotionString", "levelString", "marchCost", "meleeCounterPercent", "modVariables", "name", "nameType", "nation", "nextCriticalModifier", "offensiveUnits", "originalPlayer", "originalTribe", "player", "pushEffect", "queuedTile", "queueList", "rebelPlayer", "rebelTeam", "religion", "roadMovementModifier", "routChain", "spreadReligion", "spreadReligionTribeCost", "stepsToFatigue", "strengthRating", "team", "tempHiddenTurns", "tileID", "trainCostWhole", "trainXP", "tribe", "turnsSinceLastMove", "turnSteps", "type", "unitCycleGroup", "unlimberedTurns", "workerAnimationType", "xp", "xpThreshold", "effectUnitCounts", "effectUnitTurnRemainings", "harvestYieldModifiers", "hasBuyTileYields", "hasEffectUnits", "hasIgnoreHeightCosts", "hasIgnoreVegetationCosts", "hasIgnoreVegetationDefenses", "hasPromotions", "isExplorerEffectUnits", "isGeneralEffectUnits", "isImmuneEffectUnits", "isUnitZocs", "tradeOutpostCosts", "promotions", "x", "y"],
+            Self::Tiles(_) => &["activeImprovementClass", "area", "areaTileCount", "cityID", "citySite", "cityTerritory", "constructionProgress", "currentSpecialist", "customMapElementName", "extraBlockingVisibilityHeight", "family", "harvestTurn", "hasCanDamageUnit", "hasCity", "hasCityTerritory", "hasFamily", "hasFreeSpecialist", "hasGovernor", "hasImprovement", "hasImprovementClass", "hasImprovementFreeSpecialist", "hasImprovementRural", "hasImprovementWithBorderSpread", "hasInactiveImprovement", "hasMapElementName", "hasMapGeneratorData", "hasOwner", "hasOwnerTribe", "hasReligion", "hasResource", "hasSpecialist", "hasUnit", "hasVegetation", "height", "heightHistory", "hiddenHeight", "hiddenTerrain", "id", "improvement", "improvementBuildTurnsLeft", "improvementBuildTurnsOriginal", "improvementClass", "improvementDevelopTurns", "improvementDynamicText", "improvementFirstSpecialist", "improvementGrammaticalGender", "improvementPillageTurns", "improvementReligionPrereq", "improvementReligionSpread", "improvementUnitTurns", "isAnyCoastLand", "isAnyDirty", "isBorder", "isBoundary", "isCanal", "isCitySiteAny", "isClear", "isFlat", "isFreshWater", "isFreshWaterAccess", "isFreshWaterSource", "isHarvested", "isImprovementUnfinished", "isImprovementUnfinishedOrPillaged", "isLand", "isMinorCity", "isNationSite", "isPassableLand", "isPillaged", "isRegrowing", "isRiver", "isRiverE", "isRiverNE", "isRiverNW", "isRiverSE", "isRiverSW", "isRiverW", "isRoad", "isSaltCoastLand", "isSaltCoastWater", "isSaltWater", "isTribeSite", "isUrban", "isWater", "landSection", "lastTurnOwnerChange", "latitude", "mapElementName", "mapElementNameType", "mapGeneratorData", "metadata", "movementCostExtra", "nation", "nationSite", "numTags", "origUrbanOwner", "owner", "ownerTribe", "regrowthTurn", "resource", "resourceHeightOffset", "riverSE", "riverSW", "riverW", "specialist", "specialistClass", "team", "terrain", "terrainHistory", "terrainStamp", "terrainStampTileID", "terrainUnitDamage", "tradeOutpostIncome", "tribeSite", "vegetation", "vegetationHistory", "x", "y", "hasOrBuildingSpecialists", "improvementCosts", "isImprovementBorderSpreads", "isReligions", "isResourceValids", "isSpecialistCostCitizens"],
+            Self::Tile(_) => &["activeImprovementClass", "area", "areaTileCount", "cityID", "citySite", "cityTerritory", "constructionProgress", "currentSpecialist", "customMapElementName", "extraBlockingVisibilityHeight", "family", "harvestTurn", "hasCanDamageUnit", "hasCity", "hasCityTerritory", "hasFamily", "hasFreeSpecialist", "hasGovernor", "hasImprovement", "hasImprovementClass", "hasImprovementFreeSpecialist", "hasImprovementRural", "hasImprovementWithBorderSpread", "hasInactiveImprovement", "hasMapElementName", "hasMapGeneratorData", "hasOwner", "hasOwnerTribe", "hasReligion", "hasResource", "hasSpecialist", "hasUnit", "hasVegetation", "height", "heightHistory", "hiddenHeight", "hiddenTerrain", "id", "improvement", "improvementBuildTurnsLeft", "improvementBuildTurnsOriginal", "improvementClass", "improvementDevelopTurns", "improvementDynamicText", "improvementFirstSpecialist", "improvementGrammaticalGender", "improvementPillageTurns", "improvementReligionPrereq", "improvementReligionSpread", "improvementUnitTurns", "isAnyCoastLand", "isAnyDirty", "isBorder", "isBoundary", "isCanal", "isCitySiteAny", "isClear", "isFlat", "isFreshWater", "isFreshWaterAccess", "isFreshWaterSource", "isHarvested", "isImprovementUnfinished", "isImprovementUnfinishedOrPillaged", "isLand", "isMinorCity", "isNationSite", "isPassableLand", "isPillaged", "isRegrowing", "isRiver", "isRiverE", "isRiverNE", "isRiverNW", "isRiverSE", "isRiverSW", "isRiverW", "isRoad", "isSaltCoastLand", "isSaltCoastWater", "isSaltWater", "isTribeSite", "isUrban", "isWater", "landSection", "lastTurnOwnerChange", "latitude", "mapElementName", "mapElementNameType", "mapGeneratorData", "metadata", "movementCostExtra", "nation", "nationSite", "numTags", "origUrbanOwner", "owner", "ownerTribe", "regrowthTurn", "resource", "resourceHeightOffset", "riverSE", "riverSW", "riverW", "specialist", "specialistClass", "team", "terrain", "terrainHistory", "terrainStamp", "terrainStampTileID", "terrainUnitDamage", "tradeOutpostIncome", "tribeSite", "vegetation", "vegetationHistory", "x", "y", "hasOrBuildingSpecialists", "improvementCosts", "isImprovementBorderSpreads", "isReligions", "isResourceValids", "isSpecialistCostCitizens"],
+            Self::TileByCoords(_) => &["activeImprovementClass", "area", "areaTileCount", "cityID", "citySite", "cityTerritory", "constructionProgress", "currentSpecialist", "customMapElementName", "extraBlockingVisibilityHeight", "family", "harvestTurn", "hasCanDamageUnit", "hasCity", "hasCityTerritory", "hasFamily", "hasFreeSpecialist", "hasGovernor", "hasImprovement", "hasImprovementClass", "hasImprovementFreeSpecialist", "hasImprovementRural", "hasImprovementWithBorderSpread", "hasInactiveImprovement", "hasMapElementName", "hasMapGeneratorData", "hasOwner", "hasOwnerTribe", "hasReligion", "hasResource", "hasSpecialist", "hasUnit", "hasVegetation", "height", "heightHistory", "hiddenHeight", "hiddenTerrain", "id", "improvement", "improvementBuildTurnsLeft", "improvementBuildTurnsOriginal", "improvementClass", "improvementDevelopTurns", "improvementDynamicText", "improvementFirstSpecialist", "improvementGrammaticalGender", "improvementPillageTurns", "improvementReligionPrereq", "improvementReligionSpread", "improvementUnitTurns", "isAnyCoastLand", "isAnyDirty", "isBorder", "isBoundary", "isCanal", "isCitySiteAny", "isClear", "isFlat", "isFreshWater", "isFreshWaterAccess", "isFreshWaterSource", "isHarvested", "isImprovementUnfinished", "isImprovementUnfinishedOrPillaged", "isLand", "isMinorCity", "isNationSite", "isPassableLand", "isPillaged", "isRegrowing", "isRiver", "isRiverE", "isRiverNE", "isRiverNW", "isRiverSE", "isRiverSW", "isRiverW", "isRoad", "isSaltCoastLand", "isSaltCoastWater", "isSaltWater", "isTribeSite", "isUrban", "isWater", "landSection", "lastTurnOwnerChange", "latitude", "mapElementName", "mapElementNameType", "mapGeneratorData", "metadata", "movementCostExtra", "nation", "nationSite", "numTags", "origUrbanOwner", "owner", "ownerTribe", "regrowthTurn", "resource", "resourceHeightOffset", "riverSE", "riverSW", "riverW", "specialist", "specialistClass", "team", "terrain", "terrainHistory", "terrainStamp", "terrainStampTileID", "terrainUnitDamage", "tradeOutpostIncome", "tribeSite", "vegetation", "vegetationHistory", "x", "y", "hasOrBuildingSpecialists", "improvementCosts", "isImprovementBorderSpreads", "isReligions", "isResourceValids", "isSpecialistCostCitizens"],
+            Self::TurnSummary(_) => &["turn", "characters", "units", "cities", "wonders"],
+            Self::TurnSummaryCharacters(_) => &["eventType", "characterId", "parentIds", "deathReason", "playerId", "newLeaderId", "newHeirId"],
+            Self::TurnSummaryCharacter(_) => &["eventType", "characterId", "parentIds", "deathReason", "playerId", "newLeaderId", "newHeirId"],
+            Self::TurnSummaryUnits(_) => &["eventType", "unitId", "unitType", "playerId", "lastOwnerId", "location", "lastLocation"],
+            Self::TurnSummaryUnit(_) => &["eventType", "unitId", "unitType", "playerId", "lastOwnerId", "location", "lastLocation"],
+            Self::TurnSummaryCities(_) => &["eventType", "cityId", "cityName", "playerId", "oldOwnerId", "newOwnerId", "wasTribe", "location"],
+            Self::TurnSummaryCity(_) => &["eventType", "cityId", "cityName", "playerId", "oldOwnerId", "newOwnerId", "wasTribe", "location"],
+            Self::TurnSummaryWonders(_) => &["eventType", "wonder", "cityId", "playerId", "tribeType"],
+            Self::Tribes(_) => &["tribeType", "isAlive", "isDead", "hasDiplomacy", "hasLeader", "hasReligion", "leaderId", "religion", "allyPlayerId"],
+            Self::Tribe(_) => &["tribeType", "isAlive", "isDead", "hasDiplomacy", "hasLeader", "hasReligion", "leaderId", "religion", "allyPlayerId"],
+            Self::DiplomacyTeams(_) => &["fromTeam", "toTeam", "diplomacy", "isHostile", "isPeace", "hasContact", "warScore", "warState", "conflictTurn"],
+            Self::AlliancesTeams(_) => &["team1", "team2", "allianceType"],
+            Self::DiplomacyTribes(_) => &["playerId", "tribeType", "diplomacy", "isHostile", "isPeace", "hasContact"],
+            Self::AlliancesTribes(_) => &["playerId", "tribeType", "allyPlayerId"],
+            Self::Religions(_) => &["religionType", "isFounded", "headCharacterId", "holyCityId"],
+            Self::Config(_) => &["numTiles", "numPlayers", "numTeams", "turn", "year"],
+            Self::Map(_) => &["numTiles"],
+            Self::Raw(_) => &[],
+        }
+    }
+
+    /// Convert to a JSON value
+    pub fn to_value(&self) -> Result<serde_json::Value> {
+        let value = match self {
+            Self::State(v) => serde_json::to_value(v)?,
+            Self::Players(v) => serde_json::to_value(v)?,
+            Self::Player(v) => serde_json::to_value(v)?,
+            Self::PlayerUnits(v) => serde_json::to_value(v)?,
+            Self::PlayerTechs(v) => serde_json::to_value(v)?,
+            Self::PlayerFamilies(v) => serde_json::to_value(v)?,
+            Self::PlayerReligion(v) => serde_json::to_value(v)?,
+            Self::PlayerGoals(v) => serde_json::to_value(v)?,
+            Self::PlayerDecisions(v) => serde_json::to_value(v)?,
+            Self::PlayerLaws(v) => serde_json::to_value(v)?,
+            Self::PlayerMissions(v) => serde_json::to_value(v)?,
+            Self::PlayerResources(v) => serde_json::to_value(v)?,
+            Self::Cities(v) => serde_json::to_value(v)?,
+            Self::City(v) => serde_json::to_value(v)?,
+            Self::Characters(v) => serde_json::to_value(v)?,
+            Self::Character(v) => serde_json::to_value(v)?,
+            Self::Units(v) => serde_json::to_value(v)?,
+            Self::Unit(v) => serde_json::to_value(v)?,
+            Self::Tiles(v) => serde_json::to_value(v)?,
+            Self::Tile(v) => serde_json::to_value(v)?,
+            Self::TileByCoords(v) => serde_json::to_value(v)?,
+            Self::TurnSummary(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryCharacters(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryCharacter(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryUnits(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryUnit(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryCities(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryCity(v) => serde_json::to_value(v)?,
+            Self::TurnSummaryWonders(v) => serde_json::to_value(v)?,
+            Self::Tribes(v) => serde_json::to_value(v)?,
+            Self::Tribe(v) => serde_json::to_value(v)?,
+            Self::DiplomacyTeams(v) => serde_json::to_value(v)?,
+            Self::AlliancesTeams(v) => serde_json::to_value(v)?,
+            Self::DiplomacyTribes(v) => serde_json::to_value(v)?,
+            Self::AlliancesTribes(v) => serde_json::to_value(v)?,
+            Self::Religions(v) => serde_json::to_value(v)?,
+            Self::Config(v) => serde_json::to_value(v)?,
+            Self::Map(v) => serde_json::to_value(v)?,
+            Self::Raw(v) => v.clone(),
+        };
+        Ok(value)
+    }
+
+    /// Whether this response is a list of entities
+    pub fn is_list(&self) -> bool {
+        match self {
+            Self::Raw(v) => v.is_array(),
+            _ => matches!(
+                self,
+                  Self::Players(_)
+                | Self::PlayerUnits(_)
+                | Self::Cities(_)
+                | Self::Characters(_)
+                | Self::Units(_)
+                | Self::Tiles(_)
+                | Self::TurnSummaryCharacters(_)
+                | Self::TurnSummaryCharacter(_)
+                | Self::TurnSummaryUnits(_)
+                | Self::TurnSummaryUnit(_)
+                | Self::TurnSummaryCities(_)
+                | Self::TurnSummaryCity(_)
+                | Self::TurnSummaryWonders(_)
+                | Self::Tribes(_)
+                | Self::DiplomacyTeams(_)
+                | Self::AlliancesTeams(_)
+                | Self::DiplomacyTribes(_)
+                | Self::AlliancesTribes(_)
+                | Self::Religions(_)
+            ),
+        }
+    }
+
+    /// Run an operation over the items of a list response (no-op for single entities)
+    pub fn apply_list_op(&mut self, op: &impl ListOp) {
+        match self {
+            Self::Players(v) => op.apply(v),
+            Self::PlayerUnits(v) => op.apply(v),
+            Self::Cities(v) => op.apply(v),
+            Self::Characters(v) => op.apply(v),
+            Self::Units(v) => op.apply(v),
+            Self::Tiles(v) => op.apply(v),
+            Self::TurnSummaryCharacters(v) => op.apply(v),
+            Self::TurnSummaryCharacter(v) => op.apply(v),
+            Self::TurnSummaryUnits(v) => op.apply(v),
+            Self::TurnSummaryUnit(v) => op.apply(v),
+            Self::TurnSummaryCities(v) => op.apply(v),
+            Self::TurnSummaryCity(v) => op.apply(v),
+            Self::TurnSummaryWonders(v) => op.apply(v),
+            Self::Tribes(v) => op.apply(v),
+            Self::DiplomacyTeams(v) => op.apply(v),
+            Self::AlliancesTeams(v) => op.apply(v),
+            Self::DiplomacyTribes(v) => op.apply(v),
+            Self::AlliancesTribes(v) => op.apply(v),
+            Self::Religions(v) => op.apply(v),
+            Self::Raw(serde_json::Value::Array(v)) => op.apply(v),
+            _ => {}
+        }
+    }
+
+    /// Write one compact JSON line per entity (a single entity is one line)
+    pub fn write_ndjson(&self, out: &mut impl std::io::Write, fields: Option<&[&str]>) -> Result<()> {
+        match self {
+            Self::State(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Players(v) => write_lines(out, v, fields),
+            Self::Player(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerUnits(v) => write_lines(out, v, fields),
+            Self::PlayerTechs(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerFamilies(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerReligion(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerGoals(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerDecisions(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerLaws(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerMissions(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::PlayerResources(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Cities(v) => write_lines(out, v, fields),
+            Self::City(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Characters(v) => write_lines(out, v, fields),
+            Self::Character(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Units(v) => write_lines(out, v, fields),
+            Self::Unit(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Tiles(v) => write_lines(out, v, fields),
+            Self::Tile(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::TileByCoords(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::TurnSummary(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::TurnSummaryCharacters(v) => write_lines(out, v, fields),
+            Self::TurnSummaryCharacter(v) => write_lines(out, v, fields),
+            Self::TurnSummaryUnits(v) => write_lines(out, v, fields),
+            Self::TurnSummaryUnit(v) => write_lines(out, v, fields),
+            Self::TurnSummaryCities(v) => write_lines(out, v, fields),
+            Self::TurnSummaryCity(v) => write_lines(out, v, fields),
+            Self::TurnSummaryWonders(v) => write_lines(out, v, fields),
+            Self::Tribes(v) => write_lines(out, v, fields),
+            Self::Tribe(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::DiplomacyTeams(v) => write_lines(out, v, fields),
+            Self::AlliancesTeams(v) => write_lines(out, v, fields),
+            Self::DiplomacyTribes(v) => write_lines(out, v, fields),
+            Self::AlliancesTribes(v) => write_lines(out, v, fields),
+            Self::Religions(v) => write_lines(out, v, fields),
+            Self::Config(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Map(v) => write_lines(out, std::slice::from_ref(v), fields),
+            Self::Raw(serde_json::Value::Array(v)) => write_lines(out, v, fields),
+            Self::Raw(v) => write_lines(out, std::slice::from_ref(v), fields),
+        }
+    }
+}
@@ -1,14 +1,19 @@
 //! Table formatters for API types.
 //!
-//! Format functions are auto-generated from openapi.yaml.
+//! Format functions are auto-generated from openapi.yaml. The helpers below are
+//! shared by the generated `--fields` projections.
 //! Regenerate with: cargo run --bin gen_formatters > src/output/table_generated.rs
 
+use tabled::builder::Builder;
+use tabled::settings::Style;
+
+use super::names::{Kind, Names, Needed};
 use crate::client::types;
 
 // === Utility ===
 
 /// Shorten game type strings (e.g., "NATION_ROME" -> "Rome")
-fn shorten_type(s: &str) -> String {
+pub(crate) fn shorten_type(s: &str) -> String {
     s.split('_')
         .skip(1)
         .map(|part| {
@@ -25,5 +30,122 @@ fn shorten_type(s: &str) -> String {
         .join(" ")
 }
 
+/// Render a boolean cell
+fn yes_no(v: bool) -> String {
+    if v { "Yes" } else { "No" }.to_string()
+}
+
+/// Render a player index with its nation (e.g., "0 (Rome)")
+fn resolve_player(names: &Names, id: impl std::fmt::Display) -> String {
+    names.describe(id, Kind::Player)
+}
+
+/// Render a city ID with its name (e.g., "17 (Roma)")
+fn resolve_city(names: &Names, id: impl std::fmt::Display) -> String {
+    names.describe(id, Kind::City)
+}
+
+/// Render a character ID with its name (e.g., "342 (Hannibal)")
+fn resolve_character(names: &Names, id: impl std::fmt::Display) -> String {
+    names.describe(id, Kind::Character)
+}
+
+/// Render a Location cell as "(x, y)"
+fn location_cell(loc: &types::Location) -> String {
+    let coord = |v: Option<i64>| v.map(|v| v.to_string()).unwrap_or_else(|| "?".to_string());
+    format!("({}, {})", coord(loc.x), coord(loc.y))
+}
+
+/// Convert a JSON field name to a column title (e.g., "capitalCityID" -> "Capital City ID")
+fn column_title(field: &str) -> String {
+    let chars: Vec<char> = field.chars().collect();
+    let mut title = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '.' {
+            title.push(' ');
+            continue;
+        }
+        if c.is_uppercase() && i > 0 {
+            let prev_upper = chars[i - 1].is_uppercase();
+            let next_lower = i + 1 < chars.len() && chars[i + 1].is_lowercase();
+            if !prev_upper || next_lower {
+                title.push(' ');
+            }
+        }
+        if i == 0 || chars[i - 1] == '.' {
+            title.extend(c.to_uppercase());
+        } else {
+            title.push(c);
+        }
+    }
+    title
+}
+
+/// Render a field via its generated cell function, falling back to a JSON lookup
+/// for names the spec doesn't define at the top level (e.g., "location.x")
+fn field_cell<T: serde::Serialize>(
+    item: &T,
+    field: &str,
+    names: &Names,
+    cell: fn(&T, &str, &Names) -> Option<String>,
+) -> String {
+    cell(item, field, names).unwrap_or_else(|| {
+        let value = serde_json::to_value(item).unwrap_or_default();
+        match crate::filter::lookup_field(&value, field) {
+            None | Some(serde_json::Value::Null) => String::new(),
+            Some(serde_json::Value::String(s)) => s.clone(),
+            Some(serde_json::Value::Bool(b)) => yes_no(*b),
+            Some(v) => v.to_string(),
+        }
+    })
+}
+
+/// Format a single item as "Field: value" lines for the selected fields
+fn format_fields<T: serde::Serialize>(
+    title: &str,
+    item: &T,
+    fields: &[&str],
+    names: &Names,
+    cell: fn(&T, &str, &Names) -> Option<String>,
+) -> String {
+    let mut lines = vec![title.to_string()];
+    for field in fields {
+        lines.push(format!(
+            "  {}: {}",
+            column_title(field),
+            field_cell(item, field, names, cell)
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Format items as a table with one column per selected field
+fn format_columns<T: serde::Serialize>(
+    items: &[T],
+    fields: &[&str],
+    names: &Names,
+    cell: fn(&T, &str, &Names) -> Option<String>,
+) -> String {
+    let mut builder = Builder::default();
+    builder.push_record(fields.iter().map(|f| column_title(f)));
+    for item in items {
+        builder.push_record(fields.iter().map(|f| field_cell(item, f, names, cell)));
+    }
+    builder.build().with(Style::rounded()).to_string()
+}
+
 // Include auto-generated formatters
 include!("table_generated.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_title() {
+        assert_eq!(column_title("population"), "Population");
+        assert_eq!(column_title("capitalCityID"), "Capital City ID");
+        assert_eq!(column_title("location.x"), "Location X");
+    }
+}
@@ -5,7 +5,7 @@
 // Included via include!() in table.rs
 
 /// Format a single Character for display (all fields)
-pub fn format_character(item: &types::Character) -> String {
+pub fn format_character(item: &types::Character, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("Character".to_string());
@@ -26,13 +26,13 @@ pub fn format_character(item: &types::Character) -> String {
         lines.push(format!("  Archetype: {}", shorten_type(v)));
     }
     if let Some(v) = item.birth_city_id {
-        lines.push(format!("  Birth City ID: {}", v));
+        lines.push(format!("  Birth City ID: {}", resolve_city(names, v)));
     }
     if let Some(v) = item.birth_father_id {
-        lines.push(format!("  Birth Father ID: {}", v));
+        lines.push(format!("  Birth Father ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = item.birth_mother_id {
-        lines.push(format!("  Birth Mother ID: {}", v));
+        lines.push(format!("  Birth Mother ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = item.birth_turn {
         lines.push(format!("  Birth Turn: {}", v));
@@ -50,14 +50,14 @@ pub fn format_character(item: &types::Character) -> String {
         lines.push(format!("  Character Portrait: {}", shorten_type(v)));
     }
     if !item.children_ids.is_empty() {
-        let items: Vec<String> = item.children_ids.iter().map(|v| v.to_string()).collect();
+        let items: Vec<String> = item.children_ids.iter().map(|v| resolve_character(names, v)).collect();
         lines.push(format!("  Children Ids: {}", items.join(", ")));
     }
     if let Some(v) = item.city_agent_id {
-        lines.push(format!("  City Agent ID: {}", v));
+        lines.push(format!("  City Agent ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = item.city_governor_id {
-        lines.push(format!("  City Governor ID: {}", v));
+        lines.push(format!("  City Governor ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.clergy_type {
         lines.push(format!("  Clergy Type: {}", shorten_type(v)));
@@ -106,7 +106,7 @@ pub fn format_character(item: &types::Character) -> String {
         }
     }
     if let Some(v) = item.father_id {
-        lines.push(format!("  Father ID: {}", v));
+        lines.push(format!("  Father ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.first_name {
         lines.push(format!("  First Name: {}", v));
@@ -770,7 +770,7 @@ pub fn format_character(item: &types::Character) -> String {
         lines.push(format!("  Max Weaknesses: {}", v));
     }
     if let Some(v) = item.mother_id {
-        lines.push(format!("  Mother ID: {}", v));
+        lines.push(format!("  Mother ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.name_type {
         lines.push(format!("  Name Type: {}", shorten_type(v)));
@@ -812,10 +812,10 @@ pub fn format_character(item: &types::Character) -> String {
         }
     }
     if let Some(v) = &item.player {
-        lines.push(format!("  Player: {}", v));
+        lines.push(format!("  Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = item.player_int {
-        lines.push(format!("  Player Int: {}", v));
+        lines.push(format!("  Player Int: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.player_opinion_of_character_religion {
         lines.push(format!("  Player Opinion Of Character Religion: {}", shorten_type(v)));
@@ -861,7 +861,7 @@ pub fn format_character(item: &types::Character) -> String {
         lines.push(format!("  Safe Turn: {}", v));
     }
     if !item.spouse_ids.is_empty() {
-        let items: Vec<String> = item.spouse_ids.iter().map(|v| v.to_string()).collect();
+        let items: Vec<String> = item.spouse_ids.iter().map(|v| resolve_character(names, v)).collect();
         lines.push(format!("  Spouse Ids: {}", items.join(", ")));
     }
     if let Some(v) = item.suffix {
@@ -961,20 +961,260 @@ pub fn format_character(item: &types::Character) -> String {
 }
 
 /// Format a list of characters (all fields for each)
-pub fn format_characters(items: &[types::Character]) -> String {
+pub fn format_characters(items: &[types::Character], names: &Names) -> String {
     if items.is_empty() {
         return "No characters found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_character(item))
+        .map(|item| format_character(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one Character field as a table cell (None for fields not in the spec)
+pub fn character_cell(item: &types::Character, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "abdicateTurn" => item.abdicate_turn.map(|v| v.to_string()),
+        "age" => item.age.map(|v| v.to_string()),
+        "ageGroup" => item.age_group.clone(),
+        "ageRemainder" => item.age_remainder.map(|v| v.to_string()),
+        "archetype" => item.archetype.as_deref().map(shorten_type),
+        "birthCityID" => item.birth_city_id.map(|v| resolve_city(names, v)),
+        "birthFatherID" => item.birth_father_id.map(|v| resolve_character(names, v)),
+        "birthMotherID" => item.birth_mother_id.map(|v| resolve_character(names, v)),
+        "birthTurn" => item.birth_turn.map(|v| v.to_string()),
+        "changeJobExtraOpinions" => Some(item.change_job_extra_opinions.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "character" => item.character.clone(),
+        "characterPortrait" => item.character_portrait.as_deref().map(shorten_type),
+        "childrenIds" => Some(item.children_ids.iter().map(|v| resolve_character(names, v)).collect::<Vec<_>>().join(", ")),
+        "cityAgentID" => item.city_agent_id.map(|v| resolve_character(names, v)),
+        "cityGovernorID" => item.city_governor_id.map(|v| resolve_character(names, v)),
+        "clergyType" => item.clergy_type.as_deref().map(shorten_type),
+        "cognomen" => item.cognomen.clone(),
+        "cognomenMinValues" => Some(item.cognomen_min_values.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "council" => item.council.as_deref().map(shorten_type),
+        "courtier" => item.courtier.clone(),
+        "customName" => item.custom_name.clone(),
+        "deadCouncil" => item.dead_council.as_deref().map(shorten_type),
+        "deathReason" => item.death_reason.clone(),
+        "deathTurn" => item.death_turn.map(|v| v.to_string()),
+        "explorerEffectUnits" => Some(item.explorer_effect_units.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "family" => item.family.as_deref().map(shorten_type),
+        "familyClass" => item.family_class.as_deref().map(shorten_type),
+        "familyOpinionCouncils" => Some(item.family_opinion_councils.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "fatherID" => item.father_id.map(|v| resolve_character(names, v)),
+        "firstName" => item.first_name.clone(),
+        "gender" => item.gender.clone(),
+        "genderInt" => item.gender_int.map(|v| v.to_string()),
+        "genderOpposite" => item.gender_opposite.clone(),
+        "generalEffectUnits" => Some(item.general_effect_units.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "hasArchetype" => item.has_archetype.map(yes_no),
+        "hasBirthCity" => item.has_birth_city.map(yes_no),
+        "hasBirthFather" => item.has_birth_father.map(yes_no),
+        "hasBirthMother" => item.has_birth_mother.map(yes_no),
+        "hasCharacter" => item.has_character.map(yes_no),
+        "hasCognomen" => item.has_cognomen.map(yes_no),
+        "hasCouncilPrereqs" => Some(item.has_council_prereqs.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasCustomName" => item.has_custom_name.map(yes_no),
+        "hasDeadCouncil" => item.has_dead_council.map(yes_no),
+        "hasExplorerAll" => item.has_explorer_all.map(yes_no),
+        "hasExplorerPrereq" => item.has_explorer_prereq.map(yes_no),
+        "hasFamily" => item.has_family.map(yes_no),
+        "hasFather" => item.has_father.map(yes_no),
+        "hasGeneralAll" => item.has_general_all.map(yes_no),
+        "hasGeneralPrereq" => item.has_general_prereq.map(yes_no),
+        "hasGovernorAll" => item.has_governor_all.map(yes_no),
+        "hasGovernorPrereq" => item.has_governor_prereq.map(yes_no),
+        "hasItems" => item.has_items.map(yes_no),
+        "hasMother" => item.has_mother.map(yes_no),
+        "hasName" => item.has_name.map(yes_no),
+        "hasNation" => item.has_nation.map(yes_no),
+        "hasNickname" => item.has_nickname.map(yes_no),
+        "hasPlayer" => item.has_player.map(yes_no),
+        "hasRelationshipTypes" => Some(item.has_relationship_types.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasReligion" => item.has_religion.map(yes_no),
+        "hasSpouseAlive" => item.has_spouse_alive.map(yes_no),
+        "hasSpouseForeign" => item.has_spouse_foreign.map(yes_no),
+        "hasSpouseTribe" => item.has_spouse_tribe.map(yes_no),
+        "hasTitle" => item.has_title.map(yes_no),
+        "hasTraitDieProb" => item.has_trait_die_prob.map(yes_no),
+        "hasTraitDoomed" => item.has_trait_doomed.map(yes_no),
+        "hasTraitFormerRegent" => item.has_trait_former_regent.map(yes_no),
+        "hasTraitLockedReligions" => Some(item.has_trait_locked_religions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasTraitNoBirth" => item.has_trait_no_birth.map(yes_no),
+        "hasTraitNoCouncil" => item.has_trait_no_council.map(yes_no),
+        "hasTraitNoCourtier" => item.has_trait_no_courtier.map(yes_no),
+        "hasTraitNoEvents" => item.has_trait_no_events.map(yes_no),
+        "hasTraitNoExplorer" => item.has_trait_no_explorer.map(yes_no),
+        "hasTraitNoFamilyHead" => item.has_trait_no_family_head.map(yes_no),
+        "hasTraitNoGeneral" => item.has_trait_no_general.map(yes_no),
+        "hasTraitNoGovernor" => item.has_trait_no_governor.map(yes_no),
+        "hasTraitNoJob" => item.has_trait_no_job.map(yes_no),
+        "hasTraitNoMarry" => item.has_trait_no_marry.map(yes_no),
+        "hasTraitNoReligion" => item.has_trait_no_religion.map(yes_no),
+        "hasTraitNoReligionHeadNew" => item.has_trait_no_religion_head_new.map(yes_no),
+        "hasTraitNoSuccession" => item.has_trait_no_succession.map(yes_no),
+        "hasTraitRegent" => item.has_trait_regent.map(yes_no),
+        "hasTraitReligionOpinions" => Some(item.has_trait_religion_opinions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasTraitReplacess" => Some(item.has_trait_replacess.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasTraitSuccessionBypass" => item.has_trait_succession_bypass.map(yes_no),
+        "hasTraitSuccessionReturn" => item.has_trait_succession_return.map(yes_no),
+        "hasUnit" => item.has_unit.map(yes_no),
+        "headReligion" => item.head_religion.as_deref().map(shorten_type),
+        "id" => item.id.map(|v| v.to_string()),
+        "isAbdicated" => item.is_abdicated.map(yes_no),
+        "isActivePlayer" => item.is_active_player.map(yes_no),
+        "isAdult" => item.is_adult.map(yes_no),
+        "isAgeless" => item.is_ageless.map(yes_no),
+        "isAlive" => item.is_alive.map(yes_no),
+        "isAnyDirty" => item.is_any_dirty.map(yes_no),
+        "isAttractedToAny" => item.is_attracted_to_any.map(yes_no),
+        "isBisexual" => item.is_bisexual.map(yes_no),
+        "isCityAgent" => item.is_city_agent.map(yes_no),
+        "isCityGovernor" => item.is_city_governor.map(yes_no),
+        "isClergy" => item.is_clergy.map(yes_no),
+        "isCouncil" => item.is_council.map(yes_no),
+        "isCourtier" => item.is_courtier.map(yes_no),
+        "isDead" => item.is_dead.map(yes_no),
+        "isFamilyHead" => item.is_family_head.map(yes_no),
+        "isFamilyHeadSpouse" => item.is_family_head_spouse.map(yes_no),
+        "isFemale" => item.is_female.map(yes_no),
+        "isFertileAge" => item.is_fertile_age.map(yes_no),
+        "isForceInheritanceTreeInclude" => item.is_force_inheritance_tree_include.map(yes_no),
+        "isGay" => item.is_gay.map(yes_no),
+        "isHeir" => item.is_heir.map(yes_no),
+        "isHeirSpouse" => item.is_heir_spouse.map(yes_no),
+        "isHuman" => item.is_human.map(yes_no),
+        "isInfertile" => item.is_infertile.map(yes_no),
+        "isJob" => item.is_job.map(yes_no),
+        "isJobs" => Some(item.is_jobs.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isLeader" => item.is_leader.map(yes_no),
+        "isLeaderAncestor" => item.is_leader_ancestor.map(yes_no),
+        "isLeaderAnySibling" => item.is_leader_any_sibling.map(yes_no),
+        "isLeaderChild" => item.is_leader_child.map(yes_no),
+        "isLeaderDescendant" => item.is_leader_descendant.map(yes_no),
+        "isLeaderOrChildOfLeader" => item.is_leader_or_child_of_leader.map(yes_no),
+        "isLeaderOrDescendant" => item.is_leader_or_descendant.map(yes_no),
+        "isLeaderOrHeir" => item.is_leader_or_heir.map(yes_no),
+        "isLeaderOrSpouse" => item.is_leader_or_spouse.map(yes_no),
+        "isLeaderOrSpouseOrHeir" => item.is_leader_or_spouse_or_heir.map(yes_no),
+        "isLeaderOrSpouseOrSuccessor" => item.is_leader_or_spouse_or_successor.map(yes_no),
+        "isLeaderOrSuccessor" => item.is_leader_or_successor.map(yes_no),
+        "isLeaderSpouse" => item.is_leader_spouse.map(yes_no),
+        "isMale" => item.is_male.map(yes_no),
+        "isOrWasLeader" => item.is_or_was_leader.map(yes_no),
+        "isOrWasLeaderSpouse" => item.is_or_was_leader_spouse.map(yes_no),
+        "isOrWasRegent" => item.is_or_was_regent.map(yes_no),
+        "isRegent" => item.is_regent.map(yes_no),
+        "isReligionAgents" => Some(item.is_religion_agents.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isReligionHead" => item.is_religion_head.map(yes_no),
+        "isRetired" => item.is_retired.map(yes_no),
+        "isRoyal" => item.is_royal.map(yes_no),
+        "isRoyalSpouse" => item.is_royal_spouse.map(yes_no),
+        "isSafe" => item.is_safe.map(yes_no),
+        "isSpouseLeaderDescendant" => item.is_spouse_leader_descendant.map(yes_no),
+        "isSuccessionSkipRegent" => item.is_succession_skip_regent.map(yes_no),
+        "isSuccessor" => item.is_successor.map(yes_no),
+        "isSuccessorParent" => item.is_successor_parent.map(yes_no),
+        "isSuccessorSpouse" => item.is_successor_spouse.map(yes_no),
+        "isTemporary" => item.is_temporary.map(yes_no),
+        "isTraits" => Some(item.is_traits.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTribe" => item.is_tribe.map(yes_no),
+        "isTribeLeader" => item.is_tribe_leader.map(yes_no),
+        "isUnitExplorer" => item.is_unit_explorer.map(yes_no),
+        "isUnitGeneral" => item.is_unit_general.map(yes_no),
+        "isValidChooseName" => item.is_valid_choose_name.map(yes_no),
+        "job" => item.job.as_deref().map(shorten_type),
+        "jobOpinions" => Some(item.job_opinions.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "leaderIndex" => item.leader_index.map(|v| v.to_string()),
+        "leaderTurn" => item.leader_turn.map(|v| v.to_string()),
+        "legitimacy" => item.legitimacy.map(|v| v.to_string()),
+        "level" => item.level.map(|v| v.to_string()),
+        "makeAgentExtraOpinion" => item.make_agent_extra_opinion.map(|v| v.to_string()),
+        "makeCouncilExtraOpinions" => Some(item.make_council_extra_opinions.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "makeExplorerExtraOpinion" => item.make_explorer_extra_opinion.map(|v| v.to_string()),
+        "makeGeneralExtraOpinion" => item.make_general_extra_opinion.map(|v| v.to_string()),
+        "makeGovernorExtraOpinion" => item.make_governor_extra_opinion.map(|v| v.to_string()),
+        "maxStrengths" => item.max_strengths.map(|v| v.to_string()),
+        "maxWeaknesses" => item.max_weaknesses.map(|v| v.to_string()),
+        "motherID" => item.mother_id.map(|v| resolve_character(names, v)),
+        "nameType" => item.name_type.as_deref().map(shorten_type),
+        "nation" => item.nation.as_deref().map(shorten_type),
+        "nationEthnicitys" => Some(item.nation_ethnicitys.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "nationTurn" => item.nation_turn.map(|v| v.to_string()),
+        "nickname" => item.nickname.clone(),
+        "nicknameText" => item.nickname_text.clone(),
+        "numChildren" => item.num_children.map(|v| v.to_string()),
+        "numEventStoryTexts" => item.num_event_story_texts.map(|v| v.to_string()),
+        "numEventStoryTurns" => item.num_event_story_turns.map(|v| v.to_string()),
+        "numSpouses" => item.num_spouses.map(|v| v.to_string()),
+        "opinions" => Some(item.opinions.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "player" => item.player.as_deref().map(|v| resolve_player(names, v)),
+        "playerInt" => item.player_int.map(|v| resolve_player(names, v)),
+        "playerOpinionOfCharacterReligion" => item.player_opinion_of_character_religion.as_deref().map(shorten_type),
+        "randomTraitDelay" => Some(item.random_trait_delay.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "ratingAttackModifierTotal" => item.rating_attack_modifier_total.map(|v| v.to_string()),
+        "ratingCriticalChanceTotal" => item.rating_critical_chance_total.map(|v| v.to_string()),
+        "ratingHarvestModifierTotal" => item.rating_harvest_modifier_total.map(|v| v.to_string()),
+        "ratingUnitXPTotal" => item.rating_unit_xp_total.map(|v| v.to_string()),
+        "ratingYieldRateAgentTotals" => Some(item.rating_yield_rate_agent_totals.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "ratings" => Some(item.ratings.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "regentTurn" => item.regent_turn.map(|v| v.to_string()),
+        "relationships" => Some(format!("{} items", item.relationships.len())),
+        "religion" => item.religion.as_deref().map(shorten_type),
+        "safeTurn" => item.safe_turn.map(|v| v.to_string()),
+        "spouseIds" => Some(item.spouse_ids.iter().map(|v| resolve_character(names, v)).collect::<Vec<_>>().join(", ")),
+        "suffix" => item.suffix.map(|v| v.to_string()),
+        "team" => item.team.clone(),
+        "title" => item.title.clone(),
+        "traitBirthModifier" => item.trait_birth_modifier.map(|v| v.to_string()),
+        "traitNickname" => item.trait_nickname.as_deref().map(shorten_type),
+        "traitProbDelay" => Some(item.trait_prob_delay.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "traitTitle" => item.trait_title.as_deref().map(shorten_type),
+        "traitTurnLengths" => Some(item.trait_turn_lengths.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "traitTurns" => Some(item.trait_turns.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "traits" => Some(item.traits.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "tribe" => item.tribe.as_deref().map(shorten_type),
+        "tribeEthnicitys" => Some(item.tribe_ethnicitys.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitBuildModifier" => item.unit_build_modifier.map(|v| v.to_string()),
+        "unitID" => item.unit_id.map(|v| v.to_string()),
+        "upgradeXPThreshold" => item.upgrade_xp_threshold.map(|v| v.to_string()),
+        "wasFamilyHead" => item.was_family_head.as_deref().map(shorten_type),
+        "wasReligionHead" => item.was_religion_head.as_deref().map(shorten_type),
+        "xp" => item.xp.map(|v| v.to_string()),
+        "yieldRateCourtiers" => Some(item.yield_rate_courtiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldRateLeaderSpouses" => Some(item.yield_rate_leader_spouses.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldRateLeaders" => Some(item.yield_rate_leaders.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldRateSuccessors" => Some(item.yield_rate_successors.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "youngestLivingChildAge" => item.youngest_living_child_age.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single Character showing only the selected fields
+pub fn format_character_fields(item: &types::Character, fields: &[&str], names: &Names) -> String {
+    format_fields("Character", item, fields, names, character_cell)
+}
+
+/// Format a list of characters as a table of the selected fields
+pub fn format_characters_columns(items: &[types::Character], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No characters found".to_string();
+    }
+
+    format_columns(items, fields, names, character_cell)
+}
+
+/// Kinds of IDs a Character table shows, of all fields or only the selected ones
+pub fn character_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("birthCityID", Kind::City), ("birthFatherID", Kind::Character), ("birthMotherID", Kind::Character), ("childrenIds", Kind::Character), ("cityAgentID", Kind::Character), ("cityGovernorID", Kind::Character), ("fatherID", Kind::Character), ("motherID", Kind::Character), ("player", Kind::Player), ("playerInt", Kind::Player), ("spouseIds", Kind::Character)], fields)
+}
+
 /// Format a list of character events (all fields for each)
-pub fn format_character_events(items: &[types::CharacterEvent]) -> String {
+pub fn format_character_events(items: &[types::CharacterEvent], names: &Names) -> String {
     if items.is_empty() {
         return "No character events found".to_string();
     }
@@ -985,7 +1225,7 @@ pub fn format_character_events(items: &[types::CharacterEvent]) -> String {
             let mut lines = Vec::new();
             lines.push("CharacterEvent".to_string());
             if let Some(v) = item.character_id {
-                lines.push(format!("  Character Id: {}", v));
+                lines.push(format!("  Character Id: {}", resolve_character(names, v)));
             }
             if let Some(v) = &item.death_reason {
                 lines.push(format!("  Death Reason: {}", v));
@@ -994,17 +1234,17 @@ pub fn format_character_events(items: &[types::CharacterEvent]) -> String {
                 lines.push(format!("  Event Type: {:?}", v));
             }
             if let Some(v) = item.new_heir_id {
-                lines.push(format!("  New Heir Id: {}", v));
+                lines.push(format!("  New Heir Id: {}", resolve_character(names, v)));
             }
             if let Some(v) = item.new_leader_id {
-                lines.push(format!("  New Leader Id: {}", v));
+                lines.push(format!("  New Leader Id: {}", resolve_character(names, v)));
             }
             if !item.parent_ids.is_empty() {
-                let vals: Vec<String> = item.parent_ids.iter().map(|v| v.to_string()).collect();
+                let vals: Vec<String> = item.parent_ids.iter().map(|v| resolve_character(names, v)).collect();
                 lines.push(format!("  Parent Ids: {}", vals.join(", ")));
             }
             if let Some(v) = item.player_id {
-                lines.push(format!("  Player Id: {}", v));
+                lines.push(format!("  Player Id: {}", resolve_player(names, v)));
             }
             lines.join("\n")
         })
@@ -1012,8 +1252,37 @@ pub fn format_character_events(items: &[types::CharacterEvent]) -> String {
         .join("\n\n")
 }
 
+/// Render one CharacterEvent field as a table cell (None for fields not in the spec)
+pub fn character_event_cell(item: &types::CharacterEvent, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "characterId" => item.character_id.map(|v| resolve_character(names, v)),
+        "deathReason" => item.death_reason.clone(),
+        "eventType" => item.event_type.as_ref().map(|v| format!("{:?}", v)),
+        "newHeirId" => item.new_heir_id.map(|v| resolve_character(names, v)),
+        "newLeaderId" => item.new_leader_id.map(|v| resolve_character(names, v)),
+        "parentIds" => Some(item.parent_ids.iter().map(|v| resolve_character(names, v)).collect::<Vec<_>>().join(", ")),
+        "playerId" => item.player_id.map(|v| resolve_player(names, v)),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of character events as a table of the selected fields
+pub fn format_character_events_columns(items: &[types::CharacterEvent], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No character events found".to_string();
+    }
+
+    format_columns(items, fields, names, character_event_cell)
+}
+
+/// Kinds of IDs a CharacterEvent table shows, of all fields or only the selected ones
+pub fn character_event_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("characterId", Kind::Character), ("newHeirId", Kind::Character), ("newLeaderId", Kind::Character), ("parentIds", Kind::Character), ("playerId", Kind::Player)], fields)
+}
+
 /// Format a single City for display (all fields)
-pub fn format_city(item: &types::City) -> String {
+pub fn format_city(item: &types::City, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("City".to_string());
@@ -1086,7 +1355,7 @@ pub fn format_city(item: &types::City) -> String {
         lines.push(format!("  Buy Tile Count: {}", v));
     }
     if let Some(v) = &item.capture_player {
-        lines.push(format!("  Capture Player: {}", v));
+        lines.push(format!("  Capture Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.capture_team {
         lines.push(format!("  Capture Team: {}", v));
@@ -1140,7 +1409,7 @@ pub fn format_city(item: &types::City) -> String {
         }
     }
     if let Some(v) = item.default_governor_id {
-        lines.push(format!("  Default Governor ID: {}", v));
+        lines.push(format!("  Default Governor ID: {}", resolve_character(names, v)));
     }
     if !item.excess_overflows.is_empty() {
         lines.push("  Excess Overflows:".to_string());
@@ -1158,7 +1427,7 @@ pub fn format_city(item: &types::City) -> String {
         lines.push(format!("  Family Opinion: {}", shorten_type(v)));
     }
     if let Some(v) = &item.first_player {
-        lines.push(format!("  First Player: {}", v));
+        lines.push(format!("  First Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.first_team {
         lines.push(format!("  First Team: {}", v));
@@ -1170,7 +1439,7 @@ pub fn format_city(item: &types::City) -> String {
         lines.push(format!("  Gifted Turn: {}", v));
     }
     if let Some(v) = item.governor_id {
-        lines.push(format!("  Governor ID: {}", v));
+        lines.push(format!("  Governor ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.grammatical_gender {
         lines.push(format!("  Grammatical Gender: {}", v));
@@ -1564,7 +1833,7 @@ pub fn format_city(item: &types::City) -> String {
         }
     }
     if let Some(v) = &item.last_player {
-        lines.push(format!("  Last Player: {}", v));
+        lines.push(format!("  Last Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.last_team {
         lines.push(format!("  Last Team: {}", v));
@@ -1627,10 +1896,10 @@ pub fn format_city(item: &types::City) -> String {
         lines.push(format!("  Passive Heal Damage: {}", v));
     }
     if let Some(v) = &item.player {
-        lines.push(format!("  Player: {}", v));
+        lines.push(format!("  Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = item.player_int {
-        lines.push(format!("  Player Int: {}", v));
+        lines.push(format!("  Player Int: {}", resolve_player(names, v)));
     }
     if let Some(v) = item.population {
         lines.push(format!("  Population: {}", v));
@@ -1822,20 +2091,231 @@ pub fn format_city(item: &types::City) -> String {
 }
 
 /// Format a list of cities (all fields for each)
-pub fn format_cities(items: &[types::City]) -> String {
+pub fn format_cities(items: &[types::City], names: &Names) -> String {
     if items.is_empty() {
         return "No cities found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_city(item))
+        .map(|item| format_city(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one City field as a table cell (None for fields not in the spec)
+pub fn city_cell(item: &types::City, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "activeEffectCity" => Some(item.active_effect_city.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "activeImprovementClassCountAll" => item.active_improvement_class_count_all.map(|v| v.to_string()),
+        "activeImprovementClassCounts" => Some(item.active_improvement_class_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "activeImprovementCountAll" => item.active_improvement_count_all.map(|v| v.to_string()),
+        "activeImprovementCounts" => Some(item.active_improvement_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "adjacentClassCostModifier" => item.adjacent_class_cost_modifier.map(|v| v.to_string()),
+        "assimilateTurns" => item.assimilate_turns.map(|v| v.to_string()),
+        "assimilateYieldModifiers" => Some(item.assimilate_yield_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "baseYieldNets" => Some(item.base_yield_nets.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "buildCount" => item.build_count.map(|v| v.to_string()),
+        "buildQueue" => Some(format!("{} items", item.build_queue.len())),
+        "buildTurnChange" => item.build_turn_change.map(|v| v.to_string()),
+        "buildUnitFamily" => item.build_unit_family.as_deref().map(shorten_type),
+        "buildUnitLevelss" => Some(item.build_unit_levelss.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "buildUnitXPs" => Some(item.build_unit_x_ps.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "buyTileCount" => item.buy_tile_count.map(|v| v.to_string()),
+        "capturePlayer" => item.capture_player.as_deref().map(|v| resolve_player(names, v)),
+        "captureTeam" => item.capture_team.clone(),
+        "captureThreshold" => item.capture_threshold.map(|v| v.to_string()),
+        "captureTribe" => item.capture_tribe.as_deref().map(shorten_type),
+        "captureTurns" => item.capture_turns.map(|v| v.to_string()),
+        "citizens" => item.citizens.map(|v| v.to_string()),
+        "citizensPlusQueuedWithNoProgress" => item.citizens_plus_queued_with_no_progress.map(|v| v.to_string()),
+        "citizensQueue" => item.citizens_queue.map(|v| v.to_string()),
+        "citizensTotal" => item.citizens_total.map(|v| v.to_string()),
+        "citySite" => item.city_site.clone(),
+        "completedBuild" => item.completed_build.clone(),
+        "culture" => item.culture.clone(),
+        "cultureStep" => item.culture_step.map(|v| v.to_string()),
+        "currentBuild" => item.current_build.as_ref().map(|_| "(nested BuildQueueItem)".to_string()),
+        "currentBuildType" => item.current_build_type.as_deref().map(shorten_type),
+        "damage" => item.damage.map(|v| v.to_string()),
+        "damageYieldModifiers" => Some(item.damage_yield_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "defaultGovernorID" => item.default_governor_id.map(|v| resolve_character(names, v)),
+        "excessOverflows" => Some(item.excess_overflows.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "family" => item.family.as_deref().map(shorten_type),
+        "familyClass" => item.family_class.as_deref().map(shorten_type),
+        "familyOpinion" => item.family_opinion.as_deref().map(shorten_type),
+        "firstPlayer" => item.first_player.as_deref().map(|v| resolve_player(names, v)),
+        "firstTeam" => item.first_team.clone(),
+        "foundedTurn" => item.founded_turn.map(|v| v.to_string()),
+        "giftedTurn" => item.gifted_turn.map(|v| v.to_string()),
+        "governorID" => item.governor_id.map(|v| resolve_character(names, v)),
+        "grammaticalGender" => item.grammatical_gender.clone(),
+        "growthCount" => item.growth_count.map(|v| v.to_string()),
+        "happinessLevel" => item.happiness_level.map(|v| v.to_string()),
+        "happinessLevelYieldModifiers" => Some(item.happiness_level_yield_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "hasAnyAgentCharacter" => item.has_any_agent_character.map(yes_no),
+        "hasAutobuildQueued" => item.has_autobuild_queued.map(yes_no),
+        "hasBuild" => item.has_build.map(yes_no),
+        "hasBuildAnyReligionUnitUnlock" => item.has_build_any_religion_unit_unlock.map(yes_no),
+        "hasBuildPlanned" => item.has_build_planned.map(yes_no),
+        "hasCapturePlayer" => item.has_capture_player.map(yes_no),
+        "hasCaptureTribe" => item.has_capture_tribe.map(yes_no),
+        "hasCompletedBuild" => item.has_completed_build.map(yes_no),
+        "hasDefaultGovernor" => item.has_default_governor.map(yes_no),
+        "hasFamily" => item.has_family.map(yes_no),
+        "hasFamilyOpinion" => item.has_family_opinion.map(yes_no),
+        "hasGovernor" => item.has_governor.map(yes_no),
+        "hasLastPlayer" => item.has_last_player.map(yes_no),
+        "hasLastTribe" => item.has_last_tribe.map(yes_no),
+        "hasNation" => item.has_nation.map(yes_no),
+        "hasPlayer" => item.has_player.map(yes_no),
+        "hasProjects" => Some(item.has_projects.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasRebelEffects" => item.has_rebel_effects.map(yes_no),
+        "hasStateReligion" => item.has_state_religion.map(yes_no),
+        "healExtra" => item.heal_extra.map(|v| v.to_string()),
+        "holyCity" => Some(item.holy_city.join(", ")),
+        "hp" => item.hp.map(|v| v.to_string()),
+        "hpMax" => item.hp_max.map(|v| v.to_string()),
+        "hpMaxExtra" => item.hp_max_extra.map(|v| v.to_string()),
+        "hurryCivicsCost" => item.hurry_civics_cost.map(|v| v.to_string()),
+        "hurryCivicsCount" => item.hurry_civics_count.map(|v| v.to_string()),
+        "hurryDiscontent" => item.hurry_discontent.map(|v| v.to_string()),
+        "hurryDiscontentModifier" => item.hurry_discontent_modifier.map(|v| v.to_string()),
+        "hurryMoneyCost" => item.hurry_money_cost.map(|v| v.to_string()),
+        "hurryMoneyCount" => item.hurry_money_count.map(|v| v.to_string()),
+        "hurryOrdersCost" => item.hurry_orders_cost.map(|v| v.to_string()),
+        "hurryOrdersCount" => item.hurry_orders_count.map(|v| v.to_string()),
+        "hurryPopulationCost" => item.hurry_population_cost.map(|v| v.to_string()),
+        "hurryPopulationCount" => item.hurry_population_count.map(|v| v.to_string()),
+        "hurryTrainingCost" => item.hurry_training_cost.map(|v| v.to_string()),
+        "hurryTrainingCount" => item.hurry_training_count.map(|v| v.to_string()),
+        "id" => item.id.map(|v| v.to_string()),
+        "improvementClassCostModifiers" => Some(item.improvement_class_cost_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementClassCounts" => Some(item.improvement_class_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementClassDevelopChanges" => Some(item.improvement_class_develop_changes.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementClassModifiers" => Some(item.improvement_class_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementClasses" => Some(item.improvement_classes.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementCostModifier" => item.improvement_cost_modifier.map(|v| v.to_string()),
+        "improvementCounts" => Some(item.improvement_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementModifiers" => Some(item.improvement_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementRiverModifiers" => Some(item.improvement_river_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvements" => Some(item.improvements.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "isActivePlayer" => item.is_active_player.map(yes_no),
+        "isAnyDirty" => item.is_any_dirty.map(yes_no),
+        "isAutomated" => item.is_automated.map(yes_no),
+        "isBannedReligionSpreads" => Some(item.is_banned_religion_spreads.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isBuildNoHurry" => item.is_build_no_hurry.map(yes_no),
+        "isCapital" => item.is_capital.map(yes_no),
+        "isCapturedCapital" => item.is_captured_capital.map(yes_no),
+        "isConnected" => item.is_connected.map(yes_no),
+        "isDamaged" => item.is_damaged.map(yes_no),
+        "isDefaultGovernorRuling" => item.is_default_governor_ruling.map(yes_no),
+        "isFamilySeat" => item.is_family_seat.map(yes_no),
+        "isGoverned" => item.is_governed.map(yes_no),
+        "isGovernorLeader" => item.is_governor_leader.map(yes_no),
+        "isHuman" => item.is_human.map(yes_no),
+        "isIdle" => item.is_idle.map(yes_no),
+        "isLuxurys" => Some(item.is_luxurys.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isNoImprovementClassMaxUnlocks" => Some(item.is_no_improvement_class_max_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isReligionHolyCityAny" => item.is_religion_holy_city_any.map(yes_no),
+        "isReligionHolyCitys" => Some(item.is_religion_holy_citys.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isReligionSpreadEligibles" => Some(item.is_religion_spread_eligibles.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isReligions" => Some(item.is_religions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isSpecialistNoPrereqs" => Some(item.is_specialist_no_prereqs.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTribe" => item.is_tribe.map(yes_no),
+        "isUnitCapturingCity" => item.is_unit_capturing_city.map(yes_no),
+        "isUnitSpawnPossibles" => Some(item.is_unit_spawn_possibles.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isVulnerable" => item.is_vulnerable.map(yes_no),
+        "isYieldBuildCurrents" => Some(item.is_yield_build_currents.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isYieldRateDefendingAny" => item.is_yield_rate_defending_any.map(yes_no),
+        "lastPlayer" => item.last_player.as_deref().map(|v| resolve_player(names, v)),
+        "lastTeam" => item.last_team.clone(),
+        "lastTribe" => item.last_tribe.as_deref().map(shorten_type),
+        "luxuryCount" => item.luxury_count.map(|v| v.to_string()),
+        "luxuryTradeLengths" => Some(item.luxury_trade_lengths.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "luxuryTurns" => Some(item.luxury_turns.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "makeGovernorCosts" => Some(item.make_governor_costs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "militaryUnitCount" => item.military_unit_count.map(|v| v.to_string()),
+        "militaryUnitFamilyCounts" => Some(item.military_unit_family_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "name" => item.name.clone(),
+        "nameType" => item.name_type.as_deref().map(shorten_type),
+        "nation" => item.nation.as_deref().map(shorten_type),
+        "nextTurnOverflows" => Some(item.next_turn_overflows.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "numRandomPromotions" => item.num_random_promotions.map(|v| v.to_string()),
+        "paganReligionCount" => item.pagan_religion_count.map(|v| v.to_string()),
+        "passiveHealDamage" => item.passive_heal_damage.map(|v| v.to_string()),
+        "player" => item.player.as_deref().map(|v| resolve_player(names, v)),
+        "playerInt" => item.player_int.map(|v| resolve_player(names, v)),
+        "population" => item.population.map(|v| v.to_string()),
+        "projectCostModifier" => item.project_cost_modifier.map(|v| v.to_string()),
+        "projectCounts" => Some(item.project_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "projects" => Some(item.projects.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "raidedTurn" => item.raided_turn.map(|v| v.to_string()),
+        "rangeChange" => item.range_change.map(|v| v.to_string()),
+        "regrowthModifier" => item.regrowth_modifier.map(|v| v.to_string()),
+        "religionCount" => item.religion_count.map(|v| v.to_string()),
+        "religionNonStateCount" => item.religion_non_state_count.map(|v| v.to_string()),
+        "religions" => Some(item.religions.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "saltWaterTiles" => item.salt_water_tiles.map(|v| v.to_string()),
+        "specialistCostModifier" => item.specialist_cost_modifier.map(|v| v.to_string()),
+        "specialistCostModifiers" => Some(item.specialist_cost_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "specialistCount" => item.specialist_count.map(|v| v.to_string()),
+        "specialistProducedCount" => item.specialist_produced_count.map(|v| v.to_string()),
+        "specialistRuralTrainTimeModifier" => item.specialist_rural_train_time_modifier.map(|v| v.to_string()),
+        "specialistUrbanCostModifier" => item.specialist_urban_cost_modifier.map(|v| v.to_string()),
+        "specialistUrbanTrainTimeModifier" => item.specialist_urban_train_time_modifier.map(|v| v.to_string()),
+        "stateReligionUnitTraitTrainModifiers" => Some(item.state_religion_unit_trait_train_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "strengthModifier" => item.strength_modifier.map(|v| v.to_string()),
+        "team" => item.team.clone(),
+        "territoryTileCount" => item.territory_tile_count.map(|v| v.to_string()),
+        "territoryTileCounts" => Some(item.territory_tile_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "territoryTiles" => Some(item.territory_tiles.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")),
+        "tileID" => item.tile_id.map(|v| v.to_string()),
+        "tradeNetwork" => item.trade_network.map(|v| v.to_string()),
+        "tribe" => item.tribe.as_deref().map(shorten_type),
+        "unitCostModifiers" => Some(item.unit_cost_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitProductionCounts" => Some(item.unit_production_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitTrainModifiers" => Some(item.unit_train_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitTraitCostModifiers" => Some(item.unit_trait_cost_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitTraitTrainModifiers" => Some(item.unit_trait_train_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "urbanBuildTurnChange" => item.urban_build_turn_change.map(|v| v.to_string()),
+        "urbanSpecialistCount" => item.urban_specialist_count.map(|v| v.to_string()),
+        "urbanTiles" => item.urban_tiles.map(|v| v.to_string()),
+        "x" => item.x.map(|v| v.to_string()),
+        "y" => item.y.map(|v| v.to_string()),
+        "yieldLevels" => Some(item.yield_levels.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldOverflows" => Some(item.yield_overflows.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldProgresss" => Some(item.yield_progresss.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldThresholdWholes" => Some(item.yield_threshold_wholes.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldThresholds" => Some(item.yield_thresholds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldTurnsLefts" => Some(item.yield_turns_lefts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yields" => Some(item.yields.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single City showing only the selected fields
+pub fn format_city_fields(item: &types::City, fields: &[&str], names: &Names) -> String {
+    format_fields("City", item, fields, names, city_cell)
+}
+
+/// Format a list of cities as a table of the selected fields
+pub fn format_cities_columns(items: &[types::City], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No cities found".to_string();
+    }
+
+    format_columns(items, fields, names, city_cell)
+}
+
+/// Kinds of IDs a City table shows, of all fields or only the selected ones
+pub fn city_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("capturePlayer", Kind::Player), ("defaultGovernorID", Kind::Character), ("firstPlayer", Kind::Player), ("governorID", Kind::Character), ("lastPlayer", Kind::Player), ("player", Kind::Player), ("playerInt", Kind::Player)], fields)
+}
+
 /// Format a list of city events (all fields for each)
-pub fn format_city_events(items: &[types::CityEvent]) -> String {
+pub fn format_city_events(items: &[types::CityEvent], names: &Names) -> String {
     if items.is_empty() {
         return "No city events found".to_string();
     }
@@ -1846,7 +2326,7 @@ pub fn format_city_events(items: &[types::CityEvent]) -> String {
             let mut lines = Vec::new();
             lines.push("CityEvent".to_string());
             if let Some(v) = item.city_id {
-                lines.push(format!("  City Id: {}", v));
+                lines.push(format!("  City Id: {}", resolve_city(names, v)));
             }
             if let Some(v) = &item.city_name {
                 lines.push(format!("  City Name: {}", v));
@@ -1855,13 +2335,13 @@ pub fn format_city_events(items: &[types::CityEvent]) -> String {
                 lines.push(format!("  Event Type: {:?}", v));
             }
                         if let Some(v) = item.new_owner_id {
-                lines.push(format!("  New Owner Id: {}", v));
+                lines.push(format!("  New Owner Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = item.old_owner_id {
-                lines.push(format!("  Old Owner Id: {}", v));
+                lines.push(format!("  Old Owner Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = item.player_id {
-                lines.push(format!("  Player Id: {}", v));
+                lines.push(format!("  Player Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = item.was_tribe {
                 if v {
@@ -1874,8 +2354,38 @@ pub fn format_city_events(items: &[types::CityEvent]) -> String {
         .join("\n\n")
 }
 
+/// Render one CityEvent field as a table cell (None for fields not in the spec)
+pub fn city_event_cell(item: &types::CityEvent, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "cityId" => item.city_id.map(|v| resolve_city(names, v)),
+        "cityName" => item.city_name.clone(),
+        "eventType" => item.event_type.as_ref().map(|v| format!("{:?}", v)),
+        "location" => item.location.as_ref().map(location_cell),
+        "newOwnerId" => item.new_owner_id.map(|v| resolve_player(names, v)),
+        "oldOwnerId" => item.old_owner_id.map(|v| resolve_player(names, v)),
+        "playerId" => item.player_id.map(|v| resolve_player(names, v)),
+        "wasTribe" => item.was_tribe.map(yes_no),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of city events as a table of the selected fields
+pub fn format_city_events_columns(items: &[types::CityEvent], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No city events found".to_string();
+    }
+
+    format_columns(items, fields, names, city_event_cell)
+}
+
+/// Kinds of IDs a CityEvent table shows, of all fields or only the selected ones
+pub fn city_event_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("cityId", Kind::City), ("newOwnerId", Kind::Player), ("oldOwnerId", Kind::Player), ("playerId", Kind::Player)], fields)
+}
+
 /// Format a single GameConfig for display (all fields)
-pub fn format_config(item: &types::GameConfig) -> String {
+pub fn format_config(item: &types::GameConfig, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("GameConfig".to_string());
@@ -1899,8 +2409,31 @@ pub fn format_config(item: &types::GameConfig) -> String {
     lines.join("\n")
 }
 
+/// Render one GameConfig field as a table cell (None for fields not in the spec)
+pub fn game_config_cell(item: &types::GameConfig, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "numPlayers" => item.num_players.map(|v| v.to_string()),
+        "numTeams" => item.num_teams.map(|v| v.to_string()),
+        "numTiles" => item.num_tiles.map(|v| v.to_string()),
+        "turn" => item.turn.map(|v| v.to_string()),
+        "year" => item.year.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single GameConfig showing only the selected fields
+pub fn format_config_fields(item: &types::GameConfig, fields: &[&str], names: &Names) -> String {
+    format_fields("GameConfig", item, fields, names, game_config_cell)
+}
+
+/// Kinds of IDs a GameConfig table shows, of all fields or only the selected ones
+pub fn game_config_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single GameState for display (all fields)
-pub fn format_state(item: &types::GameState) -> String {
+pub fn format_state(item: &types::GameState, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("GameState".to_string());
@@ -1912,7 +2445,7 @@ pub fn format_state(item: &types::GameState) -> String {
         lines.push(format!("  Cities: {} items", item.cities.len()));
     }
     if let Some(v) = item.current_player {
-        lines.push(format!("  Current Player: {}", v));
+        lines.push(format!("  Current Player: {}", resolve_player(names, v)));
     }
     if !item.players.is_empty() {
         lines.push(format!("  Players: {} items", item.players.len()));
@@ -1945,8 +2478,38 @@ pub fn format_state(item: &types::GameState) -> String {
     lines.join("\n")
 }
 
+/// Render one GameState field as a table cell (None for fields not in the spec)
+pub fn game_state_cell(item: &types::GameState, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "characters" => Some(format!("{} items", item.characters.len())),
+        "cities" => Some(format!("{} items", item.cities.len())),
+        "currentPlayer" => item.current_player.map(|v| resolve_player(names, v)),
+        "players" => Some(format!("{} items", item.players.len())),
+        "teamAlliances" => Some(format!("{} items", item.team_alliances.len())),
+        "teamDiplomacy" => Some(format!("{} items", item.team_diplomacy.len())),
+        "tribeAlliances" => Some(format!("{} items", item.tribe_alliances.len())),
+        "tribeDiplomacy" => Some(format!("{} items", item.tribe_diplomacy.len())),
+        "tribes" => Some(format!("{} items", item.tribes.len())),
+        "turn" => item.turn.map(|v| v.to_string()),
+        "units" => Some(format!("{} items", item.units.len())),
+        "year" => item.year.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single GameState showing only the selected fields
+pub fn format_state_fields(item: &types::GameState, fields: &[&str], names: &Names) -> String {
+    format_fields("GameState", item, fields, names, game_state_cell)
+}
+
+/// Kinds of IDs a GameState table shows, of all fields or only the selected ones
+pub fn game_state_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("currentPlayer", Kind::Player)], fields)
+}
+
 /// Format a single MapMetadata for display (all fields)
-pub fn format_map(item: &types::MapMetadata) -> String {
+pub fn format_map(item: &types::MapMetadata, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("MapMetadata".to_string());
@@ -1958,8 +2521,27 @@ pub fn format_map(item: &types::MapMetadata) -> String {
     lines.join("\n")
 }
 
+/// Render one MapMetadata field as a table cell (None for fields not in the spec)
+pub fn map_metadata_cell(item: &types::MapMetadata, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "numTiles" => item.num_tiles.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single MapMetadata showing only the selected fields
+pub fn format_map_fields(item: &types::MapMetadata, fields: &[&str], names: &Names) -> String {
+    format_fields("MapMetadata", item, fields, names, map_metadata_cell)
+}
+
+/// Kinds of IDs a MapMetadata table shows, of all fields or only the selected ones
+pub fn map_metadata_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single Player for display (all fields)
-pub fn format_player(item: &types::Player) -> String {
+pub fn format_player(item: &types::Player, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("Player".to_string());
@@ -1998,10 +2580,10 @@ pub fn format_player(item: &types::Player) -> String {
         lines.push(format!("  Buy Tile Count: {}", v));
     }
     if let Some(v) = item.capital_city_id {
-        lines.push(format!("  Capital City ID: {}", v));
+        lines.push(format!("  Capital City ID: {}", resolve_city(names, v)));
     }
     if let Some(v) = item.chosen_heir_id {
-        lines.push(format!("  Chosen Heir ID: {}", v));
+        lines.push(format!("  Chosen Heir ID: {}", resolve_character(names, v)));
     }
     if !item.cities.is_empty() {
         let items: Vec<String> = item.cities.iter().map(|v| v.to_string()).collect();
@@ -2104,7 +2686,7 @@ pub fn format_player(item: &types::Player) -> String {
         lines.push(format!("  Fixed Num Cities: {}", v));
     }
     if let Some(v) = item.founder_id {
-        lines.push(format!("  Founder ID: {}", v));
+        lines.push(format!("  Founder ID: {}", resolve_character(names, v)));
     }
     if !item.gift_yield_quantitys.is_empty() {
         lines.push("  Gift Yield Quantitys:".to_string());
@@ -2831,20 +3413,20 @@ pub fn format_player(item: &types::Player) -> String {
         lines.push(format!("  Orders Left: {}", v));
     }
     if let Some(v) = item.original_capital_city_id {
-        lines.push(format!("  Original Capital City ID: {}", v));
+        lines.push(format!("  Original Capital City ID: {}", resolve_city(names, v)));
     }
     if !item.ping_tiles.is_empty() {
         let items: Vec<String> = item.ping_tiles.iter().map(|v| v.to_string()).collect();
         lines.push(format!("  Ping Tiles: {}", items.join(", ")));
     }
     if let Some(v) = &item.player {
-        lines.push(format!("  Player: {}", v));
+        lines.push(format!("  Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.player_email {
         lines.push(format!("  Player Email: {}", v));
     }
     if let Some(v) = item.player_int {
-        lines.push(format!("  Player Int: {}", v));
+        lines.push(format!("  Player Int: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.popup_tech_discovered {
         lines.push(format!("  Popup Tech Discovered: {}", shorten_type(v)));
@@ -3065,20 +3647,264 @@ pub fn format_player(item: &types::Player) -> String {
 }
 
 /// Format a list of players (all fields for each)
-pub fn format_players(items: &[types::Player]) -> String {
+pub fn format_players(items: &[types::Player], names: &Names) -> String {
     if items.is_empty() {
         return "No players found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_player(item))
+        .map(|item| format_player(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one Player field as a table cell (None for fields not in the spec)
+pub fn player_cell(item: &types::Player, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "activeImprovementClassCounts" => Some(item.active_improvement_class_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "activeImprovementCounts" => Some(item.active_improvement_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "adoptReligionCosts" => Some(item.adopt_religion_costs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "aiAutoPlayToTurn" => item.ai_auto_play_to_turn.map(|v| v.to_string()),
+        "ambitionDecisionss" => Some(item.ambition_decisionss.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "borderVisibility" => item.border_visibility.map(|v| v.to_string()),
+        "buyTileCount" => item.buy_tile_count.map(|v| v.to_string()),
+        "capitalCityID" => item.capital_city_id.map(|v| resolve_city(names, v)),
+        "chosenHeirID" => item.chosen_heir_id.map(|v| resolve_character(names, v)),
+        "cities" => Some(item.cities.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")),
+        "consumptionModifier" => item.consumption_modifier.map(|v| v.to_string()),
+        "convertOrdersCost" => item.convert_orders_cost.map(|v| v.to_string()),
+        "convertOrdersYield" => item.convert_orders_yield.as_deref().map(shorten_type),
+        "councilCharacters" => Some(item.council_characters.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "crest" => item.crest.clone(),
+        "customName" => item.custom_name.clone(),
+        "customReminder" => item.custom_reminder.clone(),
+        "development" => item.development.as_deref().map(shorten_type),
+        "difficulty" => item.difficulty.as_deref().map(shorten_type),
+        "difficultyMode" => item.difficulty_mode.as_deref().map(shorten_type),
+        "dynasty" => item.dynasty.clone(),
+        "effectCityRebelProbs" => Some(item.effect_city_rebel_probs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "effectPlayerCounts" => Some(item.effect_player_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "extraLuxuryCounts" => Some(item.extra_luxury_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "families" => Some(item.families.join(", ")),
+        "familyControls" => Some(item.family_controls.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "familyHeadIDs" => Some(item.family_head_i_ds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "familyOpinionRates" => Some(item.family_opinion_rates.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "familySeatCityIDs" => Some(item.family_seat_city_i_ds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "familySupremacy" => item.family_supremacy.as_deref().map(shorten_type),
+        "familyTurnsNoLeaders" => Some(item.family_turns_no_leaders.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "firstPopup" => item.first_popup.clone(),
+        "fixedNumCities" => item.fixed_num_cities.map(|v| v.to_string()),
+        "founderID" => item.founder_id.map(|v| resolve_character(names, v)),
+        "giftYieldQuantitys" => Some(item.gift_yield_quantitys.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "goalStartedCounts" => Some(item.goal_started_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "harvestModifier" => item.harvest_modifier.map(|v| v.to_string()),
+        "hasAchievementLocked" => item.has_achievement_locked.map(yes_no),
+        "hasAchievementUnlocked" => item.has_achievement_unlocked.map(yes_no),
+        "hasActiveCharacters" => Some(item.has_active_characters.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasAutomatedUnits" => item.has_automated_units.map(yes_no),
+        "hasCapitalCity" => item.has_capital_city.map(yes_no),
+        "hasChosenHeir" => item.has_chosen_heir.map(yes_no),
+        "hasCouncilCharacters" => Some(item.has_council_characters.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasCustomName" => item.has_custom_name.map(yes_no),
+        "hasDecisions" => item.has_decisions.map(yes_no),
+        "hasDynasty" => item.has_dynasty.map(yes_no),
+        "hasFamilyHeads" => Some(item.has_family_heads.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasFamilyOpinions" => Some(item.has_family_opinions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasFamilySeatCitys" => Some(item.has_family_seat_citys.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasFounder" => item.has_founder.map(yes_no),
+        "hasHeightClaimeds" => Some(item.has_height_claimeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasHeir" => item.has_heir.map(yes_no),
+        "hasLeader" => item.has_leader.map(yes_no),
+        "hasLeaderFamily" => item.has_leader_family.map(yes_no),
+        "hasLeaderSpouseAlive" => item.has_leader_spouse_alive.map(yes_no),
+        "hasNation" => item.has_nation.map(yes_no),
+        "hasReligionOpinionHistorys" => Some(item.has_religion_opinion_historys.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasReligions" => Some(item.has_religions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasScenarioCompleted" => item.has_scenario_completed.map(yes_no),
+        "hasStateReligion" => item.has_state_religion.map(yes_no),
+        "hasTerrainClaimeds" => Some(item.has_terrain_claimeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasTraitCharacters" => Some(item.has_trait_characters.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasTurnSummary" => item.has_turn_summary.map(yes_no),
+        "hasVegetationClaimeds" => Some(item.has_vegetation_claimeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "ignoreReminders" => item.ignore_reminders.clone(),
+        "improvementClassCounts" => Some(item.improvement_class_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementCounts" => Some(item.improvement_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementLawsRequireds" => Some(item.improvement_laws_requireds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "index" => item.index.map(|v| v.to_string()),
+        "isAIAutoPlay" => item.is_ai_auto_play.map(yes_no),
+        "isActive" => item.is_active.map(yes_no),
+        "isActiveLaws" => Some(item.is_active_laws.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isAlive" => item.is_alive.map(yes_no),
+        "isAnyDirty" => item.is_any_dirty.map(yes_no),
+        "isAnyPickLater" => item.is_any_pick_later.map(yes_no),
+        "isAnyTechAvailable" => item.is_any_tech_available.map(yes_no),
+        "isBuyTileUnlocks" => Some(item.is_buy_tile_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isCityMaxReached" => item.is_city_max_reached.map(yes_no),
+        "isCityYieldBuildCurrents" => Some(item.is_city_yield_build_currents.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isCompletedGameSaved" => item.is_completed_game_saved.map(yes_no),
+        "isConnectedForeignUnlocks" => Some(item.is_connected_foreign_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isConvertedLegitimacy" => item.is_converted_legitimacy.map(yes_no),
+        "isCouncilUnlocks" => Some(item.is_council_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isDead" => item.is_dead.map(yes_no),
+        "isFamilyReligions" => Some(item.is_family_religions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isFamilyStarteds" => Some(item.is_family_starteds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isFirstTurnProcessing" => item.is_first_turn_processing.map(yes_no),
+        "isFounded" => item.is_founded.map(yes_no),
+        "isHideUnitUnlocks" => Some(item.is_hide_unit_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isHuman" => item.is_human.map(yes_no),
+        "isHumanOriginal" => item.is_human_original.map(yes_no),
+        "isImprovementNotValids" => Some(item.is_improvement_not_valids.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isImprovementUnlockeds" => Some(item.is_improvement_unlockeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isInitialized" => item.is_initialized.map(yes_no),
+        "isLatestUpgrades" => Some(item.is_latest_upgrades.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isLeaderAlive" => item.is_leader_alive.map(yes_no),
+        "isMinTreatyTurnss" => Some(item.is_min_treaty_turnss.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isNationImprovements" => Some(item.is_nation_improvements.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isNoFamilyRestrictionJobUnlocks" => Some(item.is_no_family_restriction_job_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isNoSellPenaltyYieldUnlocks" => Some(item.is_no_sell_penalty_yield_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isPlaying" => item.is_playing.map(yes_no),
+        "isProcessingTurn" => item.is_processing_turn.map(yes_no),
+        "isProcessingTurnStart" => item.is_processing_turn_start.map(yes_no),
+        "isProjectNotValids" => Some(item.is_project_not_valids.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isResearching" => item.is_researching.map(yes_no),
+        "isSpreadBordersUnlocks" => Some(item.is_spread_borders_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isStartCityNumberFlexible" => item.is_start_city_number_flexible.map(yes_no),
+        "isSurrendered" => item.is_surrendered.map(yes_no),
+        "isTeamHuman" => item.is_team_human.map(yes_no),
+        "isTechAcquireds" => Some(item.is_tech_acquireds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechAnyDecks" => Some(item.is_tech_any_decks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechAvailables" => Some(item.is_tech_availables.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechHides" => Some(item.is_tech_hides.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechLockeds" => Some(item.is_tech_lockeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechNotValids" => Some(item.is_tech_not_valids.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechPassedCurrents" => Some(item.is_tech_passed_currents.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechPasseds" => Some(item.is_tech_passeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechRedraw" => item.is_tech_redraw.map(yes_no),
+        "isTechTargets" => Some(item.is_tech_targets.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTechTrasheds" => Some(item.is_tech_trasheds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTurnEnded" => item.is_turn_ended.map(yes_no),
+        "isTurnSummaryReady" => item.is_turn_summary_ready.map(yes_no),
+        "isTutorial" => item.is_tutorial.map(yes_no),
+        "isUnitNotValids" => Some(item.is_unit_not_valids.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isUnitObsoletes" => Some(item.is_unit_obsoletes.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isUnitUnlockeds" => Some(item.is_unit_unlockeds.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isUsedOffensive" => item.is_used_offensive.map(yes_no),
+        "isVersionMismatch" => item.is_version_mismatch.map(yes_no),
+        "isWaterUnitUnlocks" => Some(item.is_water_unit_unlocks.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isWorldOrOurPaganReligions" => Some(item.is_world_or_our_pagan_religions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "jobOpinionRates" => Some(item.job_opinion_rates.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "language" => item.language.clone(),
+        "lastDoTurn" => item.last_do_turn.map(|v| v.to_string()),
+        "leaderFamily" => item.leader_family.as_deref().map(shorten_type),
+        "legitimacy" => item.legitimacy.map(|v| v.to_string()),
+        "livingCourtiersYields" => Some(item.living_courtiers_yields.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "livingRoyalsYields" => Some(item.living_royals_yields.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "luxuryCounts" => Some(item.luxury_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "minStartingCities" => item.min_starting_cities.map(|v| v.to_string()),
+        "missionCooldownTurnsLefts" => Some(item.mission_cooldown_turns_lefts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "missionModifier" => item.mission_modifier.map(|v| v.to_string()),
+        "missionStartedTurns" => Some(item.mission_started_turns.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "money" => item.money.map(|v| v.to_string()),
+        "moneyWhole" => item.money_whole.map(|v| v.to_string()),
+        "nation" => item.nation.as_deref().map(shorten_type),
+        "numCities" => item.num_cities.map(|v| v.to_string()),
+        "numDecisions" => item.num_decisions.map(|v| v.to_string()),
+        "numEmptySites" => item.num_empty_sites.map(|v| v.to_string()),
+        "numFamilies" => item.num_families.map(|v| v.to_string()),
+        "numGameLoads" => item.num_game_loads.map(|v| v.to_string()),
+        "numGoals" => item.num_goals.map(|v| v.to_string()),
+        "numMemories" => item.num_memories.map(|v| v.to_string()),
+        "numMissions" => item.num_missions.map(|v| v.to_string()),
+        "numPopups" => item.num_popups.map(|v| v.to_string()),
+        "numStartingTiles" => item.num_starting_tiles.map(|v| v.to_string()),
+        "numTiles" => item.num_tiles.map(|v| v.to_string()),
+        "numTrades" => item.num_trades.map(|v| v.to_string()),
+        "numTributes" => item.num_tributes.map(|v| v.to_string()),
+        "numUnits" => item.num_units.map(|v| v.to_string()),
+        "onlineID" => item.online_id.clone(),
+        "ordersLeft" => item.orders_left.map(|v| v.to_string()),
+        "originalCapitalCityID" => item.original_capital_city_id.map(|v| resolve_city(names, v)),
+        "pingTiles" => Some(item.ping_tiles.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")),
+        "player" => item.player.as_deref().map(|v| resolve_player(names, v)),
+        "playerEmail" => item.player_email.clone(),
+        "playerInt" => item.player_int.map(|v| resolve_player(names, v)),
+        "popupTechDiscovered" => item.popup_tech_discovered.as_deref().map(shorten_type),
+        "projectsProduceds" => Some(item.projects_produceds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "questsFaileds" => Some(item.quests_faileds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "rates" => Some(item.rates.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "recruitLegitimacy" => item.recruit_legitimacy.map(|v| v.to_string()),
+        "religionCounts" => Some(item.religion_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "religionOpinionRates" => Some(item.religion_opinion_rates.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "reloads" => item.reloads.map(|v| v.to_string()),
+        "resourceRevealeds" => Some(item.resource_revealeds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "saltWaterTileCount" => item.salt_water_tile_count.map(|v| v.to_string()),
+        "sellPenaltyModifier" => item.sell_penalty_modifier.map(|v| v.to_string()),
+        "startLawCosts" => Some(item.start_law_costs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "startTurnCities" => item.start_turn_cities.map(|v| v.to_string()),
+        "startingTileID" => item.starting_tile_id.map(|v| v.to_string()),
+        "stateReligion" => item.state_religion.as_deref().map(shorten_type),
+        "stateReligionChangeCount" => item.state_religion_change_count.map(|v| v.to_string()),
+        "stateReligionSpreadChange" => item.state_religion_spread_change.map(|v| v.to_string()),
+        "stockpiles" => Some(item.stockpiles.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "succession" => Some(item.succession.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")),
+        "successionCount" => item.succession_count.map(|v| v.to_string()),
+        "successionGender" => item.succession_gender.clone(),
+        "successionOrder" => item.succession_order.clone(),
+        "team" => item.team.clone(),
+        "techCostModifier" => item.tech_cost_modifier.map(|v| v.to_string()),
+        "techCostWholes" => Some(item.tech_cost_wholes.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "techCosts" => Some(item.tech_costs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "techProgresss" => Some(item.tech_progresss.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "techResearching" => item.tech_researching.as_deref().map(shorten_type),
+        "techTurnDiscovereds" => Some(item.tech_turn_discovereds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "techsAvailableChange" => item.techs_available_change.map(|v| v.to_string()),
+        "theologyEstablishedCounts" => Some(item.theology_established_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "timeStockpile" => item.time_stockpile.map(|v| v.to_string()),
+        "tradeOutpostYieldTotals" => Some(item.trade_outpost_yield_totals.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "trainingOrderModifier" => item.training_order_modifier.map(|v| v.to_string()),
+        "tribeFatigueLimitChange" => item.tribe_fatigue_limit_change.map(|v| v.to_string()),
+        "tribeMercenaryCount" => item.tribe_mercenary_count.map(|v| v.to_string()),
+        "turnSummary" => Some(item.turn_summary.join(", ")),
+        "turnTimeNeeded" => item.turn_time_needed.map(|v| v.to_string()),
+        "unitTraitConsumptionModifiers" => Some(item.unit_trait_consumption_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitsProducedTurns" => Some(item.units_produced_turns.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "unitsProduceds" => Some(item.units_produceds.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "visionChange" => item.vision_change.map(|v| v.to_string()),
+        "wonderModifier" => item.wonder_modifier.map(|v| v.to_string()),
+        "worldReligionCount" => item.world_religion_count.map(|v| v.to_string()),
+        "worldReligionSpreadChange" => item.world_religion_spread_change.map(|v| v.to_string()),
+        "yieldStockpileWholes" => Some(item.yield_stockpile_wholes.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldStockpiles" => Some(item.yield_stockpiles.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldTotals" => Some(item.yield_totals.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "yieldUpkeepModifier" => item.yield_upkeep_modifier.map(|v| v.to_string()),
+        "yieldUpkeepNets" => Some(item.yield_upkeep_nets.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single Player showing only the selected fields
+pub fn format_player_fields(item: &types::Player, fields: &[&str], names: &Names) -> String {
+    format_fields("Player", item, fields, names, player_cell)
+}
+
+/// Format a list of players as a table of the selected fields
+pub fn format_players_columns(items: &[types::Player], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No players found".to_string();
+    }
+
+    format_columns(items, fields, names, player_cell)
+}
+
+/// Kinds of IDs a Player table shows, of all fields or only the selected ones
+pub fn player_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("capitalCityID", Kind::City), ("chosenHeirID", Kind::Character), ("founderID", Kind::Character), ("originalCapitalCityID", Kind::City), ("player", Kind::Player), ("playerInt", Kind::Player)], fields)
+}
+
 /// Format a single PlayerDecisions for display (all fields)
-pub fn format_player_decisions(item: &types::PlayerDecisions) -> String {
+pub fn format_player_decisions(item: &types::PlayerDecisions, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerDecisions".to_string());
@@ -3095,8 +3921,28 @@ pub fn format_player_decisions(item: &types::PlayerDecisions) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerDecisions field as a table cell (None for fields not in the spec)
+pub fn player_decisions_cell(item: &types::PlayerDecisions, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "decisions" => Some(format!("{} items", item.decisions.len())),
+        "hasDecisions" => item.has_decisions.map(yes_no),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerDecisions showing only the selected fields
+pub fn format_player_decisions_fields(item: &types::PlayerDecisions, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerDecisions", item, fields, names, player_decisions_cell)
+}
+
+/// Kinds of IDs a PlayerDecisions table shows, of all fields or only the selected ones
+pub fn player_decisions_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerFamilies for display (all fields)
-pub fn format_player_families(item: &types::PlayerFamilies) -> String {
+pub fn format_player_families(item: &types::PlayerFamilies, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerFamilies".to_string());
@@ -3108,8 +3954,27 @@ pub fn format_player_families(item: &types::PlayerFamilies) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerFamilies field as a table cell (None for fields not in the spec)
+pub fn player_families_cell(item: &types::PlayerFamilies, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "families" => Some(format!("{} items", item.families.len())),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerFamilies showing only the selected fields
+pub fn format_player_families_fields(item: &types::PlayerFamilies, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerFamilies", item, fields, names, player_families_cell)
+}
+
+/// Kinds of IDs a PlayerFamilies table shows, of all fields or only the selected ones
+pub fn player_families_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerGoals for display (all fields)
-pub fn format_player_goals(item: &types::PlayerGoals) -> String {
+pub fn format_player_goals(item: &types::PlayerGoals, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerGoals".to_string());
@@ -3121,8 +3986,27 @@ pub fn format_player_goals(item: &types::PlayerGoals) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerGoals field as a table cell (None for fields not in the spec)
+pub fn player_goals_cell(item: &types::PlayerGoals, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "goals" => Some(format!("{} items", item.goals.len())),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerGoals showing only the selected fields
+pub fn format_player_goals_fields(item: &types::PlayerGoals, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerGoals", item, fields, names, player_goals_cell)
+}
+
+/// Kinds of IDs a PlayerGoals table shows, of all fields or only the selected ones
+pub fn player_goals_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerLaws for display (all fields)
-pub fn format_player_laws(item: &types::PlayerLaws) -> String {
+pub fn format_player_laws(item: &types::PlayerLaws, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerLaws".to_string());
@@ -3140,8 +4024,28 @@ pub fn format_player_laws(item: &types::PlayerLaws) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerLaws field as a table cell (None for fields not in the spec)
+pub fn player_laws_cell(item: &types::PlayerLaws, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "activeLawCount" => item.active_law_count.map(|v| v.to_string()),
+        "activeLaws" => Some(item.active_laws.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerLaws showing only the selected fields
+pub fn format_player_laws_fields(item: &types::PlayerLaws, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerLaws", item, fields, names, player_laws_cell)
+}
+
+/// Kinds of IDs a PlayerLaws table shows, of all fields or only the selected ones
+pub fn player_laws_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerMissions for display (all fields)
-pub fn format_player_missions(item: &types::PlayerMissions) -> String {
+pub fn format_player_missions(item: &types::PlayerMissions, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerMissions".to_string());
@@ -3159,8 +4063,28 @@ pub fn format_player_missions(item: &types::PlayerMissions) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerMissions field as a table cell (None for fields not in the spec)
+pub fn player_missions_cell(item: &types::PlayerMissions, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "cooldowns" => Some(item.cooldowns.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "missions" => Some(format!("{} items", item.missions.len())),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerMissions showing only the selected fields
+pub fn format_player_missions_fields(item: &types::PlayerMissions, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerMissions", item, fields, names, player_missions_cell)
+}
+
+/// Kinds of IDs a PlayerMissions table shows, of all fields or only the selected ones
+pub fn player_missions_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerReligion for display (all fields)
-pub fn format_player_religion(item: &types::PlayerReligion) -> String {
+pub fn format_player_religion(item: &types::PlayerReligion, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerReligion".to_string());
@@ -3178,8 +4102,28 @@ pub fn format_player_religion(item: &types::PlayerReligion) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerReligion field as a table cell (None for fields not in the spec)
+pub fn player_religion_cell(item: &types::PlayerReligion, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "religionCounts" => Some(item.religion_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "stateReligion" => item.state_religion.as_deref().map(shorten_type),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerReligion showing only the selected fields
+pub fn format_player_religion_fields(item: &types::PlayerReligion, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerReligion", item, fields, names, player_religion_cell)
+}
+
+/// Kinds of IDs a PlayerReligion table shows, of all fields or only the selected ones
+pub fn player_religion_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerResources for display (all fields)
-pub fn format_player_resources(item: &types::PlayerResources) -> String {
+pub fn format_player_resources(item: &types::PlayerResources, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerResources".to_string());
@@ -3200,8 +4144,28 @@ pub fn format_player_resources(item: &types::PlayerResources) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerResources field as a table cell (None for fields not in the spec)
+pub fn player_resources_cell(item: &types::PlayerResources, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "luxuries" => Some(item.luxuries.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "revealed" => Some(item.revealed.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerResources showing only the selected fields
+pub fn format_player_resources_fields(item: &types::PlayerResources, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerResources", item, fields, names, player_resources_cell)
+}
+
+/// Kinds of IDs a PlayerResources table shows, of all fields or only the selected ones
+pub fn player_resources_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single PlayerTechs for display (all fields)
-pub fn format_player_techs(item: &types::PlayerTechs) -> String {
+pub fn format_player_techs(item: &types::PlayerTechs, _names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("PlayerTechs".to_string());
@@ -3225,17 +4189,39 @@ pub fn format_player_techs(item: &types::PlayerTechs) -> String {
     lines.join("\n")
 }
 
+/// Render one PlayerTechs field as a table cell (None for fields not in the spec)
+pub fn player_techs_cell(item: &types::PlayerTechs, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "available" => Some(item.available.join(", ")),
+        "progress" => Some(item.progress.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "researched" => Some(item.researched.join(", ")),
+        "researching" => item.researching.clone(),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single PlayerTechs showing only the selected fields
+pub fn format_player_techs_fields(item: &types::PlayerTechs, fields: &[&str], names: &Names) -> String {
+    format_fields("PlayerTechs", item, fields, names, player_techs_cell)
+}
+
+/// Kinds of IDs a PlayerTechs table shows, of all fields or only the selected ones
+pub fn player_techs_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single Religion for display (all fields)
-pub fn format_religion(item: &types::Religion) -> String {
+pub fn format_religion(item: &types::Religion, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("Religion".to_string());
 
     if let Some(v) = item.head_character_id {
-        lines.push(format!("  Head Character Id: {}", v));
+        lines.push(format!("  Head Character Id: {}", resolve_character(names, v)));
     }
     if let Some(v) = item.holy_city_id {
-        lines.push(format!("  Holy City Id: {}", v));
+        lines.push(format!("  Holy City Id: {}", resolve_city(names, v)));
     }
     if let Some(v) = item.is_founded {
         if v {
@@ -3250,20 +4236,46 @@ pub fn format_religion(item: &types::Religion) -> String {
 }
 
 /// Format a list of religions (all fields for each)
-pub fn format_religions(items: &[types::Religion]) -> String {
+pub fn format_religions(items: &[types::Religion], names: &Names) -> String {
     if items.is_empty() {
         return "No religions found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_religion(item))
+        .map(|item| format_religion(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one Religion field as a table cell (None for fields not in the spec)
+pub fn religion_cell(item: &types::Religion, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "headCharacterId" => item.head_character_id.map(|v| resolve_character(names, v)),
+        "holyCityId" => item.holy_city_id.map(|v| resolve_city(names, v)),
+        "isFounded" => item.is_founded.map(yes_no),
+        "religionType" => item.religion_type.as_deref().map(shorten_type),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of religions as a table of the selected fields
+pub fn format_religions_columns(items: &[types::Religion], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No religions found".to_string();
+    }
+
+    format_columns(items, fields, names, religion_cell)
+}
+
+/// Kinds of IDs a Religion table shows, of all fields or only the selected ones
+pub fn religion_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("headCharacterId", Kind::Character), ("holyCityId", Kind::City)], fields)
+}
+
 /// Format a list of team alliances (all fields for each)
-pub fn format_team_alliances(items: &[types::TeamAlliance]) -> String {
+pub fn format_team_alliances(items: &[types::TeamAlliance], _names: &Names) -> String {
     if items.is_empty() {
         return "No team alliances found".to_string();
     }
@@ -3288,8 +4300,33 @@ pub fn format_team_alliances(items: &[types::TeamAlliance]) -> String {
         .join("\n\n")
 }
 
+/// Render one TeamAlliance field as a table cell (None for fields not in the spec)
+pub fn team_alliance_cell(item: &types::TeamAlliance, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "allianceType" => item.alliance_type.as_deref().map(shorten_type),
+        "team1" => item.team1.map(|v| v.to_string()),
+        "team2" => item.team2.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of team alliances as a table of the selected fields
+pub fn format_team_alliances_columns(items: &[types::TeamAlliance], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No team alliances found".to_string();
+    }
+
+    format_columns(items, fields, names, team_alliance_cell)
+}
+
+/// Kinds of IDs a TeamAlliance table shows, of all fields or only the selected ones
+pub fn team_alliance_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a list of team diplomacy entries (all fields for each)
-pub fn format_team_diplomacy(items: &[types::TeamDiplomacy]) -> String {
+pub fn format_team_diplomacy(items: &[types::TeamDiplomacy], _names: &Names) -> String {
     if items.is_empty() {
         return "No team diplomacy entries found".to_string();
     }
@@ -3338,8 +4375,39 @@ pub fn format_team_diplomacy(items: &[types::TeamDiplomacy]) -> String {
         .join("\n\n")
 }
 
+/// Render one TeamDiplomacy field as a table cell (None for fields not in the spec)
+pub fn team_diplomacy_cell(item: &types::TeamDiplomacy, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "conflictTurn" => item.conflict_turn.map(|v| v.to_string()),
+        "diplomacy" => item.diplomacy.as_deref().map(shorten_type),
+        "fromTeam" => item.from_team.map(|v| v.to_string()),
+        "hasContact" => item.has_contact.map(yes_no),
+        "isHostile" => item.is_hostile.map(yes_no),
+        "isPeace" => item.is_peace.map(yes_no),
+        "toTeam" => item.to_team.map(|v| v.to_string()),
+        "warScore" => item.war_score.map(|v| v.to_string()),
+        "warState" => item.war_state.clone(),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of team diplomacy entries as a table of the selected fields
+pub fn format_team_diplomacy_columns(items: &[types::TeamDiplomacy], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No team diplomacy entries found".to_string();
+    }
+
+    format_columns(items, fields, names, team_diplomacy_cell)
+}
+
+/// Kinds of IDs a TeamDiplomacy table shows, of all fields or only the selected ones
+pub fn team_diplomacy_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[], fields)
+}
+
 /// Format a single Tile for display (all fields)
-pub fn format_tile(item: &types::Tile) -> String {
+pub fn format_tile(item: &types::Tile, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("Tile".to_string());
@@ -3354,13 +4422,13 @@ pub fn format_tile(item: &types::Tile) -> String {
         lines.push(format!("  Area Tile Count: {}", v));
     }
     if let Some(v) = item.city_id {
-        lines.push(format!("  City ID: {}", v));
+        lines.push(format!("  City ID: {}", resolve_city(names, v)));
     }
     if let Some(v) = &item.city_site {
         lines.push(format!("  City Site: {}", v));
     }
     if let Some(v) = item.city_territory {
-        lines.push(format!("  City Territory: {}", v));
+        lines.push(format!("  City Territory: {}", resolve_city(names, v)));
     }
     if let Some(v) = item.construction_progress {
         lines.push(format!("  Construction Progress: {}", v));
@@ -3789,7 +4857,7 @@ pub fn format_tile(item: &types::Tile) -> String {
         lines.push(format!("  Orig Urban Owner: {}", v));
     }
     if let Some(v) = &item.owner {
-        lines.push(format!("  Owner: {}", v));
+        lines.push(format!("  Owner: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.owner_tribe {
         lines.push(format!("  Owner Tribe: {}", shorten_type(v)));
@@ -3859,26 +4927,177 @@ pub fn format_tile(item: &types::Tile) -> String {
 }
 
 /// Format a list of tiles (all fields for each)
-pub fn format_tiles(items: &[types::Tile]) -> String {
+pub fn format_tiles(items: &[types::Tile], names: &Names) -> String {
     if items.is_empty() {
         return "No tiles found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_tile(item))
+        .map(|item| format_tile(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one Tile field as a table cell (None for fields not in the spec)
+pub fn tile_cell(item: &types::Tile, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "activeImprovementClass" => item.active_improvement_class.as_deref().map(shorten_type),
+        "area" => item.area.map(|v| v.to_string()),
+        "areaTileCount" => item.area_tile_count.map(|v| v.to_string()),
+        "cityID" => item.city_id.map(|v| resolve_city(names, v)),
+        "citySite" => item.city_site.clone(),
+        "cityTerritory" => item.city_territory.map(|v| resolve_city(names, v)),
+        "constructionProgress" => item.construction_progress.map(|v| v.to_string()),
+        "currentSpecialist" => item.current_specialist.clone(),
+        "customMapElementName" => item.custom_map_element_name.clone(),
+        "extraBlockingVisibilityHeight" => item.extra_blocking_visibility_height.map(|v| v.to_string()),
+        "family" => item.family.as_deref().map(shorten_type),
+        "harvestTurn" => item.harvest_turn.map(|v| v.to_string()),
+        "hasCanDamageUnit" => item.has_can_damage_unit.map(yes_no),
+        "hasCity" => item.has_city.map(yes_no),
+        "hasCityTerritory" => item.has_city_territory.map(yes_no),
+        "hasFamily" => item.has_family.map(yes_no),
+        "hasFreeSpecialist" => item.has_free_specialist.map(yes_no),
+        "hasGovernor" => item.has_governor.map(yes_no),
+        "hasImprovement" => item.has_improvement.map(yes_no),
+        "hasImprovementClass" => item.has_improvement_class.map(yes_no),
+        "hasImprovementFreeSpecialist" => item.has_improvement_free_specialist.map(yes_no),
+        "hasImprovementRural" => item.has_improvement_rural.map(yes_no),
+        "hasImprovementWithBorderSpread" => item.has_improvement_with_border_spread.map(yes_no),
+        "hasInactiveImprovement" => item.has_inactive_improvement.map(yes_no),
+        "hasMapElementName" => item.has_map_element_name.map(yes_no),
+        "hasMapGeneratorData" => item.has_map_generator_data.map(yes_no),
+        "hasOrBuildingSpecialists" => Some(item.has_or_building_specialists.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasOwner" => item.has_owner.map(yes_no),
+        "hasOwnerTribe" => item.has_owner_tribe.map(yes_no),
+        "hasReligion" => item.has_religion.map(yes_no),
+        "hasResource" => item.has_resource.map(yes_no),
+        "hasSpecialist" => item.has_specialist.map(yes_no),
+        "hasUnit" => item.has_unit.map(yes_no),
+        "hasVegetation" => item.has_vegetation.map(yes_no),
+        "height" => item.height.as_deref().map(shorten_type),
+        "heightHistory" => item.height_history.as_deref().map(shorten_type),
+        "hiddenHeight" => item.hidden_height.as_deref().map(shorten_type),
+        "hiddenTerrain" => item.hidden_terrain.as_deref().map(shorten_type),
+        "id" => item.id.map(|v| v.to_string()),
+        "improvement" => item.improvement.as_deref().map(shorten_type),
+        "improvementBuildTurnsLeft" => item.improvement_build_turns_left.map(|v| v.to_string()),
+        "improvementBuildTurnsOriginal" => item.improvement_build_turns_original.map(|v| v.to_string()),
+        "improvementClass" => item.improvement_class.as_deref().map(shorten_type),
+        "improvementCosts" => Some(item.improvement_costs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "improvementDevelopTurns" => item.improvement_develop_turns.map(|v| v.to_string()),
+        "improvementDynamicText" => item.improvement_dynamic_text.as_deref().map(shorten_type),
+        "improvementFirstSpecialist" => item.improvement_first_specialist.as_deref().map(shorten_type),
+        "improvementGrammaticalGender" => item.improvement_grammatical_gender.as_deref().map(shorten_type),
+        "improvementPillageTurns" => item.improvement_pillage_turns.map(|v| v.to_string()),
+        "improvementReligionPrereq" => item.improvement_religion_prereq.as_deref().map(shorten_type),
+        "improvementReligionSpread" => item.improvement_religion_spread.as_deref().map(shorten_type),
+        "improvementUnitTurns" => item.improvement_unit_turns.map(|v| v.to_string()),
+        "isAnyCoastLand" => item.is_any_coast_land.map(yes_no),
+        "isAnyDirty" => item.is_any_dirty.map(yes_no),
+        "isBorder" => item.is_border.map(yes_no),
+        "isBoundary" => item.is_boundary.map(yes_no),
+        "isCanal" => item.is_canal.map(yes_no),
+        "isCitySiteAny" => item.is_city_site_any.map(yes_no),
+        "isClear" => item.is_clear.map(yes_no),
+        "isFlat" => item.is_flat.map(yes_no),
+        "isFreshWater" => item.is_fresh_water.map(yes_no),
+        "isFreshWaterAccess" => item.is_fresh_water_access.map(yes_no),
+        "isFreshWaterSource" => item.is_fresh_water_source.map(yes_no),
+        "isHarvested" => item.is_harvested.map(yes_no),
+        "isImprovementBorderSpreads" => Some(item.is_improvement_border_spreads.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isImprovementUnfinished" => item.is_improvement_unfinished.map(yes_no),
+        "isImprovementUnfinishedOrPillaged" => item.is_improvement_unfinished_or_pillaged.map(yes_no),
+        "isLand" => item.is_land.map(yes_no),
+        "isMinorCity" => item.is_minor_city.map(yes_no),
+        "isNationSite" => item.is_nation_site.map(yes_no),
+        "isPassableLand" => item.is_passable_land.map(yes_no),
+        "isPillaged" => item.is_pillaged.map(yes_no),
+        "isRegrowing" => item.is_regrowing.map(yes_no),
+        "isReligions" => Some(item.is_religions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isResourceValids" => Some(item.is_resource_valids.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isRiver" => item.is_river.map(yes_no),
+        "isRiverE" => item.is_river_e.map(yes_no),
+        "isRiverNE" => item.is_river_ne.map(yes_no),
+        "isRiverNW" => item.is_river_nw.map(yes_no),
+        "isRiverSE" => item.is_river_se.map(yes_no),
+        "isRiverSW" => item.is_river_sw.map(yes_no),
+        "isRiverW" => item.is_river_w.map(yes_no),
+        "isRoad" => item.is_road.map(yes_no),
+        "isSaltCoastLand" => item.is_salt_coast_land.map(yes_no),
+        "isSaltCoastWater" => item.is_salt_coast_water.map(yes_no),
+        "isSaltWater" => item.is_salt_water.map(yes_no),
+        "isSpecialistCostCitizens" => Some(item.is_specialist_cost_citizens.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isTribeSite" => item.is_tribe_site.map(yes_no),
+        "isUrban" => item.is_urban.map(yes_no),
+        "isWater" => item.is_water.map(yes_no),
+        "landSection" => item.land_section.map(|v| v.to_string()),
+        "lastTurnOwnerChange" => item.last_turn_owner_change.map(|v| v.to_string()),
+        "latitude" => item.latitude.map(|v| v.to_string()),
+        "mapElementName" => item.map_element_name.clone(),
+        "mapElementNameType" => item.map_element_name_type.as_deref().map(shorten_type),
+        "mapGeneratorData" => item.map_generator_data.clone(),
+        "metadata" => item.metadata.clone(),
+        "movementCostExtra" => item.movement_cost_extra.map(|v| v.to_string()),
+        "nation" => item.nation.as_deref().map(shorten_type),
+        "nationSite" => item.nation_site.as_deref().map(shorten_type),
+        "numTags" => item.num_tags.map(|v| v.to_string()),
+        "origUrbanOwner" => item.orig_urban_owner.clone(),
+        "owner" => item.owner.as_deref().map(|v| resolve_player(names, v)),
+        "ownerTribe" => item.owner_tribe.as_deref().map(shorten_type),
+        "regrowthTurn" => item.regrowth_turn.map(|v| v.to_string()),
+        "resource" => item.resource.as_deref().map(shorten_type),
+        "resourceHeightOffset" => item.resource_height_offset.map(|v| v.to_string()),
+        "riverSE" => item.river_se.clone(),
+        "riverSW" => item.river_sw.clone(),
+        "riverW" => item.river_w.clone(),
+        "specialist" => item.specialist.clone(),
+        "specialistClass" => item.specialist_class.clone(),
+        "team" => item.team.clone(),
+        "terrain" => item.terrain.as_deref().map(shorten_type),
+        "terrainHistory" => item.terrain_history.as_deref().map(shorten_type),
+        "terrainStamp" => item.terrain_stamp.as_deref().map(shorten_type),
+        "terrainStampTileID" => item.terrain_stamp_tile_id.map(|v| v.to_string()),
+        "terrainUnitDamage" => item.terrain_unit_damage.map(|v| v.to_string()),
+        "tradeOutpostIncome" => item.trade_outpost_income.map(|v| v.to_string()),
+        "tribeSite" => item.tribe_site.as_deref().map(shorten_type),
+        "vegetation" => item.vegetation.as_deref().map(shorten_type),
+        "vegetationHistory" => item.vegetation_history.as_deref().map(shorten_type),
+        "x" => item.x.map(|v| v.to_string()),
+        "y" => item.y.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single Tile showing only the selected fields
+pub fn format_tile_fields(item: &types::Tile, fields: &[&str], names: &Names) -> String {
+    format_fields("Tile", item, fields, names, tile_cell)
+}
+
+/// Format a list of tiles as a table of the selected fields
+pub fn format_tiles_columns(items: &[types::Tile], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No tiles found".to_string();
+    }
+
+    format_columns(items, fields, names, tile_cell)
+}
+
+/// Kinds of IDs a Tile table shows, of all fields or only the selected ones
+pub fn tile_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("cityID", Kind::City), ("cityTerritory", Kind::City), ("owner", Kind::Player)], fields)
+}
+
 /// Format a single Tribe for display (all fields)
-pub fn format_tribe(item: &types::Tribe) -> String {
+pub fn format_tribe(item: &types::Tribe, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("Tribe".to_string());
 
     if let Some(v) = item.ally_player_id {
-        lines.push(format!("  Ally Player Id: {}", v));
+        lines.push(format!("  Ally Player Id: {}", resolve_player(names, v)));
     }
     if let Some(v) = item.has_diplomacy {
         if v {
@@ -3906,7 +5125,7 @@ pub fn format_tribe(item: &types::Tribe) -> String {
         }
     }
     if let Some(v) = item.leader_id {
-        lines.push(format!("  Leader Id: {}", v));
+        lines.push(format!("  Leader Id: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.religion {
         lines.push(format!("  Religion: {}", shorten_type(v)));
@@ -3919,20 +5138,56 @@ pub fn format_tribe(item: &types::Tribe) -> String {
 }
 
 /// Format a list of tribes (all fields for each)
-pub fn format_tribes(items: &[types::Tribe]) -> String {
+pub fn format_tribes(items: &[types::Tribe], names: &Names) -> String {
     if items.is_empty() {
         return "No tribes found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_tribe(item))
+        .map(|item| format_tribe(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one Tribe field as a table cell (None for fields not in the spec)
+pub fn tribe_cell(item: &types::Tribe, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "allyPlayerId" => item.ally_player_id.map(|v| resolve_player(names, v)),
+        "hasDiplomacy" => item.has_diplomacy.map(yes_no),
+        "hasLeader" => item.has_leader.map(yes_no),
+        "hasReligion" => item.has_religion.map(yes_no),
+        "isAlive" => item.is_alive.map(yes_no),
+        "isDead" => item.is_dead.map(yes_no),
+        "leaderId" => item.leader_id.map(|v| resolve_character(names, v)),
+        "religion" => item.religion.as_deref().map(shorten_type),
+        "tribeType" => item.tribe_type.as_deref().map(shorten_type),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single Tribe showing only the selected fields
+pub fn format_tribe_fields(item: &types::Tribe, fields: &[&str], names: &Names) -> String {
+    format_fields("Tribe", item, fields, names, tribe_cell)
+}
+
+/// Format a list of tribes as a table of the selected fields
+pub fn format_tribes_columns(items: &[types::Tribe], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No tribes found".to_string();
+    }
+
+    format_columns(items, fields, names, tribe_cell)
+}
+
+/// Kinds of IDs a Tribe table shows, of all fields or only the selected ones
+pub fn tribe_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("allyPlayerId", Kind::Player), ("leaderId", Kind::Character)], fields)
+}
+
 /// Format a list of tribe alliances (all fields for each)
-pub fn format_tribe_alliances(items: &[types::TribeAlliance]) -> String {
+pub fn format_tribe_alliances(items: &[types::TribeAlliance], names: &Names) -> String {
     if items.is_empty() {
         return "No tribe alliances found".to_string();
     }
@@ -3943,10 +5198,10 @@ pub fn format_tribe_alliances(items: &[types::TribeAlliance]) -> String {
             let mut lines = Vec::new();
             lines.push("TribeAlliance".to_string());
             if let Some(v) = item.ally_player_id {
-                lines.push(format!("  Ally Player Id: {}", v));
+                lines.push(format!("  Ally Player Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = item.player_id {
-                lines.push(format!("  Player Id: {}", v));
+                lines.push(format!("  Player Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = &item.tribe_type {
                 lines.push(format!("  Tribe Type: {}", shorten_type(v)));
@@ -3957,8 +5212,33 @@ pub fn format_tribe_alliances(items: &[types::TribeAlliance]) -> String {
         .join("\n\n")
 }
 
+/// Render one TribeAlliance field as a table cell (None for fields not in the spec)
+pub fn tribe_alliance_cell(item: &types::TribeAlliance, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "allyPlayerId" => item.ally_player_id.map(|v| resolve_player(names, v)),
+        "playerId" => item.player_id.map(|v| resolve_player(names, v)),
+        "tribeType" => item.tribe_type.as_deref().map(shorten_type),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of tribe alliances as a table of the selected fields
+pub fn format_tribe_alliances_columns(items: &[types::TribeAlliance], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No tribe alliances found".to_string();
+    }
+
+    format_columns(items, fields, names, tribe_alliance_cell)
+}
+
+/// Kinds of IDs a TribeAlliance table shows, of all fields or only the selected ones
+pub fn tribe_alliance_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("allyPlayerId", Kind::Player), ("playerId", Kind::Player)], fields)
+}
+
 /// Format a list of tribe diplomacy entries (all fields for each)
-pub fn format_tribe_diplomacy(items: &[types::TribeDiplomacy]) -> String {
+pub fn format_tribe_diplomacy(items: &[types::TribeDiplomacy], names: &Names) -> String {
     if items.is_empty() {
         return "No tribe diplomacy entries found".to_string();
     }
@@ -3987,7 +5267,7 @@ pub fn format_tribe_diplomacy(items: &[types::TribeDiplomacy]) -> String {
                 }
             }
             if let Some(v) = item.player_id {
-                lines.push(format!("  Player Id: {}", v));
+                lines.push(format!("  Player Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = &item.tribe_type {
                 lines.push(format!("  Tribe Type: {}", shorten_type(v)));
@@ -3998,8 +5278,101 @@ pub fn format_tribe_diplomacy(items: &[types::TribeDiplomacy]) -> String {
         .join("\n\n")
 }
 
+/// Render one TribeDiplomacy field as a table cell (None for fields not in the spec)
+pub fn tribe_diplomacy_cell(item: &types::TribeDiplomacy, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "diplomacy" => item.diplomacy.as_deref().map(shorten_type),
+        "hasContact" => item.has_contact.map(yes_no),
+        "isHostile" => item.is_hostile.map(yes_no),
+        "isPeace" => item.is_peace.map(yes_no),
+        "playerId" => item.player_id.map(|v| resolve_player(names, v)),
+        "tribeType" => item.tribe_type.as_deref().map(shorten_type),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of tribe diplomacy entries as a table of the selected fields
+pub fn format_tribe_diplomacy_columns(items: &[types::TribeDiplomacy], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No tribe diplomacy entries found".to_string();
+    }
+
+    format_columns(items, fields, names, tribe_diplomacy_cell)
+}
+
+/// Kinds of IDs a TribeDiplomacy table shows, of all fields or only the selected ones
+pub fn tribe_diplomacy_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("playerId", Kind::Player)], fields)
+}
+
+/// Format a single TurnSummary for display (all fields)
+pub fn format_turn_summary(item: &types::TurnSummary, names: &Names) -> String {
+    let mut lines = Vec::new();
+
+    lines.push("TurnSummary".to_string());
+
+    if !item.characters.is_empty() {
+        lines.push("  Characters:".to_string());
+        for line in format_character_events(&item.characters, names).lines() {
+            lines.push(format!("    {}", line));
+        }
+    }
+    if !item.cities.is_empty() {
+        lines.push("  Cities:".to_string());
+        for line in format_city_events(&item.cities, names).lines() {
+            lines.push(format!("    {}", line));
+        }
+    }
+    if let Some(v) = item.turn {
+        lines.push(format!("  Turn: {}", v));
+    }
+    if !item.units.is_empty() {
+        lines.push("  Units:".to_string());
+        for line in format_unit_events(&item.units, names).lines() {
+            lines.push(format!("    {}", line));
+        }
+    }
+    if !item.wonders.is_empty() {
+        lines.push("  Wonders:".to_string());
+        for line in format_wonder_events(&item.wonders, names).lines() {
+            lines.push(format!("    {}", line));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Render one TurnSummary field as a table cell (None for fields not in the spec)
+pub fn turn_summary_cell(item: &types::TurnSummary, field: &str, _names: &Names) -> Option<String> {
+    let cell = match field {
+        "characters" => Some(format!("{} items", item.characters.len())),
+        "cities" => Some(format!("{} items", item.cities.len())),
+        "turn" => item.turn.map(|v| v.to_string()),
+        "units" => Some(format!("{} items", item.units.len())),
+        "wonders" => Some(format!("{} items", item.wonders.len())),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single TurnSummary showing only the selected fields
+pub fn format_turn_summary_fields(item: &types::TurnSummary, fields: &[&str], names: &Names) -> String {
+    format_fields("TurnSummary", item, fields, names, turn_summary_cell)
+}
+
+/// Kinds of IDs a TurnSummary table shows, of all fields or only the selected ones
+pub fn turn_summary_id_kinds(fields: Option<&[&str]>) -> Needed {
+    let needed = Needed::of_fields(&[], fields);
+    // Nested lists are only expanded when all fields are shown
+    match fields {
+        None => needed.union(character_event_id_kinds(None)).union(city_event_id_kinds(None)).union(unit_event_id_kinds(None)).union(wonder_event_id_kinds(None)),
+        Some(_) => needed,
+    }
+}
+
 /// Format a single Unit for display (all fields)
-pub fn format_unit(item: &types::Unit) -> String {
+pub fn format_unit(item: &types::Unit, names: &Names) -> String {
     let mut lines = Vec::new();
 
     lines.push("Unit".to_string());
@@ -4060,7 +5433,7 @@ pub fn format_unit(item: &types::Unit) -> String {
         lines.push(format!("  End Rout Effect: {}", shorten_type(v)));
     }
     if let Some(v) = item.explorer_id {
-        lines.push(format!("  Explorer ID: {}", v));
+        lines.push(format!("  Explorer ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.facing {
         lines.push(format!("  Facing: {}", v));
@@ -4102,7 +5475,7 @@ pub fn format_unit(item: &types::Unit) -> String {
         lines.push(format!("  Gender: {}", v));
     }
     if let Some(v) = item.general_id {
-        lines.push(format!("  General ID: {}", v));
+        lines.push(format!("  General ID: {}", resolve_character(names, v)));
     }
     if let Some(v) = &item.grammatical_gender {
         lines.push(format!("  Grammatical Gender: {}", v));
@@ -4663,13 +6036,13 @@ pub fn format_unit(item: &types::Unit) -> String {
         lines.push(format!("  Offensive Units: {}", item.offensive_units.join(", ")));
     }
     if let Some(v) = &item.original_player {
-        lines.push(format!("  Original Player: {}", v));
+        lines.push(format!("  Original Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.original_tribe {
         lines.push(format!("  Original Tribe: {}", shorten_type(v)));
     }
     if let Some(v) = &item.player {
-        lines.push(format!("  Player: {}", v));
+        lines.push(format!("  Player: {}", resolve_player(names, v)));
     }
     if !item.promotions.is_empty() {
         lines.push(format!("  Promotions: {}", item.promotions.join(", ")));
@@ -4684,7 +6057,7 @@ pub fn format_unit(item: &types::Unit) -> String {
         lines.push(format!("  Queued Tile: {}", v));
     }
     if let Some(v) = &item.rebel_player {
-        lines.push(format!("  Rebel Player: {}", v));
+        lines.push(format!("  Rebel Player: {}", resolve_player(names, v)));
     }
     if let Some(v) = &item.rebel_team {
         lines.push(format!("  Rebel Team: {}", v));
@@ -4769,20 +6142,224 @@ pub fn format_unit(item: &types::Unit) -> String {
 }
 
 /// Format a list of units (all fields for each)
-pub fn format_units(items: &[types::Unit]) -> String {
+pub fn format_units(items: &[types::Unit], names: &Names) -> String {
     if items.is_empty() {
         return "No units found".to_string();
     }
 
     items
         .iter()
-        .map(|item| format_unit(item))
+        .map(|item| format_unit(item, names))
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
+/// Render one Unit field as a table cell (None for fields not in the spec)
+pub fn unit_cell(item: &types::Unit, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "agentNetworkCost" => item.agent_network_cost.map(|v| v.to_string()),
+        "anchoredTurns" => item.anchored_turns.map(|v| v.to_string()),
+        "caravanMissionTarget" => item.caravan_mission_target.as_deref().map(shorten_type),
+        "civilianCycleSubgroup" => item.civilian_cycle_subgroup.clone(),
+        "consumptionMercenaryModifier" => item.consumption_mercenary_modifier.map(|v| v.to_string()),
+        "cooldown" => item.cooldown.clone(),
+        "cooldownTurns" => item.cooldown_turns.map(|v| v.to_string()),
+        "createTurn" => item.create_turn.map(|v| v.to_string()),
+        "currentFormation" => item.current_formation.clone(),
+        "damage" => item.damage.map(|v| v.to_string()),
+        "damagedExtraBuildTurns" => item.damaged_extra_build_turns.map(|v| v.to_string()),
+        "effectUnitCount" => item.effect_unit_count.map(|v| v.to_string()),
+        "effectUnitCounts" => Some(item.effect_unit_counts.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "effectUnitTurnRemainings" => Some(item.effect_unit_turn_remainings.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "effectUnits" => Some(item.effect_units.iter().map(|s| shorten_type(s)).collect::<Vec<_>>().join(", ")),
+        "endRoutEffect" => item.end_rout_effect.as_deref().map(shorten_type),
+        "explorerID" => item.explorer_id.map(|v| resolve_character(names, v)),
+        "facing" => item.facing.clone(),
+        "family" => item.family.as_deref().map(shorten_type),
+        "familyClass" => item.family_class.as_deref().map(shorten_type),
+        "familyOpinion" => item.family_opinion.as_deref().map(shorten_type),
+        "fatigueLimit" => item.fatigue_limit.map(|v| v.to_string()),
+        "formationBonus" => item.formation_bonus.map(|v| v.to_string()),
+        "formationTurns" => item.formation_turns.map(|v| v.to_string()),
+        "fortifyBonus" => item.fortify_bonus.map(|v| v.to_string()),
+        "fortifyTurns" => item.fortify_turns.map(|v| v.to_string()),
+        "freeActions" => item.free_actions.map(|v| v.to_string()),
+        "freeActionsTaken" => item.free_actions_taken.map(|v| v.to_string()),
+        "freeImprovementBuild" => item.free_improvement_build.as_deref().map(shorten_type),
+        "gender" => item.gender.clone(),
+        "generalID" => item.general_id.map(|v| resolve_character(names, v)),
+        "grammaticalGender" => item.grammatical_gender.clone(),
+        "harvestDistanceModifier" => item.harvest_distance_modifier.map(|v| v.to_string()),
+        "harvestYieldModifiers" => Some(item.harvest_yield_modifiers.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "hasBuyTileYields" => Some(item.has_buy_tile_yields.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasCaravanMissionTarget" => item.has_caravan_mission_target.map(yes_no),
+        "hasCooldown" => item.has_cooldown.map(yes_no),
+        "hasCriticalImmune" => item.has_critical_immune.map(yes_no),
+        "hasEffectEstablishTheology" => item.has_effect_establish_theology.map(yes_no),
+        "hasEffectMercenary" => item.has_effect_mercenary.map(yes_no),
+        "hasEffectSpreadReligion" => item.has_effect_spread_religion.map(yes_no),
+        "hasEffectUnitAny" => item.has_effect_unit_any.map(yes_no),
+        "hasEffectUnits" => Some(item.has_effect_units.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasEndRoutEffect" => item.has_end_rout_effect.map(yes_no),
+        "hasEnlistNext" => item.has_enlist_next.map(yes_no),
+        "hasExplorer" => item.has_explorer.map(yes_no),
+        "hasFamily" => item.has_family.map(yes_no),
+        "hasFamilyOpinion" => item.has_family_opinion.map(yes_no),
+        "hasFamilyTerritoryOnly" => item.has_family_territory_only.map(yes_no),
+        "hasFreeActions" => item.has_free_actions.map(yes_no),
+        "hasFreeImprovementBuild" => item.has_free_improvement_build.map(yes_no),
+        "hasGeneral" => item.has_general.map(yes_no),
+        "hasGeneralHopping" => item.has_general_hopping.map(yes_no),
+        "hasGeneralModifier" => item.has_general_modifier.map(|v| v.to_string()),
+        "hasHarvest" => item.has_harvest.map(yes_no),
+        "hasHealKill" => item.has_heal_kill.map(yes_no),
+        "hasHealNeutral" => item.has_heal_neutral.map(yes_no),
+        "hasHealPillage" => item.has_heal_pillage.map(yes_no),
+        "hasIgnoreHeightCosts" => Some(item.has_ignore_height_costs.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasIgnoreVegetationCosts" => Some(item.has_ignore_vegetation_costs.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasIgnoreVegetationDefenses" => Some(item.has_ignore_vegetation_defenses.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasIgnoreZOC" => item.has_ignore_zoc.map(yes_no),
+        "hasLastStand" => item.has_last_stand.map(yes_no),
+        "hasLaunchOffensive" => item.has_launch_offensive.map(yes_no),
+        "hasMoved" => item.has_moved.map(yes_no),
+        "hasName" => item.has_name.map(yes_no),
+        "hasNoRoadCooldown" => item.has_no_road_cooldown.map(yes_no),
+        "hasOffensiveRemoveCooldown" => item.has_offensive_remove_cooldown.map(yes_no),
+        "hasOriginalPlayer" => item.has_original_player.map(yes_no),
+        "hasOriginalTribe" => item.has_original_tribe.map(yes_no),
+        "hasPillage" => item.has_pillage.map(yes_no),
+        "hasPlayer" => item.has_player.map(yes_no),
+        "hasPromotions" => Some(item.has_promotions.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "hasQueueList" => item.has_queue_list.map(yes_no),
+        "hasRebelPlayer" => item.has_rebel_player.map(yes_no),
+        "hasReligion" => item.has_religion.map(yes_no),
+        "hasRemoveVegetation" => item.has_remove_vegetation.map(yes_no),
+        "hasVisibleAttackCooldown" => item.has_visible_attack_cooldown.map(yes_no),
+        "hasZOC" => item.has_zoc.map(yes_no),
+        "hp" => item.hp.map(|v| v.to_string()),
+        "hpMax" => item.hp_max.map(|v| v.to_string()),
+        "id" => item.id.map(|v| v.to_string()),
+        "initialGender" => item.initial_gender.clone(),
+        "isActiveOutOfTurn" => item.is_active_out_of_turn.map(yes_no),
+        "isActivePlayer" => item.is_active_player.map(yes_no),
+        "isAlive" => item.is_alive.map(yes_no),
+        "isAnchored" => item.is_anchored.map(yes_no),
+        "isAnyDirty" => item.is_any_dirty.map(yes_no),
+        "isAutoHarvest" => item.is_auto_harvest.map(yes_no),
+        "isAutoHeal" => item.is_auto_heal.map(yes_no),
+        "isAutoMoveOnly" => item.is_auto_move_only.map(yes_no),
+        "isAutomated" => item.is_automated.map(yes_no),
+        "isBusy" => item.is_busy.map(yes_no),
+        "isCapturingCity" => item.is_capturing_city.map(yes_no),
+        "isCriticalHit" => item.is_critical_hit.map(yes_no),
+        "isDamaged" => item.is_damaged.map(yes_no),
+        "isDead" => item.is_dead.map(yes_no),
+        "isExplorerEffectUnits" => Some(item.is_explorer_effect_units.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isFatigued" => item.is_fatigued.map(yes_no),
+        "isFormation" => item.is_formation.map(yes_no),
+        "isFormationMax" => item.is_formation_max.map(yes_no),
+        "isFortify" => item.is_fortify.map(yes_no),
+        "isFortifyMax" => item.is_fortify_max.map(yes_no),
+        "isGeneralEffectUnits" => Some(item.is_general_effect_units.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isHiddenNotTemp" => item.is_hidden_not_temp.map(yes_no),
+        "isHuman" => item.is_human.map(yes_no),
+        "isImmobile" => item.is_immobile.map(yes_no),
+        "isImmuneEffectUnits" => Some(item.is_immune_effect_units.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isImprovingTile" => item.is_improving_tile.map(yes_no),
+        "isLeaderExplorer" => item.is_leader_explorer.map(yes_no),
+        "isLeaderGeneral" => item.is_leader_general.map(yes_no),
+        "isLevelMax" => item.is_level_max.map(yes_no),
+        "isLevelPromotionMax" => item.is_level_promotion_max.map(yes_no),
+        "isLocked" => item.is_locked.map(yes_no),
+        "isMarch" => item.is_march.map(yes_no),
+        "isModVariables" => item.is_mod_variables.map(yes_no),
+        "isOccurrenceImpassableImmune" => item.is_occurrence_impassable_immune.map(yes_no),
+        "isPass" => item.is_pass.map(yes_no),
+        "isPromotable" => item.is_promotable.map(yes_no),
+        "isRaiding" => item.is_raiding.map(yes_no),
+        "isRemoveVegatation" => item.is_remove_vegatation.map(yes_no),
+        "isRoadBuilder" => item.is_road_builder.map(yes_no),
+        "isScout" => item.is_scout.map(yes_no),
+        "isSentry" => item.is_sentry.map(yes_no),
+        "isShowCriticalHit" => item.is_show_critical_hit.map(yes_no),
+        "isSkip" => item.is_skip.map(yes_no),
+        "isSleep" => item.is_sleep.map(yes_no),
+        "isTempHidden" => item.is_temp_hidden.map(yes_no),
+        "isTribe" => item.is_tribe.map(yes_no),
+        "isUnitZocs" => Some(item.is_unit_zocs.iter().filter(|(_, v)| **v).map(|(k, _)| shorten_type(k)).collect::<Vec<_>>().join(", ")),
+        "isUnlimbered" => item.is_unlimbered.map(yes_no),
+        "isWorker" => item.is_worker.map(yes_no),
+        "level" => item.level.map(|v| v.to_string()),
+        "levelPromotion" => item.level_promotion.map(|v| v.to_string()),
+        "levelPromotionString" => item.level_promotion_string.clone(),
+        "levelString" => item.level_string.clone(),
+        "marchCost" => item.march_cost.map(|v| v.to_string()),
+        "meleeCounterPercent" => item.melee_counter_percent.map(|v| v.to_string()),
+        "modVariables" => Some(item.mod_variables.join(", ")),
+        "name" => item.name.clone(),
+        "nameType" => item.name_type.as_deref().map(shorten_type),
+        "nation" => item.nation.as_deref().map(shorten_type),
+        "nextCriticalModifier" => item.next_critical_modifier.map(|v| v.to_string()),
+        "offensiveUnits" => Some(item.offensive_units.join(", ")),
+        "originalPlayer" => item.original_player.as_deref().map(|v| resolve_player(names, v)),
+        "originalTribe" => item.original_tribe.as_deref().map(shorten_type),
+        "player" => item.player.as_deref().map(|v| resolve_player(names, v)),
+        "promotions" => Some(item.promotions.join(", ")),
+        "pushEffect" => item.push_effect.as_deref().map(shorten_type),
+        "queueList" => item.queue_list.clone(),
+        "queuedTile" => item.queued_tile.clone(),
+        "rebelPlayer" => item.rebel_player.as_deref().map(|v| resolve_player(names, v)),
+        "rebelTeam" => item.rebel_team.clone(),
+        "religion" => item.religion.as_deref().map(shorten_type),
+        "roadMovementModifier" => item.road_movement_modifier.map(|v| v.to_string()),
+        "routChain" => item.rout_chain.map(|v| v.to_string()),
+        "spreadReligion" => item.spread_religion.as_deref().map(shorten_type),
+        "spreadReligionTribeCost" => item.spread_religion_tribe_cost.map(|v| v.to_string()),
+        "stepsToFatigue" => item.steps_to_fatigue.map(|v| v.to_string()),
+        "strengthRating" => item.strength_rating.map(|v| v.to_string()),
+        "team" => item.team.clone(),
+        "tempHiddenTurns" => item.temp_hidden_turns.map(|v| v.to_string()),
+        "tileID" => item.tile_id.map(|v| v.to_string()),
+        "tradeOutpostCosts" => Some(item.trade_outpost_costs.iter().map(|(k, v)| format!("{}: {}", shorten_type(k), v)).collect::<Vec<_>>().join(", ")),
+        "trainCostWhole" => item.train_cost_whole.map(|v| v.to_string()),
+        "trainXP" => item.train_xp.map(|v| v.to_string()),
+        "tribe" => item.tribe.as_deref().map(shorten_type),
+        "turnSteps" => item.turn_steps.map(|v| v.to_string()),
+        "turnsSinceLastMove" => item.turns_since_last_move.map(|v| v.to_string()),
+        "type" => item.type_.as_deref().map(shorten_type),
+        "unitCycleGroup" => item.unit_cycle_group.clone(),
+        "unlimberedTurns" => item.unlimbered_turns.map(|v| v.to_string()),
+        "workerAnimationType" => item.worker_animation_type.as_deref().map(shorten_type),
+        "x" => item.x.map(|v| v.to_string()),
+        "xp" => item.xp.map(|v| v.to_string()),
+        "xpThreshold" => item.xp_threshold.map(|v| v.to_string()),
+        "y" => item.y.map(|v| v.to_string()),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a single Unit showing only the selected fields
+pub fn format_unit_fields(item: &types::Unit, fields: &[&str], names: &Names) -> String {
+    format_fields("Unit", item, fields, names, unit_cell)
+}
+
+/// Format a list of units as a table of the selected fields
+pub fn format_units_columns(items: &[types::Unit], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No units found".to_string();
+    }
+
+    format_columns(items, fields, names, unit_cell)
+}
+
+/// Kinds of IDs a Unit table shows, of all fields or only the selected ones
+pub fn unit_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("explorerID", Kind::Character), ("generalID", Kind::Character), ("originalPlayer", Kind::Player), ("player", Kind::Player), ("rebelPlayer", Kind::Player)], fields)
+}
+
 /// Format a list of unit events (all fields for each)
-pub fn format_unit_events(items: &[types::UnitEvent]) -> String {
+pub fn format_unit_events(items: &[types::UnitEvent], names: &Names) -> String {
     if items.is_empty() {
         return "No unit events found".to_string();
     }
@@ -4796,10 +6373,10 @@ pub fn format_unit_events(items: &[types::UnitEvent]) -> String {
                 lines.push(format!("  Event Type: {:?}", v));
             }
                         if let Some(v) = item.last_owner_id {
-                lines.push(format!("  Last Owner Id: {}", v));
+                lines.push(format!("  Last Owner Id: {}", resolve_player(names, v)));
             }
                         if let Some(v) = item.player_id {
-                lines.push(format!("  Player Id: {}", v));
+                lines.push(format!("  Player Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = item.unit_id {
                 lines.push(format!("  Unit Id: {}", v));
@@ -4813,8 +6390,37 @@ pub fn format_unit_events(items: &[types::UnitEvent]) -> String {
         .join("\n\n")
 }
 
+/// Render one UnitEvent field as a table cell (None for fields not in the spec)
+pub fn unit_event_cell(item: &types::UnitEvent, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "eventType" => item.event_type.as_ref().map(|v| format!("{:?}", v)),
+        "lastLocation" => item.last_location.as_ref().map(location_cell),
+        "lastOwnerId" => item.last_owner_id.map(|v| resolve_player(names, v)),
+        "location" => item.location.as_ref().map(location_cell),
+        "playerId" => item.player_id.map(|v| resolve_player(names, v)),
+        "unitId" => item.unit_id.map(|v| v.to_string()),
+        "unitType" => item.unit_type.as_deref().map(shorten_type),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of unit events as a table of the selected fields
+pub fn format_unit_events_columns(items: &[types::UnitEvent], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No unit events found".to_string();
+    }
+
+    format_columns(items, fields, names, unit_event_cell)
+}
+
+/// Kinds of IDs a UnitEvent table shows, of all fields or only the selected ones
+pub fn unit_event_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("lastOwnerId", Kind::Player), ("playerId", Kind::Player)], fields)
+}
+
 /// Format a list of wonder events (all fields for each)
-pub fn format_wonder_events(items: &[types::WonderEvent]) -> String {
+pub fn format_wonder_events(items: &[types::WonderEvent], names: &Names) -> String {
     if items.is_empty() {
         return "No wonder events found".to_string();
     }
@@ -4825,13 +6431,13 @@ pub fn format_wonder_events(items: &[types::WonderEvent]) -> String {
             let mut lines = Vec::new();
             lines.push("WonderEvent".to_string());
             if let Some(v) = item.city_id {
-                lines.push(format!("  City Id: {}", v));
+                lines.push(format!("  City Id: {}", resolve_city(names, v)));
             }
             if let Some(v) = &item.event_type {
                 lines.push(format!("  Event Type: {:?}", v));
             }
             if let Some(v) = item.player_id {
-                lines.push(format!("  Player Id: {}", v));
+                lines.push(format!("  Player Id: {}", resolve_player(names, v)));
             }
             if let Some(v) = &item.tribe_type {
                 lines.push(format!("  Tribe Type: {}", shorten_type(v)));
@@ -4845,3 +6451,30 @@ pub fn format_wonder_events(items: &[types::WonderEvent]) -> String {
         .join("\n\n")
 }
 
+/// Render one WonderEvent field as a table cell (None for fields not in the spec)
+pub fn wonder_event_cell(item: &types::WonderEvent, field: &str, names: &Names) -> Option<String> {
+    let cell = match field {
+        "cityId" => item.city_id.map(|v| resolve_city(names, v)),
+        "eventType" => item.event_type.as_ref().map(|v| format!("{:?}", v)),
+        "playerId" => item.player_id.map(|v| resolve_player(names, v)),
+        "tribeType" => item.tribe_type.as_deref().map(shorten_type),
+        "wonder" => item.wonder.clone(),
+        _ => return None,
+    };
+    Some(cell.unwrap_or_default())
+}
+
+/// Format a list of wonder events as a table of the selected fields
+pub fn format_wonder_events_columns(items: &[types::WonderEvent], fields: &[&str], names: &Names) -> String {
+    if items.is_empty() {
+        return "No wonder events found".to_string();
+    }
+
+    format_columns(items, fields, names, wonder_event_cell)
+}
+
+/// Kinds of IDs a WonderEvent table shows, of all fields or only the selected ones
+pub fn wonder_event_id_kinds(fields: Option<&[&str]>) -> Needed {
+    Needed::of_fields(&[("cityId", Kind::City), ("playerId", Kind::Player)], fields)
+}
+
@@ -3,48 +3,39 @@ use crate::error::{OwcliError, Result};
 /// Represents a parsed API path
 #[derive(Debug, Clone)]
 pub struct ApiPath {
-    /// The full path to send to the API
+    /// The normalized query path
     pub path: String,
-    /// The endpoint type for output formatting
+    /// The endpoint type for dispatch and output formatting
     pub endpoint_type: EndpointType,
+    /// Path parameter values in the order they appear in the path
+    pub params: Vec<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum EndpointType {
-    State,
-    Config,
-    Players,
-    Player,
-    PlayerUnits,
-    PlayerTechs,
-    PlayerFamilies,
-    PlayerReligion,
-    PlayerGoals,
-    PlayerDecisions,
-    PlayerLaws,
-    PlayerMissions,
-    PlayerResources,
-    Cities,
-    City,
-    Characters,
-    Character,
-    Units,
-    Unit,
-    Map,
-    Tiles,
-    Tile,
-    Tribes,
-    Tribe,
-    Religions,
-    TeamDiplomacy,
-    TeamAlliances,
-    TribeDiplomacy,
-    TribeAlliances,
-    CharacterEvents,
-    UnitEvents,
-    CityEvents,
+impl ApiPath {
+    /// Get an integer path parameter by position
+    pub fn int_param(&self, index: usize) -> Result<i64> {
+        let value = self.str_param(index)?;
+        value
+            .parse::<i64>()
+            .map_err(|_| OwcliError::InvalidPath(format!("Expected integer, got '{}'", value)))
+    }
+
+    /// Get a string path parameter by position
+    pub fn str_param(&self, index: usize) -> Result<&str> {
+        self.params.get(index).map(String::as_str).ok_or_else(|| {
+            OwcliError::InvalidPath(format!("Missing parameter in path: {}", self.path))
+        })
+    }
 }
 
+/// Path prefixes that look like queries but have no endpoint in the API spec,
+/// with the error shown instead of "Unknown path"
+const UNSUPPORTED_PREFIXES: &[(&str, &str)] = &[(
+    "unit-type",
+    "the game API has no unit-type endpoint, so unit types can't be looked up. \
+     To list units of a type, use: owcli units --where 'type==\"UNIT_ARCHER\"'",
+)];
+
 /// Parse a slash-notation path into an API path
 pub fn parse_path(input: &str) -> Result<ApiPath> {
     let input = input.trim().trim_start_matches('/');
@@ -54,127 +45,60 @@ pub fn parse_path(input: &str) -> Result<ApiPath> {
         return Err(OwcliError::InvalidPath("Empty path".to_string()));
     }
 
-    let (path, endpoint_type) = match parts.as_slice() {
-        // Simple endpoints
-        ["state"] => ("state".to_string(), EndpointType::State),
-        ["config"] => ("config".to_string(), EndpointType::Config),
-        ["players"] => ("players".to_string(), EndpointType::Players),
-        ["cities"] => ("cities".to_string(), EndpointType::Cities),
-        ["characters"] => ("characters".to_string(), EndpointType::Characters),
-        ["units"] => ("units".to_string(), EndpointType::Units),
-        ["map"] => ("map".to_string(), EndpointType::Map),
-        ["tiles"] => ("tiles".to_string(), EndpointType::Tiles),
-        ["tribes"] => ("tribes".to_string(), EndpointType::Tribes),
-        ["religions"] => ("religions".to_string(), EndpointType::Religions),
-        ["diplomacy", "teams"] => ("diplomacy/teams".to_string(), EndpointType::TeamDiplomacy),
-        ["diplomacy", "tribes"] => ("diplomacy/tribes".to_string(), EndpointType::TribeDiplomacy),
-        ["alliances", "teams"] => ("alliances/teams".to_string(), EndpointType::TeamAlliances),
-        ["alliances", "tribes"] => ("alliances/tribes".to_string(), EndpointType::TribeAlliances),
-        ["events", "characters"] => ("events/characters".to_string(), EndpointType::CharacterEvents),
-        ["events", "units"] => ("events/units".to_string(), EndpointType::UnitEvents),
-        ["events", "cities"] => ("events/cities".to_string(), EndpointType::CityEvents),
-
-        // Player with index
-        ["player", index] => {
-            validate_integer(index)?;
-            (format!("player/{}", index), EndpointType::Player)
-        }
-        ["player", index, "units"] => {
-            validate_integer(index)?;
-            (format!("player/{}/units", index), EndpointType::PlayerUnits)
-        }
-        ["player", index, "techs"] => {
-            validate_integer(index)?;
-            (format!("player/{}/techs", index), EndpointType::PlayerTechs)
-        }
-        ["player", index, "families"] => {
-            validate_integer(index)?;
-            (
-                format!("player/{}/families", index),
-                EndpointType::PlayerFamilies,
-            )
-        }
-        ["player", index, "religion"] => {
-            validate_integer(index)?;
-            (
-                format!("player/{}/religion", index),
-                EndpointType::PlayerReligion,
-            )
-        }
-        ["player", index, "goals"] => {
-            validate_integer(index)?;
-            (format!("player/{}/goals", index), EndpointType::PlayerGoals)
-        }
-        ["player", index, "decisions"] => {
-            validate_integer(index)?;
-            (
-                format!("player/{}/decisions", index),
-                EndpointType::PlayerDecisions,
-            )
-        }
-        ["player", index, "laws"] => {
-            validate_integer(index)?;
-            (format!("player/{}/laws", index), EndpointType::PlayerLaws)
-        }
-        ["player", index, "missions"] => {
-            validate_integer(index)?;
-            (
-                format!("player/{}/missions", index),
-                EndpointType::PlayerMissions,
-            )
-        }
-        ["player", index, "resources"] => {
-            validate_integer(index)?;
-            (
-                format!("player/{}/resources", index),
-                EndpointType::PlayerResources,
-            )
-        }
-
-        // City by ID
-        ["city", id] => {
-            validate_integer(id)?;
-            (format!("city/{}", id), EndpointType::City)
-        }
-
-        // Character by ID
-        ["character", id] => {
-            validate_integer(id)?;
-            (format!("character/{}", id), EndpointType::Character)
-        }
-
-        // Unit by ID
-        ["unit", id] => {
-            validate_integer(id)?;
-            (format!("unit/{}", id), EndpointType::Unit)
-        }
-
-        // Tile by ID or coordinates
-        ["tile", id] => {
-            validate_integer(id)?;
-            (format!("tile/{}", id), EndpointType::Tile)
-        }
-        ["tile", x, y] => {
-            validate_integer(x)?;
-            validate_integer(y)?;
-            (format!("tile/{}/{}", x, y), EndpointType::Tile)
-        }
+    match match_endpoint(&parts)? {
+        Some((endpoint_type, params)) => Ok(ApiPath {
+            path: parts.join("/"),
+            endpoint_type,
+            params,
+        }),
+        None => match UNSUPPORTED_PREFIXES
+            .iter()
+            .find(|(prefix, _)| *prefix == parts[0])
+        {
+            Some((_, reason)) => Err(OwcliError::InvalidPath(format!(
+                "'{}': {}",
+                parts.join("/"),
+                reason
+            ))),
+            None => Err(OwcliError::InvalidPath(format!(
+                "Unknown path: '{}'. Run 'owcli help queries' for valid paths.",
+                parts.join("/")
+            ))),
+        },
+    }
+}
 
-        // Tribe by type
-        ["tribe", tribe_type] => (format!("tribe/{}", tribe_type), EndpointType::Tribe),
+/// Split CLI path arguments into queries. Arguments are joined with `/` when
+/// that makes a valid path (`owcli player 0 units`, `owcli events characters`);
+/// otherwise, if each argument is a path of its own, each runs as a separate
+/// query (`owcli players cities`).
+pub fn split_query_paths(args: &[String]) -> Vec<String> {
+    let joined = args.join("/");
+    if args.len() > 1
+        && parse_path(&joined).is_err()
+        && args.iter().all(|arg| parse_path(arg).is_ok())
+    {
+        args.to_vec()
+    } else {
+        vec![joined]
+    }
+}
 
-        _ => {
-            return Err(OwcliError::InvalidPath(format!(
-                "Unknown path: '{}'. Run 'owcli --help' for valid paths.",
-                parts.join("/")
-            )));
+/// Replace `me` as a player parameter (`player/me/units`) with the default
+/// player from `--default-player` or the profile
+pub fn substitute_player(input: &str, player: Option<&str>) -> Result<String> {
+    let mut parts: Vec<&str> = input.trim().trim_start_matches('/').split('/').collect();
+    for i in 1..parts.len() {
+        if parts[i - 1] == "player" && parts[i] == "me" {
+            parts[i] = player.ok_or_else(|| {
+                OwcliError::InvalidPath(
+                    "'me' needs a default player (--default-player or `player` in the profile)"
+                        .to_string(),
+                )
+            })?;
         }
-    };
-
-    Ok(ApiPath {
-        path,
-        endpoint_type,
-    })
+    }
+    Ok(parts.join("/"))
 }
 
 fn validate_integer(s: &str) -> Result<()> {
@@ -183,6 +107,10 @@ fn validate_integer(s: &str) -> Result<()> {
         .map_err(|_| OwcliError::InvalidPath(format!("Expected integer, got '{}'", s)))
 }
 
+// EndpointType enum and match_endpoint() are generated from openapi.yaml
+// Regenerate with: cargo run --bin gen_queries -- paths > src/path_parser_generated.rs
+include!("path_parser_generated.rs");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -217,7 +145,99 @@ mod tests {
 
         let path = parse_path("tile/5/12").unwrap();
         assert_eq!(path.path, "tile/5/12");
-        assert_eq!(path.endpoint_type, EndpointType::Tile);
+        assert_eq!(path.endpoint_type, EndpointType::TileByCoords);
+        assert_eq!(path.params, vec!["5", "12"]);
+    }
+
+    #[test]
+    fn test_event_paths() {
+        let path = parse_path("events").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::TurnSummary);
+
+        let path = parse_path("events/wonders").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::TurnSummaryWonders);
+
+        let path = parse_path("events/character/42").unwrap();
+        assert_eq!(path.path, "events/character/42");
+        assert_eq!(path.endpoint_type, EndpointType::TurnSummaryCharacter);
+
+        let path = parse_path("events/unit/7").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::TurnSummaryUnit);
+
+        let path = parse_path("events/city/3").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::TurnSummaryCity);
+
+        assert!(parse_path("events/unit/abc").is_err());
+    }
+
+    #[test]
+    fn test_name_params() {
+        // Names are kept as-is and resolved to IDs when the query runs
+        let path = parse_path("city/Roma").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::City);
+        assert_eq!(path.params, vec!["Roma"]);
+
+        let path = parse_path("player/rome/units").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::PlayerUnits);
+        assert_eq!(path.params, vec!["rome"]);
+
+        let path = parse_path("events/character/\"Hannibal\"").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::TurnSummaryCharacter);
+
+        assert!(parse_path("tile/5/north").is_err());
+    }
+
+    #[test]
+    fn test_string_params() {
+        let path = parse_path("tribe/TRIBE_GAUL").unwrap();
+        assert_eq!(path.endpoint_type, EndpointType::Tribe);
+        assert_eq!(path.str_param(0).unwrap(), "TRIBE_GAUL");
+    }
+
+    #[test]
+    fn test_unknown_path() {
+        assert!(parse_path("players/cities").is_err());
+        assert!(parse_path("").is_err());
+
+        let err = parse_path("unit-type/UNIT_ARCHER").unwrap_err().to_string();
+        assert!(err.contains("no unit-type endpoint"), "{}", err);
+    }
+
+    #[test]
+    fn test_split_query_paths() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            split_query_paths(&args(&["players", "cities"])),
+            vec!["players", "cities"]
+        );
+        assert_eq!(
+            split_query_paths(&args(&["player/0", "city/Roma"])),
+            vec!["player/0", "city/Roma"]
+        );
+        assert_eq!(
+            split_query_paths(&args(&["player", "0", "units"])),
+            vec!["player/0/units"]
+        );
+        // "events" and "characters" are paths too, but the joined path wins
+        assert_eq!(
+            split_query_paths(&args(&["events", "characters"])),
+            vec!["events/characters"]
+        );
+        assert_eq!(
+            split_query_paths(&args(&["events", "units", "cities"])),
+            vec!["events", "units", "cities"]
+        );
+    }
+
+    #[test]
+    fn test_substitute_player() {
+        assert_eq!(
+            substitute_player("player/me/units", Some("rome")).unwrap(),
+            "player/rome/units"
+        );
+        assert_eq!(substitute_player("city/me", None).unwrap(), "city/me");
+        assert!(substitute_player("player/me", None).is_err());
     }
 
     #[test]
@@ -228,7 +248,8 @@ mod tests {
 
     #[test]
     fn test_invalid_integer() {
-        let result = parse_path("player/abc");
+        // Units have no names, so their IDs must be numeric
+        let result = parse_path("unit/abc");
         assert!(result.is_err());
     }
 }
@@ -0,0 +1,108 @@
+// This file is auto-generated by `cargo run --bin gen_queries -- paths`
+// Do not edit manually. Regenerate with:
+//   cargo run --bin gen_queries -- paths > src/path_parser_generated.rs
+//
+// Included via include!() in path_parser.rs
+
+/// Query endpoints generated from OpenAPI spec (38 endpoints)
+#[derive(Debug, Clone, PartialEq)]
+pub enum EndpointType {
+    State,
+    Players,
+    Player,
+    PlayerUnits,
+    PlayerTechs,
+    PlayerFamilies,
+    PlayerReligion,
+    PlayerGoals,
+    PlayerDecisions,
+    PlayerLaws,
+    PlayerMissions,
+    PlayerResources,
+    Cities,
+    City,
+    Characters,
+    Character,
+    Units,
+    Unit,
+    Tiles,
+    Tile,
+    TileByCoords,
+    TurnSummary,
+    TurnSummaryCharacters,
+    TurnSummaryCharacter,
+    TurnSummaryUnits,
+    TurnSummaryUnit,
+    TurnSummaryCities,
+    TurnSummaryCity,
+    TurnSummaryWonders,
+    Tribes,
+    Tribe,
+    DiplomacyTeams,
+    AlliancesTeams,
+    DiplomacyTribes,
+    AlliancesTribes,
+    Religions,
+    Config,
+    Map,
+}
+
+/// Match path segments against the query grammar, returning the endpoint and its parameters
+fn match_endpoint(parts: &[&str]) -> Result<Option<(EndpointType, Vec<String>)>> {
+    let matched = match parts {
+        ["state"] => (EndpointType::State, vec![]),
+        ["players"] => (EndpointType::Players, vec![]),
+        ["player", index] => (EndpointType::Player, vec![index.to_string()]),
+        ["player", index, "units"] => (EndpointType::PlayerUnits, vec![index.to_string()]),
+        ["player", index, "techs"] => (EndpointType::PlayerTechs, vec![index.to_string()]),
+        ["player", index, "families"] => (EndpointType::PlayerFamilies, vec![index.to_string()]),
+        ["player", index, "religion"] => (EndpointType::PlayerReligion, vec![index.to_string()]),
+        ["player", index, "goals"] => (EndpointType::PlayerGoals, vec![index.to_string()]),
+        ["player", index, "decisions"] => (EndpointType::PlayerDecisions, vec![index.to_string()]),
+        ["player", index, "laws"] => (EndpointType::PlayerLaws, vec![index.to_string()]),
+        ["player", index, "missions"] => (EndpointType::PlayerMissions, vec![index.to_string()]),
+        ["player", index, "resources"] => (EndpointType::PlayerResources, vec![index.to_string()]),
+        ["cities"] => (EndpointType::Cities, vec![]),
+        ["city", id] => (EndpointType::City, vec![id.to_string()]),
+        ["characters"] => (EndpointType::Characters, vec![]),
+        ["character", id] => (EndpointType::Character, vec![id.to_string()]),
+        ["units"] => (EndpointType::Units, vec![]),
+        ["unit", id] => {
+            validate_integer(id)?;
+            (EndpointType::Unit, vec![id.to_string()])
+        }
+        ["tiles"] => (EndpointType::Tiles, vec![]),
+        ["tile", id] => {
+            validate_integer(id)?;
+            (EndpointType::Tile, vec![id.to_string()])
+        }
+        ["tile", x, y] => {
+            validate_integer(x)?;
+            validate_integer(y)?;
+            (EndpointType::TileByCoords, vec![x.to_string(), y.to_string()])
+        }
+        ["events"] => (EndpointType::TurnSummary, vec![]),
+        ["events", "characters"] => (EndpointType::TurnSummaryCharacters, vec![]),
+        ["events", "character", id] => (EndpointType::TurnSummaryCharacter, vec![id.to_string()]),
+        ["events", "units"] => (EndpointType::TurnSummaryUnits, vec![]),
+        ["events", "unit", id] => {
+            validate_integer(id)?;
+            (EndpointType::TurnSummaryUnit, vec![id.to_string()])
+        }
+        ["events", "cities"] => (EndpointType::TurnSummaryCities, vec![]),
+        ["events", "city", id] => (EndpointType::TurnSummaryCity, vec![id.to_string()]),
+        ["events", "wonders"] => (EndpointType::TurnSummaryWonders, vec![]),
+        ["tribes"] => (EndpointType::Tribes, vec![]),
+        ["tribe", tribe_type] => (EndpointType::Tribe, vec![tribe_type.to_string()]),
+        ["diplomacy", "teams"] => (EndpointType::DiplomacyTeams, vec![]),
+        ["alliances", "teams"] => (EndpointType::AlliancesTeams, vec![]),
+        ["diplomacy", "tribes"] => (EndpointType::DiplomacyTribes, vec![]),
+        ["alliances", "tribes"] => (EndpointType::AlliancesTribes, vec![]),
+        ["religions"] => (EndpointType::Religions, vec![]),
+        ["config"] => (EndpointType::Config, vec![]),
+        ["map"] => (EndpointType::Map, vec![]),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(matched))
+}
@@ -0,0 +1,139 @@
+//! Named connection profiles from `~/.config/owcli/config.toml`
+//!
+//! ```toml
+//! [profiles.default]
+//! host = "localhost"
+//!
+//! [profiles.laptop]
+//! host = "192.168.1.20"
+//! port = 9877
+//! format = "table"
+//! player = "rome"
+//! color = "always"
+//! timeout = "1m"
+//! wait = "2m"
+//! retries = 3
+//! ```
+//!
+//! `--profile laptop` (or `OWCLI_PROFILE`) selects a profile; without it the
+//! `default` profile applies if present. Flags and environment variables
+//! override every profile setting.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::cli::parse_duration;
+use crate::config::{ColorMode, OutputFormat};
+use crate::error::{OwcliError, Result};
+
+/// Settings of one profile; unset fields fall back to the built-in defaults
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub format: Option<OutputFormat>,
+    /// Player index or nation substituted for `me` in query paths
+    pub player: Option<String>,
+    pub color: Option<ColorMode>,
+    #[serde(default, deserialize_with = "duration")]
+    pub timeout: Option<Duration>,
+    #[serde(default, deserialize_with = "duration")]
+    pub wait: Option<Duration>,
+    pub retries: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    profiles: BTreeMap<String, Profile>,
+}
+
+impl Profile {
+    /// Load the named profile (or `default`) from `path`, or the standard config file
+    pub fn load(path: Option<&Path>, name: Option<&str>) -> Result<Self> {
+        let Some(path) = path.map(PathBuf::from).or_else(default_path) else {
+            return Ok(Self::default());
+        };
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content, name)
+                .map_err(|e| OwcliError::Other(format!("{}: {}", path.display(), e))),
+            Err(_) if name.is_none() => Ok(Self::default()),
+            Err(e) => Err(OwcliError::Other(format!(
+                "Cannot read profiles from {}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    fn parse(content: &str, name: Option<&str>) -> std::result::Result<Self, String> {
+        let mut file: ConfigFile = toml::from_str(content).map_err(|e| e.to_string())?;
+        match name {
+            Some(name) => file.profiles.remove(name).ok_or_else(|| {
+                let available: Vec<&str> = file.profiles.keys().map(String::as_str).collect();
+                format!(
+                    "unknown profile '{}' (available: {})",
+                    name,
+                    available.join(", ")
+                )
+            }),
+            None => Ok(file.profiles.remove("default").unwrap_or_default()),
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/owcli/config.toml`, falling back to `~/.config`
+fn default_path() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("owcli").join("config.toml"))
+}
+
+/// Durations are written like on the command line ("30s", "2m")
+fn duration<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Duration>, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    parse_duration(&s)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+[profiles.default]
+host = "localhost"
+
+[profiles.laptop]
+host = "192.168.1.20"
+format = "csv"
+player = "rome"
+timeout = "1m"
+"#;
+
+    #[test]
+    fn test_parse_profiles() {
+        let laptop = Profile::parse(CONFIG, Some("laptop")).unwrap();
+        assert_eq!(laptop.host.as_deref(), Some("192.168.1.20"));
+        assert_eq!(laptop.format, Some(OutputFormat::Csv));
+        assert_eq!(laptop.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(laptop.port, None);
+
+        let default = Profile::parse(CONFIG, None).unwrap();
+        assert_eq!(default.host.as_deref(), Some("localhost"));
+
+        let err = Profile::parse(CONFIG, Some("desktop")).unwrap_err();
+        assert!(err.contains("available: default, laptop"));
+        assert!(Profile::parse("[profiles.x]\nhots = \"a\"", Some("x")).is_err());
+    }
+}
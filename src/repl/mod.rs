@@ -3,10 +3,11 @@ mod completer;
 use crate::cli::{Cli, Commands};
 use crate::client::{command_succeeded, ApiClient};
 use crate::commands::{execute_action, execute_query};
-use crate::config::Config;
+use crate::config::{Config, OutputFormat};
 use crate::help::{
     generate_command_help, generate_commands_list, generate_overview_help, generate_queries_list,
 };
+use crate::output::names::Names;
 use crate::output::{format_command_response, format_typed_output};
 use clap::Parser;
 use completer::OwcliCompleter;
@@ -137,8 +138,9 @@ fn process_repl_line(client: &ApiClient, line: &str, rt: &Runtime) -> ReplResult
             };
             match result {
                 Ok(result) => {
-                    let output = format_typed_output(&result, false)
-                        .unwrap_or_else(|e| format!("Format error: {}", e));
+                    let output =
+                        format_typed_output(&result, OutputFormat::Table, None, &Names::default())
+                            .unwrap_or_else(|e| format!("Format error: {}", e));
                     ReplResult::Continue(Some(output))
                 }
                 Err(e) => ReplResult::Continue(Some(format!("Error: {}", e))),
@@ -147,8 +149,9 @@ fn process_repl_line(client: &ApiClient, line: &str, rt: &Runtime) -> ReplResult
 
         _ => match rt.block_on(execute_query(client, parts[0], None)) {
             Ok(result) => {
-                let output = format_typed_output(&result, false)
-                    .unwrap_or_else(|e| format!("Format error: {}", e));
+                let output =
+                    format_typed_output(&result, OutputFormat::Table, None, &Names::default())
+                        .unwrap_or_else(|e| format!("Format error: {}", e));
                 ReplResult::Continue(Some(output))
             }
             Err(e) => ReplResult::Continue(Some(format!("Error: {}", e))),
@@ -0,0 +1,361 @@
+//! Game snapshots
+//!
+//! `owcli snapshot save` captures the response of every GET route of the API
+//! into one JSON file, keyed by route (`/player/0/units`), and
+//! `--from-snapshot` answers queries from that file instead of the live API.
+//! Which routes are captured and how each endpoint is answered comes from
+//! SNAPSHOT_ENDPOINTS, generated from openapi.yaml.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::client::{decode_value, types, ApiClient};
+use crate::commands::lookup::{find_character, find_city, find_player, find_tribe};
+use crate::commands::query::{
+    decode_response, get_all_raw_tiles, snapshot_params, MAX_CONCURRENT_BATCHES, SNAPSHOT_ENDPOINTS,
+};
+use crate::error::{OwcliError, Result};
+use crate::output::TypedResponse;
+use crate::path_parser::{parse_path, EndpointType};
+
+/// Snapshot file format version, bumped on incompatible changes
+const SNAPSHOT_VERSION: u32 = 2;
+
+/// API responses by route, enough to answer queries offline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    pub routes: BTreeMap<String, Value>,
+}
+
+/// How a snapshot captures and answers one endpoint
+#[derive(Debug)]
+pub struct SnapshotEndpoint {
+    pub endpoint_type: EndpointType,
+    /// API route template, e.g. `/player/{index}/units`
+    pub route: &'static str,
+    /// Whether the API takes a `fields` query parameter on this route
+    pub accepts_fields: bool,
+    pub source: SnapshotSource,
+}
+
+#[derive(Debug)]
+pub enum SnapshotSource {
+    /// Captured as is
+    Route,
+    /// Captured once for every player
+    PerPlayer,
+    /// Captured like `tiles --all` and stored as one page holding every item
+    Paginated { items: &'static str },
+    /// Not captured; the item of the `list` route (under its `items` property,
+    /// if paginated) whose `keys` equal the path parameters
+    Item {
+        list: &'static str,
+        items: Option<&'static str>,
+        keys: &'static [&'static str],
+    },
+    /// Not captured; the items of the `list` route whose `key` equals the
+    /// path parameter, e.g. the events of one city in `/turn-summary/cities`
+    Filter {
+        list: &'static str,
+        key: &'static str,
+    },
+}
+
+/// The SNAPSHOT_ENDPOINTS entry of an endpoint
+pub fn endpoint(endpoint_type: &EndpointType) -> &'static SnapshotEndpoint {
+    SNAPSHOT_ENDPOINTS
+        .iter()
+        .find(|ep| ep.endpoint_type == *endpoint_type)
+        .expect("every endpoint is in SNAPSHOT_ENDPOINTS")
+}
+
+/// Replace the `{...}` segments of a route template with `params`, in order
+pub fn fill_route(template: &str, params: &[String]) -> String {
+    let mut params = params.iter();
+    template
+        .split('/')
+        .map(|segment| match segment.starts_with('{') {
+            true => params.next().map(String::as_str).unwrap_or(segment),
+            false => segment,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+impl Snapshot {
+    /// Capture every route of SNAPSHOT_ENDPOINTS from the live API
+    pub async fn capture(client: &ApiClient) -> Result<Self> {
+        let players = client.get_json("/players").await?;
+        let indexes: Vec<String> = players
+            .as_array()
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, p)| p["index"].as_i64().unwrap_or(i as i64).to_string())
+            .collect();
+
+        let mut requests = Vec::new();
+        for ep in SNAPSHOT_ENDPOINTS {
+            match ep.source {
+                SnapshotSource::Route => requests.push((ep.route.to_string(), None)),
+                SnapshotSource::Paginated { items } => {
+                    requests.push((ep.route.to_string(), Some(items)))
+                }
+                SnapshotSource::PerPlayer => requests.extend(
+                    indexes
+                        .iter()
+                        .map(|i| (fill_route(ep.route, std::slice::from_ref(i)), None)),
+                ),
+                SnapshotSource::Item { .. } | SnapshotSource::Filter { .. } => {}
+            }
+        }
+
+        // Limit concurrency as execute_all_tiles_query does (buffered preserves order)
+        let responses: Vec<(String, Value)> = stream::iter(requests)
+            .map(|(route, items)| async move {
+                let body = match items {
+                    Some(items) => {
+                        let all = get_all_raw_tiles(client, None).await?;
+                        let total = all.as_array().map_or(0, Vec::len);
+                        json!({
+                            items: all,
+                            "pagination": {
+                                "offset": 0,
+                                "limit": total,
+                                "total": total,
+                                "hasMore": false,
+                            },
+                        })
+                    }
+                    None => client.get_json(&route).await?,
+                };
+                Ok::<_, OwcliError>((route, body))
+            })
+            .buffered(MAX_CONCURRENT_BATCHES)
+            .try_collect()
+            .await?;
+
+        Ok(Self {
+            version: SNAPSHOT_VERSION,
+            routes: responses.into_iter().collect(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let value: Value = serde_json::from_reader(std::io::BufReader::new(file))?;
+        Self::from_value(value, path)
+    }
+
+    /// A snapshot read from `path`, checking its format version
+    pub fn from_value(value: Value, path: &Path) -> Result<Self> {
+        match value["version"].as_u64() {
+            Some(version) if version == SNAPSHOT_VERSION as u64 => {}
+            Some(version) => {
+                return Err(OwcliError::Other(format!(
+                    "Unsupported snapshot version {} in {} (expected {}); save it again",
+                    version,
+                    path.display(),
+                    SNAPSHOT_VERSION
+                )))
+            }
+            None => {
+                return Err(OwcliError::Other(format!(
+                    "{} is not a snapshot saved with `owcli snapshot save`",
+                    path.display()
+                )))
+            }
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer(std::io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    /// Answer a query path from the snapshot
+    pub fn query(&self, path_str: &str) -> Result<TypedResponse> {
+        let api_path = parse_path(path_str)?;
+        decode_response(&api_path.endpoint_type, self.query_json(path_str)?)
+    }
+
+    /// Answer a query path from the snapshot as undecoded JSON
+    pub fn query_json(&self, path_str: &str) -> Result<Value> {
+        let api_path = parse_path(path_str)?;
+        let params = snapshot_params(self, &api_path)?;
+        self.get(endpoint(&api_path.endpoint_type), &params)
+    }
+
+    /// The response of `endpoint` for the given API path parameters
+    pub fn get(&self, endpoint: &SnapshotEndpoint, params: &[String]) -> Result<Value> {
+        let route = fill_route(endpoint.route, params);
+        match endpoint.source {
+            SnapshotSource::Route
+            | SnapshotSource::PerPlayer
+            | SnapshotSource::Paginated { .. } => self.route(&route).cloned(),
+            SnapshotSource::Item { list, items, keys } => {
+                let mut list = self.route(list)?;
+                if let Some(items) = items {
+                    list = &list[items];
+                }
+                list.as_array()
+                    .into_iter()
+                    .flatten()
+                    .find(|item| {
+                        keys.iter()
+                            .zip(params)
+                            .all(|(k, p)| key_matches(&item[k], p))
+                    })
+                    .cloned()
+                    .ok_or_else(|| {
+                        OwcliError::NotFound(format!("'{}' is not in the snapshot", route))
+                    })
+            }
+            SnapshotSource::Filter { list, key } => {
+                let param = params.first().map(String::as_str).unwrap_or_default();
+                let items = self.route(list)?.as_array().into_iter().flatten();
+                Ok(items
+                    .filter(|item| key_matches(&item[key], param))
+                    .cloned()
+                    .collect())
+            }
+        }
+    }
+
+    /// A captured route
+    fn route(&self, route: &str) -> Result<&Value> {
+        self.routes
+            .get(route)
+            .ok_or_else(|| OwcliError::NotFound(format!("'{}' is not in the snapshot", route)))
+    }
+
+    /// A captured route decoded into its schema type
+    fn decode<T: DeserializeOwned>(&self, route: &str) -> Result<T> {
+        decode_value(self.route(route)?.clone())
+    }
+
+    /// The game state (`/state`)
+    pub fn state(&self) -> Result<types::GameState> {
+        self.decode("/state")
+    }
+
+    /// Every tile of the map (`/tiles`)
+    pub fn tiles(&self) -> Result<Vec<types::Tile>> {
+        match self.query("tiles")? {
+            TypedResponse::Tiles(tiles) => Ok(tiles),
+            _ => Err(OwcliError::Other("Unexpected response type".into())),
+        }
+    }
+
+    pub fn players(&self) -> Result<Vec<types::Player>> {
+        self.decode("/players")
+    }
+
+    pub fn cities(&self) -> Result<Vec<types::City>> {
+        self.decode("/cities")
+    }
+
+    pub fn characters(&self) -> Result<Vec<types::Character>> {
+        self.decode("/characters")
+    }
+
+    // Name lookups, as in commands::lookup
+
+    pub fn player_index(&self, param: &str) -> Result<i64> {
+        find_player(&self.players()?, param)
+    }
+
+    pub fn city_id(&self, param: &str) -> Result<i64> {
+        find_city(&self.cities()?, param)
+    }
+
+    pub fn character_id(&self, param: &str) -> Result<i64> {
+        find_character(&self.characters()?, param)
+    }
+
+    pub fn tribe_type(&self, param: &str) -> Result<String> {
+        find_tribe(&self.decode::<Vec<types::Tribe>>("/tribes")?, param)
+    }
+}
+
+/// Whether a property of a list item equals a path parameter
+fn key_matches(value: &Value, param: &str) -> bool {
+    match value {
+        Value::String(s) => s == param,
+        Value::Number(n) => n.to_string() == param,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> Snapshot {
+        serde_json::from_value(json!({
+            "version": SNAPSHOT_VERSION,
+            "routes": {
+                "/cities": [{"id": 1, "name": "Roma"}, {"id": 2, "name": "Carthage"}],
+                "/tiles": {
+                    "tiles": [{"id": 0, "x": 0, "y": 0}, {"id": 1, "x": 1, "y": 0}],
+                    "pagination": {"offset": 0, "limit": 2, "total": 2, "hasMore": false},
+                },
+                "/turn-summary/cities": [{"cityId": 1}, {"cityId": 2}, {"cityId": 1}],
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_query() {
+        let snapshot = snapshot();
+
+        match snapshot.query("city/roma").unwrap() {
+            TypedResponse::City(city) => assert_eq!(city.id, Some(1)),
+            other => panic!("unexpected response: {:?}", other.to_value()),
+        }
+        match snapshot.query("tile/1/0").unwrap() {
+            TypedResponse::TileByCoords(tile) => assert_eq!(tile.id, Some(1)),
+            other => panic!("unexpected response: {:?}", other.to_value()),
+        }
+        match snapshot.query("events/cities").unwrap() {
+            TypedResponse::TurnSummaryCities(events) => assert_eq!(events.len(), 3),
+            other => panic!("unexpected response: {:?}", other.to_value()),
+        }
+        match snapshot.query("events/city/Roma").unwrap() {
+            TypedResponse::TurnSummaryCity(events) => assert_eq!(events.len(), 2),
+            other => panic!("unexpected response: {:?}", other.to_value()),
+        }
+        assert_eq!(snapshot.tiles().unwrap().len(), 2);
+
+        assert!(matches!(
+            snapshot.query("tile/5/5"),
+            Err(OwcliError::NotFound(_))
+        ));
+        assert!(matches!(
+            snapshot.query("config"),
+            Err(OwcliError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_fill_route() {
+        assert_eq!(
+            fill_route("/player/{index}/units", &["3".to_string()]),
+            "/player/3/units"
+        );
+        assert_eq!(
+            fill_route("/tile/{x}/{y}", &["1".to_string(), "2".to_string()]),
+            "/tile/1/2"
+        );
+        assert_eq!(fill_route("/state", &[]), "/state");
+    }
+}
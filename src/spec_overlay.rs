@@ -0,0 +1,308 @@
+//! Local patches to openapi.yaml
+//!
+//! `openapi.yaml` is regenerated upstream, so known spec errors are fixed in
+//! `openapi.overlay.yaml` instead. The overlay is either a JSON merge patch
+//! (RFC 7386) or an OpenAPI Overlay document (`overlay: 1.0.0` with `actions`).
+//!
+//! Shared by build.rs and the generator binaries via `#[path]`, so every piece
+//! of generated code sees the same patched spec. The tests below run with the
+//! gen_queries binary only; the other generators set `test = false`.
+
+use serde_yaml::{Mapping, Value};
+use std::fs;
+
+pub const SPEC_PATH: &str = "openapi.yaml";
+pub const OVERLAY_PATH: &str = "openapi.overlay.yaml";
+
+/// Read openapi.yaml with openapi.overlay.yaml (if present) applied
+pub fn load_spec() -> Value {
+    let content = fs::read_to_string(SPEC_PATH).expect("Failed to read openapi.yaml");
+    let mut spec: Value = serde_yaml::from_str(&content).expect("Failed to parse openapi.yaml");
+
+    if let Ok(content) = fs::read_to_string(OVERLAY_PATH) {
+        let overlay: Value =
+            serde_yaml::from_str(&content).expect("Failed to parse openapi.overlay.yaml");
+        apply_overlay(&mut spec, &overlay)
+            .unwrap_or_else(|e| panic!("Failed to apply openapi.overlay.yaml: {}", e));
+    }
+    spec
+}
+
+/// Apply an Overlay document or a JSON merge patch to the spec
+pub fn apply_overlay(spec: &mut Value, overlay: &Value) -> Result<(), String> {
+    if overlay.get("overlay").is_none() {
+        merge_patch(spec, overlay);
+        return Ok(());
+    }
+
+    let actions = overlay["actions"]
+        .as_sequence()
+        .ok_or("overlay document has no actions")?;
+    for action in actions {
+        let target = action["target"]
+            .as_str()
+            .ok_or("overlay action has no target")?;
+        let path = parse_json_path(target)?;
+
+        // A typo in the overlay must fail the build, not leave the spec unpatched
+        let matched = if action["remove"].as_bool() == Some(true) {
+            let (last, parent) = path.split_last().ok_or("cannot remove the root")?;
+            let mut removed = 0;
+            visit(spec, parent, &mut |value| {
+                if remove(value, last) {
+                    removed += 1;
+                }
+            });
+            removed
+        } else if let Some(update) = action.get("update") {
+            visit(spec, &path, &mut |value| deep_merge(value, update))
+        } else {
+            return Err(format!(
+                "overlay action for '{}' has neither `update` nor `remove: true`",
+                target
+            ));
+        };
+        if matched == 0 {
+            return Err(format!("overlay target '{}' matches nothing", target));
+        }
+    }
+    Ok(())
+}
+
+/// RFC 7386: objects merge recursively, null deletes a key, anything else replaces
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Mapping(patch) = patch else {
+        // An empty overlay file parses as null and changes nothing
+        if !patch.is_null() {
+            *target = patch.clone();
+        }
+        return;
+    };
+    if !target.is_mapping() {
+        *target = Value::Mapping(Mapping::new());
+    }
+    let Value::Mapping(target) = target else {
+        unreachable!()
+    };
+
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// Overlay `update`: objects merge recursively, arrays are appended to, anything else replaces
+fn deep_merge(target: &mut Value, update: &Value) {
+    match (target, update) {
+        (Value::Mapping(target), Value::Mapping(update)) => {
+            for (key, value) in update {
+                match target.get_mut(key) {
+                    Some(existing) => deep_merge(existing, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Sequence(target), Value::Sequence(update)) => target.extend(update.iter().cloned()),
+        (Value::Sequence(target), update) => target.push(update.clone()),
+        (target, update) => *target = update.clone(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Selector {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Parse the JSONPath subset used by overlays: `$.a.b`, `$['/path/{id}']`, `[0]` and `*`
+fn parse_json_path(path: &str) -> Result<Vec<Selector>, String> {
+    let invalid = || format!("unsupported JSONPath '{}'", path);
+    let mut rest = path.strip_prefix('$').ok_or_else(invalid)?;
+    let mut selectors = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            let name = &after[..end];
+            if name.is_empty() {
+                return Err(invalid());
+            }
+            selectors.push(match name {
+                "*" => Selector::Wildcard,
+                _ => Selector::Key(name.to_string()),
+            });
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let quote = after.chars().next().filter(|c| *c == '\'' || *c == '"');
+            let (selector, len) = match quote {
+                Some(quote) => {
+                    let end = after[1..].find(quote).ok_or_else(invalid)? + 1;
+                    (Selector::Key(after[1..end].to_string()), end + 1)
+                }
+                None => {
+                    let end = after.find(']').ok_or_else(invalid)?;
+                    match &after[..end] {
+                        "*" => (Selector::Wildcard, end),
+                        index => (Selector::Index(index.parse().map_err(|_| invalid())?), end),
+                    }
+                }
+            };
+            rest = after[len..].strip_prefix(']').ok_or_else(invalid)?;
+            selectors.push(selector);
+        } else {
+            return Err(invalid());
+        }
+    }
+    Ok(selectors)
+}
+
+/// Call `f` on every value matched by `path`, returning how many matched
+fn visit(value: &mut Value, path: &[Selector], f: &mut dyn FnMut(&mut Value)) -> usize {
+    let Some((selector, rest)) = path.split_first() else {
+        f(value);
+        return 1;
+    };
+    match (selector, value) {
+        (Selector::Key(key), Value::Mapping(map)) => map
+            .get_mut(key.as_str())
+            .map_or(0, |child| visit(child, rest, f)),
+        (Selector::Index(index), Value::Sequence(items)) => items
+            .get_mut(*index)
+            .map_or(0, |child| visit(child, rest, f)),
+        (Selector::Wildcard, Value::Mapping(map)) => {
+            map.values_mut().map(|child| visit(child, rest, f)).sum()
+        }
+        (Selector::Wildcard, Value::Sequence(items)) => {
+            items.iter_mut().map(|child| visit(child, rest, f)).sum()
+        }
+        _ => 0,
+    }
+}
+
+/// Remove the children of `parent` selected by `selector`, returning whether
+/// there were any
+fn remove(parent: &mut Value, selector: &Selector) -> bool {
+    match (selector, parent) {
+        (Selector::Key(key), Value::Mapping(map)) => map.remove(key.as_str()).is_some(),
+        (Selector::Index(index), Value::Sequence(items)) if *index < items.len() => {
+            items.remove(*index);
+            true
+        }
+        (Selector::Wildcard, Value::Mapping(map)) => {
+            let any = !map.is_empty();
+            map.clear();
+            any
+        }
+        (Selector::Wildcard, Value::Sequence(items)) => {
+            let any = !items.is_empty();
+            items.clear();
+            any
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn yaml(s: &str) -> Value {
+        serde_yaml::from_str(s).unwrap()
+    }
+
+    const SPEC: &str = "
+paths:
+  /city/{id}:
+    get: {operationId: getCity}
+components:
+  schemas:
+    City:
+      required: [id]
+      properties:
+        name: {type: string}
+        hurryCost: {type: integer}
+";
+
+    #[test]
+    fn test_merge_patch() {
+        let mut spec = yaml(SPEC);
+        let patch = yaml(
+            "components: {schemas: {City: {properties: {name: {nullable: true}, hurryCost: null}}}}",
+        );
+        apply_overlay(&mut spec, &patch).unwrap();
+
+        let properties = &spec["components"]["schemas"]["City"]["properties"];
+        assert_eq!(properties["name"], yaml("{type: string, nullable: true}"));
+        assert!(properties.get("hurryCost").is_none());
+    }
+
+    #[test]
+    fn test_overlay_actions() {
+        let mut spec = yaml(SPEC);
+        let overlay = yaml(
+            "
+overlay: 1.0.0
+info: {title: fixes, version: 1}
+actions:
+  - target: $.components.schemas.City.properties.hurryCost
+    update: {type: number}
+  - target: $.components.schemas.City.required
+    update: name
+  - target: $.paths['/city/{id}'].get.operationId
+    remove: true
+  - target: $.components.schemas.*.properties.name
+    update: {nullable: true}
+",
+        );
+        apply_overlay(&mut spec, &overlay).unwrap();
+
+        let city = &spec["components"]["schemas"]["City"];
+        assert_eq!(city["properties"]["hurryCost"]["type"], yaml("number"));
+        assert_eq!(city["required"], yaml("[id, name]"));
+        assert_eq!(city["properties"]["name"]["nullable"], yaml("true"));
+        assert!(spec["paths"]["/city/{id}"]["get"]
+            .get("operationId")
+            .is_none());
+    }
+
+    #[test]
+    fn test_overlay_errors() {
+        let apply = |actions: &str| {
+            let overlay = yaml(&format!("{{overlay: 1.0.0, actions: [{}]}}", actions));
+            apply_overlay(&mut yaml(SPEC), &overlay)
+        };
+
+        // A misspelled key leaves nothing to update or remove
+        let err = apply("{target: $.components.schemas.Cty.required, update: name}").unwrap_err();
+        assert!(err.contains("matches nothing"), "{}", err);
+        let err = apply(r#"{target: "$.paths['/city/{id}'].get.operationID", remove: true}"#)
+            .unwrap_err();
+        assert!(err.contains("matches nothing"), "{}", err);
+
+        // An action must say what to do with its target
+        let err = apply("{target: $.components.schemas.City, remove: yes}").unwrap_err();
+        assert!(err.contains("neither"), "{}", err);
+        let err = apply("{target: $.components.schemas.City, updates: {}}").unwrap_err();
+        assert!(err.contains("neither"), "{}", err);
+    }
+
+    #[test]
+    fn test_parse_json_path() {
+        assert_eq!(
+            parse_json_path("$.paths['/tile/{x}'][0].*").unwrap(),
+            vec![
+                Selector::Key("paths".into()),
+                Selector::Key("/tile/{x}".into()),
+                Selector::Index(0),
+                Selector::Wildcard,
+            ]
+        );
+        assert!(parse_json_path("paths.x").is_err());
+    }
+}
@@ -0,0 +1,815 @@
+//! API spec validation
+//!
+//! Fetches every GET endpoint in `openapi.yaml` and checks the raw JSON against
+//! its schema, reporting fields whose type, nullability or presence differs from
+//! the spec. Path parameters are filled with IDs found in the list endpoints.
+//!
+//! The upstream spec is checked as is, without openapi.overlay.yaml, so the
+//! report keeps showing the errors the overlay works around until upstream
+//! fixes them.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use serde_yaml::Value as Schema;
+
+use crate::client::ApiClient;
+use crate::error::Result;
+use crate::mock_server::respond_get;
+
+/// The spec owcli was built from, with openapi.overlay.yaml applied
+pub(crate) const SPEC: &str = include_str!(concat!(env!("OUT_DIR"), "/openapi.yaml"));
+
+/// openapi.yaml as copied from upstream, which validate-api checks
+const UPSTREAM_SPEC: &str = include_str!("../openapi.yaml");
+
+/// Items checked per list response, and per array nested in an entity
+const MAX_LIST_ITEMS: usize = 5;
+const MAX_NESTED_ITEMS: usize = 10;
+
+/// Example values are cut to this many characters
+const MAX_VALUE_LEN: usize = 50;
+
+/// List endpoints that supply path parameters: the path segment before the
+/// parameter, the list to fetch and the ID fields to try
+const ID_SOURCES: &[(&str, &str, &[&str])] = &[
+    // The API reports playerInt when index is missing
+    ("player", "/players", &["index", "playerInt"]),
+    ("city", "/cities", &["id"]),
+    ("character", "/characters", &["id"]),
+    ("unit", "/units", &["id"]),
+    ("tile", "/tiles?limit=10", &["id"]),
+    ("tribe", "/tribes", &["tribeType"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    TypeMismatch,
+    UnexpectedNull,
+    ExtraField,
+    MissingRequired,
+    EnumMismatch,
+    MissingOptional,
+}
+
+impl IssueKind {
+    const ALL: [IssueKind; 6] = [
+        IssueKind::TypeMismatch,
+        IssueKind::UnexpectedNull,
+        IssueKind::ExtraField,
+        IssueKind::MissingRequired,
+        IssueKind::EnumMismatch,
+        IssueKind::MissingOptional,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            IssueKind::TypeMismatch => "Type mismatch",
+            IssueKind::UnexpectedNull => "Unexpected null",
+            IssueKind::ExtraField => "Extra field (in response, not in spec)",
+            IssueKind::MissingRequired => "Missing required field",
+            IssueKind::EnumMismatch => "Enum value mismatch",
+            IssueKind::MissingOptional => "Missing optional field",
+        }
+    }
+
+    /// Report section title and explanation; missing optional fields are only counted
+    fn section(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            IssueKind::TypeMismatch => Some((
+                "Type Mismatches (Critical)",
+                "These fields have incorrect types in the spec and will cause deserialization failures.",
+            )),
+            IssueKind::UnexpectedNull => Some((
+                "Unexpected Nulls",
+                "These fields are null in responses but not nullable in the spec.",
+            )),
+            IssueKind::ExtraField => Some((
+                "Extra Fields (Spec Missing)",
+                "These fields appear in API responses but are not defined in the spec.",
+            )),
+            IssueKind::MissingRequired => Some((
+                "Missing Required Fields",
+                "These fields are marked required in spec but missing from responses.",
+            )),
+            IssueKind::EnumMismatch => Some((
+                "Enum Mismatches",
+                "These values are not among the enum values defined in the spec.",
+            )),
+            IssueKind::MissingOptional => None,
+        }
+    }
+}
+
+/// A difference between a response and its schema
+#[derive(Debug, Clone, Serialize)]
+pub struct Issue {
+    pub schema: String,
+    pub path: String,
+    pub issue: IssueKind,
+    pub expected: Option<String>,
+    pub actual: String,
+    pub value: Option<String>,
+}
+
+/// Outcome of checking one endpoint
+#[derive(Debug, Serialize)]
+pub struct EndpointResult {
+    pub path: String,
+    pub schema: String,
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl EndpointResult {
+    fn error(path: &str, schema: &str, error: String) -> Self {
+        Self {
+            path: path.to_string(),
+            schema: schema.to_string(),
+            status: "error",
+            issues: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// A GET endpoint whose 200 response is a schema or an array of one
+pub(crate) struct Endpoint {
+    pub path: String,
+    pub schema: String,
+    is_array: bool,
+}
+
+/// Validate every GET endpoint of the upstream spec against the API (or `--from-snapshot`)
+pub async fn validate_api(client: &ApiClient) -> Result<Report> {
+    let spec: Schema = serde_yaml::from_str(UPSTREAM_SPEC)?;
+    let schemas = spec["components"]["schemas"]
+        .as_mapping()
+        .cloned()
+        .unwrap_or_default();
+    let mut validator = Validator {
+        schemas: &schemas,
+        issues: Vec::new(),
+    };
+
+    eprintln!("Discovering IDs from list endpoints...");
+    let samples = discover_samples(client).await;
+
+    let endpoints = spec_endpoints(&spec);
+    eprintln!("Testing {} endpoints...", endpoints.len());
+    let mut results = Vec::new();
+    for endpoint in endpoints {
+        let path = match fill_params(&endpoint.path, &samples) {
+            Ok(path) => path,
+            Err(e) => {
+                eprintln!("  {} ... SKIP ({})", endpoint.path, e);
+                results.push(EndpointResult::error(&endpoint.path, &endpoint.schema, e));
+                continue;
+            }
+        };
+
+        let data = match fetch_raw(client, &path).await {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("  {} ... ERROR: {}", path, e);
+                results.push(EndpointResult::error(&path, &endpoint.schema, e));
+                continue;
+            }
+        };
+        let Some(schema) = schemas.get(endpoint.schema.as_str()) else {
+            let e = format!("Schema not found: {}", endpoint.schema);
+            eprintln!("  {} ... SKIP ({})", path, e);
+            results.push(EndpointResult::error(&path, &endpoint.schema, e));
+            continue;
+        };
+
+        let before = validator.issues.len();
+        match data.as_array() {
+            Some(items) if endpoint.is_array => {
+                for (i, item) in items.iter().take(MAX_LIST_ITEMS).enumerate() {
+                    validator.validate(item, schema, &format!("[{}]", i), &endpoint.schema);
+                }
+            }
+            _ => validator.validate(&data, schema, "", &endpoint.schema),
+        }
+        let issues = validator.issues.len() - before;
+
+        if issues > 0 {
+            eprintln!("  {} ... ISSUES: {}", path, issues);
+        } else {
+            eprintln!("  {} ... OK", path);
+        }
+        results.push(EndpointResult {
+            path,
+            schema: endpoint.schema,
+            status: "ok",
+            issues: Some(issues),
+            error: None,
+        });
+    }
+
+    eprintln!("Total issues found: {}", validator.issues.len());
+    Ok(Report {
+        generated: chrono::Utc::now()
+            .format("%Y-%m-%d %H:%M:%S UTC")
+            .to_string(),
+        results,
+        issues: validator.issues,
+    })
+}
+
+/// GET endpoints in spec order. Paginated endpoints get a small `limit`.
+pub(crate) fn spec_endpoints(spec: &Schema) -> Vec<Endpoint> {
+    let Some(paths) = spec["paths"].as_mapping() else {
+        return Vec::new();
+    };
+
+    paths
+        .iter()
+        .filter_map(|(path, item)| {
+            let get = &item["get"];
+            let schema = &get["responses"]["200"]["content"]["application/json"]["schema"];
+            let (name, is_array) = match ref_name(schema) {
+                Some(name) => (name, false),
+                None => (ref_name(&schema["items"])?, true),
+            };
+
+            let paginated = get["parameters"]
+                .as_sequence()
+                .is_some_and(|params| params.iter().any(|p| p["name"].as_str() == Some("limit")));
+            let path = path.as_str()?;
+            Some(Endpoint {
+                path: if paginated {
+                    format!("{}?limit=10", path)
+                } else {
+                    path.to_string()
+                },
+                schema: name.to_string(),
+                is_array,
+            })
+        })
+        .collect()
+}
+
+/// First entity of each ID source list, keyed by path segment
+async fn discover_samples(client: &ApiClient) -> BTreeMap<&'static str, Value> {
+    let mut samples = BTreeMap::new();
+    for (segment, list_path, _) in ID_SOURCES {
+        let Ok(mut data) = fetch_raw(client, list_path).await else {
+            continue;
+        };
+        // Paginated responses wrap the list
+        if let Some(tiles) = data.get_mut("tiles") {
+            data = tiles.take();
+        }
+        if let Some(first) = data.as_array().and_then(|items| items.first()) {
+            eprintln!(
+                "  {}: {}",
+                list_path,
+                first.get("id").unwrap_or(&Value::Null)
+            );
+            samples.insert(*segment, first.clone());
+        }
+    }
+    samples
+}
+
+/// Replace `{param}` segments with values from the sample entity named by the
+/// nearest preceding literal segment (e.g., `/tile/{x}/{y}` uses a tile's x and y)
+fn fill_params(
+    template: &str,
+    samples: &BTreeMap<&str, Value>,
+) -> std::result::Result<String, String> {
+    let mut source = None;
+    let mut segments = Vec::new();
+
+    for segment in template.split('/') {
+        let Some(param) = segment.strip_prefix('{').and_then(|s| s.strip_suffix('}')) else {
+            source = ID_SOURCES.iter().find(|(name, _, _)| *name == segment);
+            segments.push(segment.to_string());
+            continue;
+        };
+
+        let (name, list_path, id_fields) =
+            source.ok_or_else(|| format!("No ID source for {{{}}}", param))?;
+        let sample = samples
+            .get(name)
+            .ok_or_else(|| format!("No IDs discovered from {}", list_path))?;
+        let value = std::iter::once(param)
+            .chain(id_fields.iter().copied())
+            .find_map(|field| sample.get(field).filter(|v| !v.is_null()))
+            .ok_or_else(|| format!("No {} in {}", param, list_path))?;
+
+        segments.push(match value {
+            Value::String(s) => s.clone(),
+            v => v.to_string(),
+        });
+    }
+    Ok(segments.join("/"))
+}
+
+/// GET a path as raw JSON, from the snapshot when `--from-snapshot` is set.
+/// Failures become the issue text shown in the report.
+async fn fetch_raw(client: &ApiClient, path: &str) -> std::result::Result<Value, String> {
+    if let Some(snapshot) = &client.snapshot {
+        let (route, query) = match path.split_once('?') {
+            Some((route, query)) => (route, Some(query)),
+            None => (path, None),
+        };
+        let (status, body) = respond_get(snapshot, route, query);
+        return match status.as_u16() {
+            200 => Ok(body),
+            code => Err(format!(
+                "{} {}",
+                code,
+                body["error"].as_str().unwrap_or_default()
+            )),
+        };
+    }
+
+    // Through get_json, so --wait and --retries apply as for any query
+    client.get_json(path).await.map_err(|e| e.to_string())
+}
+
+/// Walks a response alongside its schema, collecting issues
+struct Validator<'a> {
+    schemas: &'a serde_yaml::Mapping,
+    issues: Vec<Issue>,
+}
+
+impl Validator<'_> {
+    fn validate(&mut self, data: &Value, schema: &Schema, path: &str, schema_name: &str) {
+        if let Some(name) = ref_name(schema) {
+            if let Some(resolved) = self.schemas.get(name) {
+                self.validate(data, resolved, path, name);
+            }
+            return;
+        }
+
+        let expected = schema["type"].as_str();
+        if data.is_null() {
+            if !schema["nullable"].as_bool().unwrap_or(false) {
+                let expected = expected.map(String::from);
+                self.push(
+                    schema_name,
+                    path,
+                    IssueKind::UnexpectedNull,
+                    expected,
+                    "null".into(),
+                    None,
+                );
+            }
+            return;
+        }
+
+        let matches = match expected {
+            Some("object") => data.is_object(),
+            Some("array") => data.is_array(),
+            Some("string") => data.is_string(),
+            Some("integer") => data.is_i64() || data.is_u64(),
+            Some("number") => data.is_number(),
+            Some("boolean") => data.is_boolean(),
+            _ => true,
+        };
+        if !matches {
+            self.push(
+                schema_name,
+                path,
+                IssueKind::TypeMismatch,
+                expected.map(String::from),
+                actual_type(data),
+                Some(truncate(data)),
+            );
+            return;
+        }
+
+        match data {
+            Value::Object(object) => self.validate_object(object, schema, path, schema_name),
+            Value::Array(items) => {
+                for (i, item) in items.iter().take(MAX_NESTED_ITEMS).enumerate() {
+                    let item_path = format!("{}[{}]", path, i);
+                    self.validate(item, &schema["items"], &item_path, schema_name);
+                }
+            }
+            Value::String(s) => {
+                if let Some(values) = schema["enum"].as_sequence() {
+                    if !values.iter().any(|v| v.as_str() == Some(s)) {
+                        self.push(
+                            schema_name,
+                            path,
+                            IssueKind::EnumMismatch,
+                            Some(format!("one of {} enum values", values.len())),
+                            format!("\"{}\"", s),
+                            Some(s.clone()),
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_object(
+        &mut self,
+        object: &serde_json::Map<String, Value>,
+        schema: &Schema,
+        path: &str,
+        schema_name: &str,
+    ) {
+        // Maps validate each value against additionalProperties
+        if schema["additionalProperties"].is_mapping() {
+            for (key, value) in object {
+                let field_path = join(path, key);
+                self.validate(
+                    value,
+                    &schema["additionalProperties"],
+                    &field_path,
+                    schema_name,
+                );
+            }
+            return;
+        }
+
+        let properties = schema["properties"].as_mapping();
+        for (key, value) in object {
+            let field_path = join(path, key);
+            match properties.and_then(|p| p.get(key.as_str())) {
+                Some(field_schema) => self.validate(value, field_schema, &field_path, schema_name),
+                None => self.push(
+                    schema_name,
+                    &field_path,
+                    IssueKind::ExtraField,
+                    None,
+                    actual_type(value),
+                    Some(truncate(value)),
+                ),
+            }
+        }
+
+        // Required fields may be listed without being declared under properties
+        let required: Vec<&str> = schema["required"]
+            .as_sequence()
+            .map(|fields| fields.iter().filter_map(Schema::as_str).collect())
+            .unwrap_or_default();
+        for field in &required {
+            if !object.contains_key(*field) {
+                self.push_missing(
+                    schema_name,
+                    path,
+                    field,
+                    &schema["properties"][*field],
+                    IssueKind::MissingRequired,
+                );
+            }
+        }
+
+        for (field, field_schema) in properties.into_iter().flatten() {
+            let Some(field) = field.as_str() else {
+                continue;
+            };
+            if object.contains_key(field)
+                || required.contains(&field)
+                || field_schema["nullable"].as_bool().unwrap_or(false)
+            {
+                continue;
+            }
+            self.push_missing(
+                schema_name,
+                path,
+                field,
+                field_schema,
+                IssueKind::MissingOptional,
+            );
+        }
+    }
+
+    fn push_missing(
+        &mut self,
+        schema_name: &str,
+        path: &str,
+        field: &str,
+        field_schema: &Schema,
+        kind: IssueKind,
+    ) {
+        let expected = Some(type_description(field_schema));
+        self.push(
+            schema_name,
+            &join(path, field),
+            kind,
+            expected,
+            "missing".into(),
+            None,
+        );
+    }
+
+    fn push(
+        &mut self,
+        schema: &str,
+        path: &str,
+        issue: IssueKind,
+        expected: Option<String>,
+        actual: String,
+        value: Option<String>,
+    ) {
+        self.issues.push(Issue {
+            schema: schema.to_string(),
+            path: if path.is_empty() { "(root)" } else { path }.to_string(),
+            issue,
+            expected,
+            actual,
+            value,
+        });
+    }
+}
+
+fn ref_name(schema: &Schema) -> Option<&str> {
+    schema["$ref"]
+        .as_str()?
+        .strip_prefix("#/components/schemas/")
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Describe a schema type (e.g., "array<Unit>", "object<string, integer>")
+fn type_description(schema: &Schema) -> String {
+    if let Some(name) = ref_name(schema) {
+        return name.to_string();
+    }
+    if let Some(values) = schema["enum"].as_sequence() {
+        return format!("enum[{} values]", values.len());
+    }
+    match schema["type"].as_str() {
+        Some("array") => format!("array<{}>", type_description(&schema["items"])),
+        Some("object") if schema["additionalProperties"].is_mapping() => format!(
+            "object<string, {}>",
+            type_description(&schema["additionalProperties"])
+        ),
+        Some(t) => t.to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Describe the JSON type of a value (e.g., "array<string>", "array<empty>")
+fn actual_type(value: &Value) -> String {
+    match value {
+        Value::Null => "null".into(),
+        Value::Bool(_) => "boolean".into(),
+        Value::Number(n) if n.is_f64() => "number".into(),
+        Value::Number(_) => "integer".into(),
+        Value::String(_) => "string".into(),
+        Value::Array(items) => match items.first() {
+            Some(first) => format!("array<{}>", actual_type(first)),
+            None => "array<empty>".into(),
+        },
+        Value::Object(_) => "object".into(),
+    }
+}
+
+fn truncate(value: &Value) -> String {
+    let s = match value {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    };
+    match s.char_indices().nth(MAX_VALUE_LEN) {
+        Some((end, _)) => format!("{}...", &s[..end]),
+        None => s,
+    }
+}
+
+/// Report path without array indices or the leading dot (e.g., "characters.age")
+fn display_path(path: &str) -> String {
+    let mut out = String::new();
+    let mut in_index = false;
+    for c in path.chars() {
+        match c {
+            '[' => in_index = true,
+            ']' => in_index = false,
+            _ if !in_index => out.push(c),
+            _ => {}
+        }
+    }
+    out.trim_start_matches('.').to_string()
+}
+
+/// Validation results for all endpoints
+pub struct Report {
+    generated: String,
+    results: Vec<EndpointResult>,
+    issues: Vec<Issue>,
+}
+
+impl Report {
+    /// First issue per kind, schema and field, ignoring array indices
+    fn unique_issues(&self) -> BTreeMap<(IssueKind, &str, String), &Issue> {
+        let mut unique = BTreeMap::new();
+        for issue in &self.issues {
+            let key = (
+                issue.issue,
+                issue.schema.as_str(),
+                display_path(&issue.path),
+            );
+            unique.entry(key).or_insert(issue);
+        }
+        unique
+    }
+
+    fn count(&self, status: &str) -> usize {
+        self.results.iter().filter(|r| r.status == status).count()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let unique = self.unique_issues();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# API Spec Validation Report\n");
+        let _ = writeln!(out, "Generated: {}\n", self.generated);
+        let _ = writeln!(out, "## Summary\n");
+        let _ = writeln!(out, "- **Endpoints tested:** {}", self.results.len());
+        let _ = writeln!(out, "- **Successful:** {}", self.count("ok"));
+        let _ = writeln!(out, "- **Failed to fetch:** {}", self.count("error"));
+        let _ = writeln!(out, "- **Unique schema issues:** {}\n", unique.len());
+
+        let _ = writeln!(out, "### Issues by Type\n");
+        let _ = writeln!(out, "| Type | Count |");
+        let _ = writeln!(out, "|------|-------|");
+        for kind in IssueKind::ALL {
+            let count = unique.keys().filter(|(k, _, _)| *k == kind).count();
+            let _ = writeln!(out, "| {} | {} |", kind.label(), count);
+        }
+        let _ = writeln!(out);
+
+        if self.count("error") > 0 {
+            let _ = writeln!(out, "## Connection Errors\n");
+            for r in self.results.iter().filter(|r| r.status == "error") {
+                let error = r.error.as_deref().unwrap_or_default();
+                let _ = writeln!(out, "- `{}`: {}", r.path, error);
+            }
+            let _ = writeln!(out);
+        }
+
+        for kind in IssueKind::ALL {
+            let Some((title, intro)) = kind.section() else {
+                continue;
+            };
+            let mut issues = unique.iter().filter(|((k, _, _), _)| *k == kind).peekable();
+            if issues.peek().is_none() {
+                continue;
+            }
+            let _ = writeln!(out, "## {}\n\n{}\n", title, intro);
+
+            let mut current_schema = None;
+            for ((_, schema, path), issue) in issues {
+                if current_schema != Some(*schema) {
+                    if current_schema.is_some() {
+                        let _ = writeln!(out);
+                    }
+                    current_schema = Some(*schema);
+                    let _ = writeln!(out, "### {}\n", schema);
+                    let _ = writeln!(out, "| Field | Spec Type | Actual Type | Example Value |");
+                    let _ = writeln!(out, "|-------|-----------|-------------|---------------|");
+                }
+                let value = match &issue.value {
+                    Some(v) => format!(
+                        "`{}`",
+                        v.replace('|', "\\|").chars().take(40).collect::<String>()
+                    ),
+                    None => "-".to_string(),
+                };
+                let expected = issue.expected.as_deref().unwrap_or("-");
+                let _ = writeln!(
+                    out,
+                    "| `{}` | {} | {} | {} |",
+                    path, expected, issue.actual, value
+                );
+            }
+            let _ = writeln!(out);
+        }
+
+        let _ = writeln!(out, "## Endpoints Tested\n");
+        let _ = writeln!(out, "| Endpoint | Schema | Status |");
+        let _ = writeln!(out, "|----------|--------|--------|");
+        for r in &self.results {
+            let status = match (&r.error, r.issues) {
+                (Some(e), _) => format!("error: {}", e.chars().take(30).collect::<String>()),
+                (None, Some(n)) if n > 0 => format!("{} issues", n),
+                _ => "ok".to_string(),
+            };
+            let _ = writeln!(out, "| `{}` | {} | {} |", r.path, r.schema, status);
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        let unique = self.unique_issues();
+        let issues_by_type: serde_json::Map<String, Value> = IssueKind::ALL
+            .iter()
+            .map(|kind| {
+                let name = serde_json::to_value(kind).unwrap_or_default();
+                let count = unique.keys().filter(|(k, _, _)| k == kind).count();
+                (name.as_str().unwrap_or_default().to_string(), json!(count))
+            })
+            .collect();
+
+        let report = json!({
+            "generated": self.generated,
+            "summary": {
+                "endpoints_tested": self.results.len(),
+                "successful": self.count("ok"),
+                "failed": self.count("error"),
+                "unique_issues": unique.len(),
+            },
+            "issues_by_type": issues_by_type,
+            "results": self.results,
+            "issues": self.issues,
+        });
+        Ok(serde_json::to_string_pretty(&report)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(data: Value, schema: &str) -> Vec<(IssueKind, String)> {
+        let schemas: serde_yaml::Mapping = serde_yaml::from_str(schema).unwrap();
+        let mut validator = Validator {
+            schemas: &schemas,
+            issues: Vec::new(),
+        };
+        validator.validate(&data, &schemas["City"], "", "City");
+        validator
+            .issues
+            .into_iter()
+            .map(|i| (i.issue, i.path))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        let schema = r#"
+City:
+  type: object
+  properties:
+    id: {type: integer}
+    name: {type: string}
+    governorID: {type: integer, nullable: true}
+    tiles: {type: array, items: {type: integer}}
+  required: [id, ownerID]
+"#;
+        let issues = validate(
+            json!({"id": "7", "name": null, "tiles": [1, 2.5], "extra": true}),
+            schema,
+        );
+        assert_eq!(
+            issues,
+            vec![
+                (IssueKind::ExtraField, "extra".to_string()),
+                (IssueKind::TypeMismatch, "id".to_string()),
+                (IssueKind::UnexpectedNull, "name".to_string()),
+                (IssueKind::TypeMismatch, "tiles[1]".to_string()),
+                // Required even though it isn't declared under properties
+                (IssueKind::MissingRequired, "ownerID".to_string()),
+            ]
+        );
+
+        let issues = validate(json!({"name": "Roma"}), schema);
+        assert_eq!(
+            issues,
+            vec![
+                (IssueKind::MissingRequired, "id".to_string()),
+                (IssueKind::MissingRequired, "ownerID".to_string()),
+                (IssueKind::MissingOptional, "tiles".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fill_params() {
+        let mut samples = BTreeMap::new();
+        samples.insert("tile", json!({"id": 3, "x": 5, "y": 12}));
+        samples.insert("player", json!({"playerInt": 0}));
+
+        assert_eq!(
+            fill_params("/tile/{x}/{y}", &samples).unwrap(),
+            "/tile/5/12"
+        );
+        assert_eq!(
+            fill_params("/player/{index}/units", &samples).unwrap(),
+            "/player/0/units"
+        );
+        assert!(fill_params("/city/{id}", &samples).is_err());
+        assert_eq!(display_path("[0].cities[3].name"), "cities.name");
+    }
+}
@@ -0,0 +1,195 @@
+//! Watch mode: re-run a query on a timer and highlight what changed
+//!
+//! Each poll is rendered with the normal output formatter and redrawn in place
+//! on the alternate screen. Output is compared with the previous poll line by
+//! line, and within a line cell by cell (table columns, or the value of a
+//! `Field: value` line), so only the values that changed are highlighted.
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::style::Stylize;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::client::ApiClient;
+use crate::commands::execute_query;
+use crate::config::{Config, OutputFormat};
+use crate::error::Result;
+use crate::listing::ListOptions;
+use crate::output::format_typed_output;
+use crate::output::names::Names;
+
+/// Poll `path` every `interval` until interrupted
+pub async fn run_watch(
+    config: &Config,
+    path: &str,
+    interval: Duration,
+    fields: Option<&str>,
+    list_options: &ListOptions,
+) -> Result<()> {
+    let client = ApiClient::new(config)?;
+
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    let result = tokio::select! {
+        result = poll(&client, config, path, interval, fields, list_options) => result,
+        _ = tokio::signal::ctrl_c() => Ok(()),
+    };
+
+    // Restore the terminal even if polling failed
+    let _ = execute!(stdout, Show, LeaveAlternateScreen);
+    result
+}
+
+async fn poll(
+    client: &ApiClient,
+    config: &Config,
+    path: &str,
+    interval: Duration,
+    fields: Option<&str>,
+    list_options: &ListOptions,
+) -> Result<()> {
+    let field_list = fields.map(crate::output::parse_fields);
+    let resolve_names = config.format == OutputFormat::Table && config.resolve_names;
+    // Fetched on the first poll, once the response shows which IDs it needs
+    let mut names: Option<Names> = None;
+
+    let mut previous: Option<Vec<String>> = None;
+    let mut poll = 0;
+
+    loop {
+        poll += 1;
+        let rendered = match execute_query(client, path, fields).await {
+            Ok(mut result) => match list_options.apply(&mut result) {
+                Ok(()) => {
+                    if resolve_names && names.is_none() {
+                        let needed = result.id_kinds(field_list.as_deref());
+                        names = Some(Names::fetch(client, needed).await);
+                    }
+                    let names = names.clone().unwrap_or_default();
+                    format_typed_output(&result, config.format, field_list.as_deref(), &names)
+                }
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        };
+
+        let header = format!(
+            "Every {:?}: {}  (poll {}, Ctrl+C to stop)",
+            interval, path, poll
+        );
+        match rendered {
+            Ok(output) => {
+                let lines: Vec<String> = output.lines().map(String::from).collect();
+                draw(&header, &lines, previous.as_deref())?;
+                previous = Some(lines);
+            }
+            // Keep polling through errors (e.g., the game is loading a save)
+            Err(e) => draw(&header, &[format!("Error: {}", e)], None)?,
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Split a line into cells: the columns of a table row, the label and value of
+/// a `Field: value` line, or the whole line. Concatenated, they give the line.
+fn cells(line: &str) -> Vec<&str> {
+    if line.contains('│') {
+        let mut cells = Vec::new();
+        let mut start = 0;
+        for (i, c) in line.char_indices().filter(|(_, c)| *c == '│') {
+            cells.push(&line[start..i]);
+            cells.push(&line[i..i + c.len_utf8()]);
+            start = i + c.len_utf8();
+        }
+        cells.push(&line[start..]);
+        return cells;
+    }
+    match line.find(": ") {
+        Some(i) => vec![&line[..i + 2], &line[i + 2..]],
+        None => vec![line],
+    }
+}
+
+/// The cells of `line`, each flagged if it differs from the cell at the same
+/// position of `previous` (the line at the same position in the last poll)
+fn changed_cells<'a>(line: &'a str, previous: Option<&str>) -> Vec<(&'a str, bool)> {
+    let before = previous.map(cells).unwrap_or_default();
+    cells(line)
+        .into_iter()
+        .enumerate()
+        .map(|(i, cell)| (cell, before.get(i) != Some(&cell)))
+        .collect()
+}
+
+/// Draw the poll from the top of the screen, cut to the terminal size so it
+/// never scrolls. Nothing is highlighted without a previous poll.
+fn draw(header: &str, lines: &[String], previous: Option<&[String]>) -> Result<()> {
+    // Not a terminal (e.g., piped): nothing to fit
+    let (cols, rows) = terminal::size().unwrap_or((u16::MAX, u16::MAX));
+    let mut out = io::stdout().lock();
+    queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+    write!(out, "{}", header.bold())?;
+
+    for (row, (i, line)) in (2..rows).zip(lines.iter().enumerate()) {
+        queue!(out, MoveTo(0, row))?;
+        let cells = match previous {
+            Some(previous) => changed_cells(line, previous.get(i).map(String::as_str)),
+            None => vec![(line.as_str(), false)],
+        };
+
+        let mut width = cols as usize;
+        for (cell, changed) in cells {
+            let cell: String = cell.chars().take(width).collect();
+            width -= cell.chars().count();
+            if changed && !cell.trim().is_empty() {
+                write!(out, "{}", cell.black().on_yellow())?;
+            } else {
+                write!(out, "{}", cell)?;
+            }
+        }
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn changed<'a>(line: &'a str, previous: &str) -> Vec<&'a str> {
+        changed_cells(line, Some(previous))
+            .into_iter()
+            .filter_map(|(cell, changed)| changed.then_some(cell))
+            .collect()
+    }
+
+    #[test]
+    fn test_changed_cells() {
+        // Only the value of a field line, even if another line shows the same text
+        assert_eq!(changed("  Iron: 40", "  Iron: 35"), vec!["40"]);
+        assert!(changed("  Iron: 40", "  Iron: 40").is_empty());
+
+        // Only the table cell that changed
+        assert_eq!(
+            changed("│ Roma │ 7  │ 3 │", "│ Roma │ 6  │ 3 │"),
+            vec![" 7  "]
+        );
+
+        // A line the last poll didn't have is new throughout
+        assert_eq!(
+            changed_cells("  Food: 125", None),
+            vec![("  Food: ", true), ("125", true)]
+        );
+    }
+
+    #[test]
+    fn test_cells() {
+        assert_eq!(cells("│ a │ b │").concat(), "│ a │ b │");
+        assert_eq!(cells("  Food: 125"), vec!["  Food: ", "125"]);
+        assert_eq!(cells("Player"), vec!["Player"]);
+    }
+}